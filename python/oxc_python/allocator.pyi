@@ -1,5 +1,10 @@
 """Type stubs for Allocator class."""
 
+from types import TracebackType
+from typing import Literal
+
+from .parse_result import ParseResult
+
 class Allocator:
     """
     Reusable memory allocator for efficient parsing.
@@ -29,6 +34,11 @@ class Allocator:
         ...     process(result)
         ...     allocator.reset()  # Reuse memory for next file
 
+        As a context manager, reset() is called when the block exits:
+
+        >>> with Allocator() as allocator:
+        ...     result = parse(source, allocator=allocator)
+
     See Also:
         - Phase 8: parse() function that accepts optional allocator parameter
         - ChunkHound: Main use case for batch parsing
@@ -65,3 +75,36 @@ class Allocator:
             >>> process(result2)
         """
         ...
+
+    def parse_many(self, sources: list[str], source_type: str | None = None) -> list[ParseResult]:
+        """
+        Parse several sources with this allocator, resetting it between them.
+
+        The allocator is reset before each parse and once more when the batch
+        finishes or fails, so callers never need to call reset() themselves.
+
+        Args:
+            sources: Source strings to parse
+            source_type: Optional source type applied to every source (see parse())
+
+        Returns:
+            List of ParseResult, one per source, in input order
+
+        Example:
+            >>> allocator = Allocator()
+            >>> results = allocator.parse_many([file1, file2, file3])
+        """
+        ...
+
+    def __enter__(self) -> "Allocator":
+        """Enter a `with` block, returning the allocator itself."""
+        ...
+
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_value: BaseException | None,
+        traceback: TracebackType | None,
+    ) -> Literal[False]:
+        """Reset the allocator; exceptions raised in the block propagate."""
+        ...
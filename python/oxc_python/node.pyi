@@ -1,5 +1,7 @@
 """Type stubs for Node base class."""
 
+from typing import Any
+
 from .span import Span
 
 class Node:
@@ -38,4 +40,26 @@ class Node:
         """
         ...
 
+    def to_repr(self, depth: int = 0) -> str:
+        """
+        Indented tree string of this node and its children.
+
+        Args:
+            depth: Indentation level of the first line (two spaces per level)
+        """
+        ...
+
+    def to_dict(self) -> dict[str, Any]:
+        """
+        Plain dict of this node's fields.
+
+        Span values are converted to {"start": ..., "end": ...}.
+        """
+        ...
+
+    @property
+    def __dict__(self) -> dict[str, Any]:  # type: ignore[override]
+        """Type, span, and line numbers as a plain dict (same as to_dict())."""
+        ...
+
     def __repr__(self) -> str: ...
@@ -14,6 +14,10 @@ class ParseResult:
         errors: List of parse error messages (empty if parsing succeeded)
         comments: List of comments found in the source
         panicked: True if parser hit an unrecoverable error
+        source_file: Filename passed to parse(), if any
+        source_type: Source type the code was parsed as
+        strict_mode: True if parse() was called with strict_mode=True
+        source_hash: Hash of the parsed source, for detecting changed files
     """
 
     program: Any
@@ -28,6 +32,18 @@ class ParseResult:
     panicked: bool
     """True if parser hit an unrecoverable error"""
 
+    source_file: str | None
+    """Filename passed to parse(), if any"""
+
+    source_type: str
+    """Source type the code was parsed as ("module", "script", "jsx", "typescript", or "tsx")"""
+
+    strict_mode: bool
+    """True if parse() was called with strict_mode=True"""
+
+    source_hash: str
+    """FNV-1a hash of the parsed source as 16 hex digits (see hash_source())"""
+
     @property
     def is_valid(self) -> bool:
         """
@@ -37,10 +53,83 @@ class ParseResult:
         """
         ...
 
+    @property
+    def has_errors(self) -> bool:
+        """True if parsing reported errors or panicked (the opposite of is_valid)."""
+        ...
+
+    @property
+    def has_jsx(self) -> bool:
+        """True if the tree contains any JSX node. Computed once, then cached."""
+        ...
+
+    @property
+    def has_typescript(self) -> bool:
+        """True if the tree uses any TypeScript syntax (TS* nodes or class member modifiers). Computed once, then cached."""
+        ...
+
     def __init__(
-        self, program: Any, errors: list[str], comments: list[str], panicked: bool
+        self,
+        program: Any,
+        errors: list[str],
+        comments: list[str],
+        panicked: bool,
+        source_file: str | None = None,
+        source_type: str = "module",
+        strict_mode: bool = False,
+        source_hash: str = "",
     ) -> None:
         """Create a new ParseResult."""
         ...
 
+    def import_paths(self) -> list[str]:
+        """
+        Module specifiers of every import, in source order. Computed once, then cached.
+
+        Covers import declarations, re-exports (export ... from), and dynamic
+        import() calls with a string literal argument.
+        """
+        ...
+
+    def export_names(self) -> list[str]:
+        """
+        Names exported from the module, in source order. Computed once, then cached.
+
+        A default export is listed as "default"; export * from without a
+        name contributes nothing.
+        """
+        ...
+
+    def required_modules(self) -> list[str]:
+        """String arguments of CommonJS require() calls, in source order. Computed once, then cached."""
+        ...
+
+    def summary(self) -> str:
+        """
+        Human-readable multiline summary for debugging.
+
+        Lists the source type, statement and comment counts, every error with
+        its line number and span, and the five most common node types.
+        """
+        ...
+
+    def to_json(self, indent: int | None = None) -> str:
+        """
+        Serialize the result, including the full AST, to a JSON string.
+
+        Every node becomes an object of its fields (as in `to_dict()`), with
+        child nodes nested; spans become `{"start": ..., "end": ...}`.
+        """
+        ...
+
+    @staticmethod
+    def from_json(json_str: str) -> ParseResult:
+        """
+        Rebuild a ParseResult from `to_json()` output, e.g. an AST cached on disk.
+
+        Nodes are restored as JsonNode objects exposing the serialized fields,
+        so `ParseResult.from_json(s).to_json() == s`.
+        """
+        ...
+
     def __repr__(self) -> str: ...
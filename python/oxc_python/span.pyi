@@ -1,6 +1,6 @@
 """Type stubs for Span class."""
 
-from typing import TypeAlias
+from typing import Iterator, TypeAlias
 
 class Span:
     """
@@ -27,6 +27,46 @@ class Span:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __ne__(self, other: object) -> bool: ...
+    def __contains__(self, offset: int) -> bool:
+        """Check if a byte offset falls within [start, end)."""
+        ...
+
+    def __add__(self, other: Span) -> Span:
+        """Smallest span covering both spans."""
+        ...
+
+    def __and__(self, other: Span) -> Span | None:
+        """Overlapping part of both spans, or None if they don't overlap."""
+        ...
+
+    def __bool__(self) -> bool:
+        """True for non-empty spans."""
+        ...
+
+    def __iter__(self) -> Iterator[int]:
+        """Iterate over (start, end)."""
+        ...
+
+    def __len__(self) -> int:
+        """Always 2, matching a (start, end) tuple."""
+        ...
+
+    def __getitem__(self, index: int) -> int:
+        """span[0] is start and span[1] is end; raises IndexError otherwise."""
+        ...
+
+    def line_slice(self, source: str) -> str:
+        """Full source line containing the span start, without its line break."""
+        ...
+
+    def caret_annotation(self, source: str) -> str:
+        """
+        The span's line followed by a line of ^ under the span's columns.
+
+        Spans running past the end of the line are underlined up to the line
+        end; empty spans get a single caret.
+        """
+        ...
 
 # Type alias for convenience
 SpanType: TypeAlias = Span
@@ -7,10 +7,21 @@ use crate::{
     Node, Span, FunctionDeclaration,
 };
 use crate::nodes::expressions::{
-    Identifier, ArrowFunctionExpression, CallExpression, MemberExpression,
+    ArrowFunctionExpression, CallExpression, MemberExpression,
     BinaryExpression, ConditionalExpression, ObjectExpression, ArrayExpression,
+    ParenthesizedExpression, RegExpLiteral,
+    AssignmentExpression, ArrayPattern, ObjectPattern, AssignmentPattern, RestElement,
+    ImportExpression, LogicalExpression, NewExpression, SequenceExpression, SpreadElement, TemplateElement, TemplateLiteral, UnaryExpression,
+    UpdateExpression, AwaitExpression, YieldExpression, ThisExpression, Super,
+};
+use crate::conversion::{
+    convert_class, convert_formal_parameters, convert_function_body, convert_jsx_element, convert_jsx_fragment, compute_line_number,
+    convert_literal, convert_numeric_literal, convert_boolean_literal, convert_bigint_literal,
+    convert_ts_type, convert_ts_type_parameter_instantiation, new_identifier, new_literal,
+};
+use crate::nodes::typescript::{
+    TSAsExpression, TSInstantiationExpression, TSNonNullExpression, TSSatisfiesExpression, TSTypeAssertion,
 };
-use crate::conversion::{convert_function_body, convert_jsx_element, convert_jsx_fragment, compute_line_number};
 
 pub fn convert_expression(py: Python, expr: &oxc_ast::ast::Expression, source: &str) -> PyResult<Py<PyAny>> {
     use oxc_ast::ast::Expression;
@@ -31,16 +42,7 @@ pub fn convert_expression(py: Python, expr: &oxc_ast::ast::Expression, source: &
 
         // Arrow functions - need to expose body for JSX traversal
         Expression::ArrowFunctionExpression(arrow) => {
-            let params: Vec<Py<PyAny>> = arrow.params.items.iter()
-                .map(|p| {
-                    let param_span = Span::from(p.span());
-                    let name = match &p.pattern.kind {
-                        oxc_ast::ast::BindingPatternKind::BindingIdentifier(ident) => ident.name.to_string(),
-                        _ => "param".to_string(),
-                    };
-                    Py::new(py, Identifier::new(param_span, name)).map(|p| p.into_any())
-                })
-                .collect::<PyResult<Vec<_>>>()?;
+            let params = convert_formal_parameters(py, &arrow.params, source)?;
 
             // Convert body - can be expression or block
             let body: Option<Py<PyAny>> = if arrow.expression {
@@ -63,6 +65,9 @@ pub fn convert_expression(py: Python, expr: &oxc_ast::ast::Expression, source: &
                 span: span_converted,
                 is_async: arrow.r#async,
                 is_generator: false,
+                is_concise: arrow.expression,
+                start_line,
+                end_line,
                 body,
                 params,
             };
@@ -77,6 +82,8 @@ pub fn convert_expression(py: Python, expr: &oxc_ast::ast::Expression, source: &
 
             let node = ConditionalExpression {
                 span: span_converted,
+                start_line,
+                end_line,
                 test: Some(test),
                 consequent: Some(consequent),
                 alternate: Some(alternate),
@@ -94,11 +101,13 @@ pub fn convert_expression(py: Python, expr: &oxc_ast::ast::Expression, source: &
                 oxc_ast::ast::LogicalOperator::Coalesce => "??",
             }.to_string();
 
-            let node = BinaryExpression {
+            let node = LogicalExpression {
                 span: span_converted,
+                left,
                 operator,
-                left: Some(left),
-                right: Some(right),
+                right,
+                start_line,
+                end_line,
             };
             Ok(Py::new(py, node)?.into_any())
         }
@@ -106,91 +115,125 @@ pub fn convert_expression(py: Python, expr: &oxc_ast::ast::Expression, source: &
         // Call expressions - need to expose arguments for JSX in callbacks
         Expression::CallExpression(call) => {
             let callee = convert_expression(py, &call.callee, source)?;
-            let arguments: Vec<Py<PyAny>> = call.arguments.iter()
-                .map(|arg| {
-                    match arg {
-                        oxc_ast::ast::Argument::SpreadElement(spread) => {
-                            convert_expression(py, &spread.argument, source)
-                        }
-                        _ => {
-                            // Regular argument - it's an Expression
-                            convert_expression(py, arg.to_expression(), source)
-                        }
-                    }
-                })
-                .collect::<PyResult<Vec<_>>>()?;
+            let arguments = convert_arguments(py, &call.arguments, source)?;
+            let type_arguments = call.type_arguments.as_ref()
+                .map(|ta| convert_ts_type_parameter_instantiation(py, ta, source))
+                .transpose()?;
 
             let node = CallExpression {
                 span: span_converted,
+                start_line,
+                end_line,
                 callee: Some(callee),
                 arguments,
+                type_arguments,
             };
             Ok(Py::new(py, node)?.into_any())
         }
 
-        // Member expressions - for chained methods like items.filter().map()
-        Expression::StaticMemberExpression(member) => {
-            let object = convert_expression(py, &member.object, source)?;
-            let property_span = Span::from(member.property.span);
-            let property = Py::new(py, Identifier::new(property_span, member.property.name.to_string()))?.into_any();
+        // Constructor calls: new Foo(a, b), new Map<K, V>()
+        Expression::NewExpression(new_expr) => {
+            let callee = convert_expression(py, &new_expr.callee, source)?;
+            let arguments = convert_arguments(py, &new_expr.arguments, source)?;
+            let type_arguments = new_expr.type_arguments.as_ref()
+                .map(|ta| convert_ts_type_parameter_instantiation(py, ta, source))
+                .transpose()?;
 
-            let node = MemberExpression {
+            let node = NewExpression {
                 span: span_converted,
-                object: Some(object),
-                property: Some(property),
-                computed: false,
+                callee: Some(callee),
+                arguments,
+                type_arguments,
+                start_line,
+                end_line,
             };
             Ok(Py::new(py, node)?.into_any())
         }
-        Expression::ComputedMemberExpression(member) => {
-            let object = convert_expression(py, &member.object, source)?;
-            let property = convert_expression(py, &member.expression, source)?;
 
-            let node = MemberExpression {
+        // Dynamic import() - expose the specifier for dependency tracking
+        Expression::ImportExpression(import) => {
+            let import_source = convert_expression(py, &import.source, source)?;
+            let options = import.options.as_ref()
+                .map(|opts| convert_expression(py, opts, source))
+                .transpose()?;
+
+            let node = ImportExpression {
                 span: span_converted,
-                object: Some(object),
-                property: Some(property),
-                computed: true,
+                source: import_source,
+                options,
+                start_line,
+                end_line,
             };
             Ok(Py::new(py, node)?.into_any())
         }
 
-        // Parenthesized expressions - unwrap and convert inner
+        // Member expressions - for chained methods like items.filter().map()
+        Expression::StaticMemberExpression(_)
+        | Expression::ComputedMemberExpression(_)
+        | Expression::PrivateFieldExpression(_) => {
+            convert_member_expression(py, expr.to_member_expression(), source)
+        }
+
+        // Parenthesized expressions - only present when parsed with preserve_parens=True
         Expression::ParenthesizedExpression(paren) => {
-            convert_expression(py, &paren.expression, source)
+            let expression = convert_expression(py, &paren.expression, source)?;
+            let node = ParenthesizedExpression {
+                span: span_converted,
+                expression,
+                start_line: compute_line_number(source, paren.span.start as usize),
+                end_line: compute_line_number(source, paren.span.end as usize),
+            };
+            Ok(Py::new(py, node)?.into_any())
         }
 
         // Sequence expressions - expose all expressions
         Expression::SequenceExpression(seq) => {
-            let _expressions: Vec<Py<PyAny>> = seq.expressions.iter()
+            let expressions: Vec<Py<PyAny>> = seq.expressions.iter()
                 .map(|e| convert_expression(py, e, source))
                 .collect::<PyResult<Vec<_>>>()?;
 
-            let mut node = Node::new("SequenceExpression".to_string(), span_converted);
-            node.start_line = start_line;
-            node.end_line = end_line;
+            let node = SequenceExpression {
+                span: span_converted,
+                expressions,
+                start_line,
+                end_line,
+            };
+            Ok(Py::new(py, node)?.into_any())
+        }
+
+        Expression::ThisExpression(_) => {
+            let node = ThisExpression { span: span_converted, start_line, end_line };
+            Ok(Py::new(py, node)?.into_any())
+        }
+
+        Expression::Super(_) => {
+            let node = Super { span: span_converted, start_line, end_line };
             Ok(Py::new(py, node)?.into_any())
         }
 
         // Object expressions - expose properties for nested JSX
         Expression::ObjectExpression(obj) => {
-            let properties: Vec<Py<PyAny>> = obj.properties.iter()
+            let (keys, properties): (Vec<Option<String>>, Vec<Py<PyAny>>) = obj.properties.iter()
                 .filter_map(|prop| {
                     match prop {
                         oxc_ast::ast::ObjectPropertyKind::ObjectProperty(p) => {
                             let value = convert_expression(py, &p.value, source).ok()?;
-                            Some(value)
+                            let key = if p.computed { None } else { p.key.static_name().map(|n| n.to_string()) };
+                            Some((key, value))
                         }
                         oxc_ast::ast::ObjectPropertyKind::SpreadProperty(spread) => {
-                            convert_expression(py, &spread.argument, source).ok()
+                            convert_spread_element(py, spread, source).ok().map(|value| (None, value))
                         }
                     }
                 })
-                .collect();
+                .unzip();
 
             let node = ObjectExpression {
                 span: span_converted,
+                start_line,
+                end_line,
                 properties,
+                keys,
             };
             Ok(Py::new(py, node)?.into_any())
         }
@@ -201,7 +244,7 @@ pub fn convert_expression(py: Python, expr: &oxc_ast::ast::Expression, source: &
                 .filter_map(|elem| {
                     match elem {
                         oxc_ast::ast::ArrayExpressionElement::SpreadElement(spread) => {
-                            convert_expression(py, &spread.argument, source).ok()
+                            convert_spread_element(py, spread, source).ok()
                         }
                         oxc_ast::ast::ArrayExpressionElement::Elision(_) => None,
                         _ => {
@@ -214,6 +257,8 @@ pub fn convert_expression(py: Python, expr: &oxc_ast::ast::Expression, source: &
 
             let node = ArrayExpression {
                 span: span_converted,
+                start_line,
+                end_line,
                 elements,
             };
             Ok(Py::new(py, node)?.into_any())
@@ -223,10 +268,12 @@ pub fn convert_expression(py: Python, expr: &oxc_ast::ast::Expression, source: &
         Expression::BinaryExpression(binary) => {
             let left = convert_expression(py, &binary.left, source)?;
             let right = convert_expression(py, &binary.right, source)?;
-            let operator = format!("{:?}", binary.operator);
+            let operator = binary.operator.as_str().to_string();
 
             let node = BinaryExpression {
                 span: span_converted,
+                start_line,
+                end_line,
                 operator,
                 left: Some(left),
                 right: Some(right),
@@ -234,16 +281,63 @@ pub fn convert_expression(py: Python, expr: &oxc_ast::ast::Expression, source: &
             Ok(Py::new(py, node)?.into_any())
         }
 
+        // Template literals: `hello ${name}`
+        Expression::TemplateLiteral(template) => convert_template_literal(py, template, source),
+
+        // Unary expressions (!x, -x, typeof x, void 0, delete obj.prop)
+        Expression::UnaryExpression(unary) => convert_unary_expression(py, unary, source),
+
+        // Update expressions (i++, --j)
+        Expression::UpdateExpression(update) => {
+            let argument = convert_simple_assignment_target(py, &update.argument, source)?;
+            let node = UpdateExpression {
+                span: span_converted,
+                operator: update.operator.as_str().to_string(),
+                prefix: update.prefix,
+                argument: Some(argument),
+                start_line,
+                end_line,
+            };
+            Ok(Py::new(py, node)?.into_any())
+        }
+
+        // await / yield
+        Expression::AwaitExpression(await_expr) => {
+            let node = AwaitExpression {
+                span: span_converted,
+                argument: convert_expression(py, &await_expr.argument, source)?,
+                start_line,
+                end_line,
+            };
+            Ok(Py::new(py, node)?.into_any())
+        }
+        Expression::YieldExpression(yield_expr) => {
+            let argument = yield_expr.argument.as_ref()
+                .map(|arg| convert_expression(py, arg, source))
+                .transpose()?;
+            let node = YieldExpression {
+                span: span_converted,
+                argument,
+                delegate: yield_expr.delegate,
+                start_line,
+                end_line,
+            };
+            Ok(Py::new(py, node)?.into_any())
+        }
+
         // Assignment expressions
         Expression::AssignmentExpression(assign) => {
+            let left = convert_assignment_target(py, &assign.left, source)?;
             let right = convert_expression(py, &assign.right, source)?;
 
-            let mut node = Node::new("AssignmentExpression".to_string(), span_converted);
-            node.start_line = start_line;
-            node.end_line = end_line;
-            // Store right for traversal - walk() handles this via 'right' attribute check
-            // For now just return node - walk() already handles 'right' in node_attrs
-            let _ = right; // unused but needed for JSX traversal
+            let node = AssignmentExpression {
+                span: span_converted,
+                operator: assign.operator.as_str().to_string(),
+                left,
+                right,
+                start_line,
+                end_line,
+            };
             Ok(Py::new(py, node)?.into_any())
         }
 
@@ -254,16 +348,7 @@ pub fn convert_expression(py: Python, expr: &oxc_ast::ast::Expression, source: &
                 .map(|b| convert_function_body(py, b, source))
                 .transpose()?;
 
-            let params: Vec<Py<PyAny>> = func.params.items.iter()
-                .map(|p| {
-                    let param_span = Span::from(p.span());
-                    let param_name = match &p.pattern.kind {
-                        oxc_ast::ast::BindingPatternKind::BindingIdentifier(ident) => ident.name.to_string(),
-                        _ => "param".to_string(),
-                    };
-                    Py::new(py, Identifier::new(param_span, param_name)).map(|p| p.into_any())
-                })
-                .collect::<PyResult<Vec<_>>>()?;
+            let params = convert_formal_parameters(py, &func.params, source)?;
 
             let node = FunctionDeclaration {
                 span: span_converted,
@@ -276,27 +361,78 @@ pub fn convert_expression(py: Python, expr: &oxc_ast::ast::Expression, source: &
                 params,
                 type_parameters: None,
                 return_type: None,
+                overloads: Vec::new(),
             };
             Ok(Py::new(py, node)?.into_any())
         }
 
+        // Class expressions share the ClassDeclaration node, like function expressions
+        Expression::ClassExpression(class) => convert_class(py, class, source),
+
+        // Identifier references
+        Expression::Identifier(ident) => {
+            Ok(Py::new(py, new_identifier(span_converted, ident.name.to_string(), source))?.into_any())
+        }
+
+        // Literals - typed Literal nodes with Python values
+        Expression::StringLiteral(lit) => convert_literal(py, lit, source),
+        Expression::NumericLiteral(lit) => convert_numeric_literal(py, lit, source),
+        Expression::BooleanLiteral(lit) => convert_boolean_literal(py, lit, source),
+        Expression::NullLiteral(_) => {
+            let node = new_literal(py, span_converted, py.None(), "null".to_string(), Some("null".to_string()), source);
+            Ok(Py::new(py, node)?.into_any())
+        }
+        Expression::BigIntLiteral(lit) => convert_bigint_literal(py, lit, source),
+
+        // Regular expression literals - expose pattern and flags
+        Expression::RegExpLiteral(regex) => {
+            let raw = source[expr_span.start as usize..expr_span.end as usize].to_string();
+            let base = new_literal(py, span_converted, py.None(), raw, Some("regex".to_string()), source);
+            let node = RegExpLiteral {
+                pattern: regex.regex.pattern.text.to_string(),
+                flags: regex.regex.flags.to_string(),
+                start_line,
+                end_line,
+            };
+            let init = pyo3::PyClassInitializer::from(base).add_subclass(node);
+            Ok(Py::new(py, init)?.into_any())
+        }
+
+        // TypeScript expression wrappers: x as T, x satisfies T, <T>x, x!, f<T>
+        Expression::TSAsExpression(as_expr) => {
+            let expression = convert_expression(py, &as_expr.expression, source)?;
+            let type_annotation = convert_ts_type(py, &as_expr.type_annotation, source)?;
+            let node = TSAsExpression { span: span_converted, start_line, end_line, expression, type_annotation };
+            Ok(Py::new(py, node)?.into_any())
+        }
+        Expression::TSSatisfiesExpression(satisfies) => {
+            let expression = convert_expression(py, &satisfies.expression, source)?;
+            let type_annotation = convert_ts_type(py, &satisfies.type_annotation, source)?;
+            let node = TSSatisfiesExpression { span: span_converted, start_line, end_line, expression, type_annotation };
+            Ok(Py::new(py, node)?.into_any())
+        }
+        Expression::TSTypeAssertion(assertion) => {
+            let expression = convert_expression(py, &assertion.expression, source)?;
+            let type_annotation = convert_ts_type(py, &assertion.type_annotation, source)?;
+            let node = TSTypeAssertion { span: span_converted, start_line, end_line, expression, type_annotation };
+            Ok(Py::new(py, node)?.into_any())
+        }
+        Expression::TSNonNullExpression(non_null) => {
+            let expression = convert_expression(py, &non_null.expression, source)?;
+            let node = TSNonNullExpression { span: span_converted, start_line, end_line, expression };
+            Ok(Py::new(py, node)?.into_any())
+        }
+        Expression::TSInstantiationExpression(inst) => {
+            let expression = convert_expression(py, &inst.expression, source)?;
+            let type_arguments = convert_ts_type_parameter_instantiation(py, &inst.type_arguments, source)?;
+            let node = TSInstantiationExpression { span: span_converted, start_line, end_line, expression, type_arguments };
+            Ok(Py::new(py, node)?.into_any())
+        }
+
         // Default: create a generic node with correct type
         _ => {
             let type_str = match expr {
-                Expression::NumericLiteral(_) => "NumericLiteral",
-                Expression::StringLiteral(_) => "StringLiteral",
-                Expression::BooleanLiteral(_) => "BooleanLiteral",
-                Expression::NullLiteral(_) => "NullLiteral",
-                Expression::Identifier(_) => "Identifier",
-                Expression::UnaryExpression(_) => "UnaryExpression",
-                Expression::UpdateExpression(_) => "UpdateExpression",
-                Expression::PrivateFieldExpression(_) => "MemberExpression",
-                Expression::NewExpression(_) => "NewExpression",
-                Expression::ThisExpression(_) => "ThisExpression",
-                Expression::TemplateLiteral(_) => "TemplateLiteral",
                 Expression::TaggedTemplateExpression(_) => "TaggedTemplateExpression",
-                Expression::AwaitExpression(_) => "AwaitExpression",
-                Expression::YieldExpression(_) => "YieldExpression",
                 _ => "Expression",
             };
 
@@ -308,4 +444,297 @@ pub fn convert_expression(py: Python, expr: &oxc_ast::ast::Expression, source: &
     }
 }
 
+/// Convert a template literal; also used for template literal types in TS
+pub fn convert_template_literal(py: Python, template: &oxc_ast::ast::TemplateLiteral, source: &str) -> PyResult<Py<PyAny>> {
+    let quasis = template.quasis
+        .iter()
+        .map(|quasi| {
+            let element = TemplateElement {
+                span: Span::from(quasi.span),
+                cooked: quasi.value.cooked.as_ref().map(|cooked| cooked.to_string()),
+                raw: quasi.value.raw.to_string(),
+                tail: quasi.tail,
+                start_line: compute_line_number(source, quasi.span.start as usize),
+                end_line: compute_line_number(source, quasi.span.end as usize),
+            };
+            Ok(Py::new(py, element)?.into_any())
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    let expressions = template.expressions
+        .iter()
+        .map(|expr| convert_expression(py, expr, source))
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let node = TemplateLiteral {
+        span: Span::from(template.span),
+        quasis,
+        expressions,
+        start_line: compute_line_number(source, template.span.start as usize),
+        end_line: compute_line_number(source, template.span.end as usize),
+    };
+    Ok(Py::new(py, node)?.into_any())
+}
+
+/// Convert a unary expression; also used for negative numbers in TS literal types
+pub fn convert_unary_expression(py: Python, unary: &oxc_ast::ast::UnaryExpression, source: &str) -> PyResult<Py<PyAny>> {
+    let argument = convert_expression(py, &unary.argument, source)?;
+    let node = UnaryExpression {
+        span: Span::from(unary.span),
+        operator: unary.operator.as_str().to_string(),
+        argument: Some(argument),
+        start_line: compute_line_number(source, unary.span.start as usize),
+        end_line: compute_line_number(source, unary.span.end as usize),
+    };
+    Ok(Py::new(py, node)?.into_any())
+}
+
+/// Convert call or `new` arguments; spread arguments keep their SpreadElement wrapper
+fn convert_arguments(py: Python, arguments: &[oxc_ast::ast::Argument], source: &str) -> PyResult<Vec<Py<PyAny>>> {
+    arguments.iter()
+        .map(|arg| match arg {
+            oxc_ast::ast::Argument::SpreadElement(spread) => convert_spread_element(py, spread, source),
+            // Regular argument - it's an Expression
+            _ => convert_expression(py, arg.to_expression(), source),
+        })
+        .collect()
+}
+
+/// Convert a spread argument or array element, keeping the `...` wrapper
+fn convert_spread_element(py: Python, spread: &oxc_ast::ast::SpreadElement, source: &str) -> PyResult<Py<PyAny>> {
+    let argument = convert_expression(py, &spread.argument, source)?;
+    let node = SpreadElement {
+        span: Span::from(spread.span),
+        argument,
+        start_line: compute_line_number(source, spread.span.start as usize),
+        end_line: compute_line_number(source, spread.span.end as usize),
+    };
+    Ok(Py::new(py, node)?.into_any())
+}
+
+/// Convert any member expression form (static, computed, private field) to a MemberExpression
+pub fn convert_member_expression(py: Python, member: &oxc_ast::ast::MemberExpression, source: &str) -> PyResult<Py<PyAny>> {
+    use oxc_ast::ast::MemberExpression as ME;
+
+    let span_converted = Span::from(member.span());
+    let (object, property, computed) = match member {
+        ME::StaticMemberExpression(member) => {
+            let object = convert_expression(py, &member.object, source)?;
+            let property_span = Span::from(member.property.span);
+            let property = Py::new(py, new_identifier(property_span, member.property.name.to_string(), source))?.into_any();
+            (object, property, false)
+        }
+        ME::ComputedMemberExpression(member) => {
+            let object = convert_expression(py, &member.object, source)?;
+            let property = convert_expression(py, &member.expression, source)?;
+            (object, property, true)
+        }
+        ME::PrivateFieldExpression(member) => {
+            let object = convert_expression(py, &member.object, source)?;
+            let property_span = Span::from(member.field.span);
+            let property = Py::new(py, new_identifier(property_span, format!("#{}", member.field.name), source))?.into_any();
+            (object, property, false)
+        }
+    };
+
+    let node = MemberExpression {
+        span: span_converted,
+        start_line: compute_line_number(source, span_converted.start),
+        end_line: compute_line_number(source, span_converted.end),
+        object: Some(object),
+        property: Some(property),
+        computed,
+    };
+    Ok(Py::new(py, node)?.into_any())
+}
+
+/// Convert a simple assignment target (identifier or member expression) to a Python node.
+///
+/// TypeScript wrappers such as `(x as T) = 1` or `x! = 1` are unwrapped to their inner expression.
+pub fn convert_simple_assignment_target(py: Python, target: &oxc_ast::ast::SimpleAssignmentTarget, source: &str) -> PyResult<Py<PyAny>> {
+    use oxc_ast::ast::SimpleAssignmentTarget as SAT;
+
+    match target {
+        SAT::AssignmentTargetIdentifier(ident) => {
+            Ok(Py::new(py, new_identifier(Span::from(ident.span), ident.name.to_string(), source))?.into_any())
+        }
+        SAT::TSAsExpression(e) => convert_expression(py, &e.expression, source),
+        SAT::TSSatisfiesExpression(e) => convert_expression(py, &e.expression, source),
+        SAT::TSNonNullExpression(e) => convert_expression(py, &e.expression, source),
+        SAT::TSTypeAssertion(e) => convert_expression(py, &e.expression, source),
+        _ => convert_member_expression(py, target.to_member_expression(), source),
+    }
+}
+
+/// Convert an assignment target to a Python node.
+///
+/// Simple targets become Identifier / MemberExpression nodes; destructuring
+/// targets become ArrayPattern / ObjectPattern nodes.
+pub fn convert_assignment_target(py: Python, target: &oxc_ast::ast::AssignmentTarget, source: &str) -> PyResult<Py<PyAny>> {
+    use oxc_ast::ast::AssignmentTarget as AT;
+
+    match target {
+        AT::ArrayAssignmentTarget(array) => {
+            let elements = array.elements.iter()
+                .map(|elem| elem.as_ref()
+                    .map(|elem| convert_assignment_target_maybe_default(py, elem, source))
+                    .transpose())
+                .collect::<PyResult<Vec<_>>>()?;
+            let rest = array.rest.as_ref()
+                .map(|rest| convert_assignment_target_rest(py, rest, source))
+                .transpose()?;
+            let node = ArrayPattern {
+                span: Span::from(array.span),
+                elements,
+                rest,
+                start_line: compute_line_number(source, array.span.start as usize),
+                end_line: compute_line_number(source, array.span.end as usize),
+            };
+            Ok(Py::new(py, node)?.into_any())
+        }
+        AT::ObjectAssignmentTarget(object) => {
+            use oxc_ast::ast::AssignmentTargetProperty as ATP;
+
+            let (keys, properties): (Vec<Option<String>>, Vec<Py<PyAny>>) = object.properties.iter()
+                .map(|prop| match prop {
+                    // Shorthand `{ x }` or `{ x = 1 }`
+                    ATP::AssignmentTargetPropertyIdentifier(prop) => {
+                        let ident = Py::new(py, new_identifier(Span::from(prop.binding.span), prop.binding.name.to_string(), source))?.into_any();
+                        let value = match &prop.init {
+                            Some(init) => convert_assignment_pattern(py, prop.span, ident, init, source)?,
+                            None => ident,
+                        };
+                        Ok((Some(prop.binding.name.to_string()), value))
+                    }
+                    // `{ key: target }` - the target is what gets assigned
+                    ATP::AssignmentTargetPropertyProperty(prop) => {
+                        let key = if prop.computed { None } else { prop.name.static_name().map(|n| n.to_string()) };
+                        Ok((key, convert_assignment_target_maybe_default(py, &prop.binding, source)?))
+                    }
+                })
+                .collect::<PyResult<Vec<_>>>()?
+                .into_iter()
+                .unzip();
+            let rest = object.rest.as_ref()
+                .map(|rest| convert_assignment_target_rest(py, rest, source))
+                .transpose()?;
+            let node = ObjectPattern {
+                span: Span::from(object.span),
+                properties,
+                keys,
+                rest,
+                start_line: compute_line_number(source, object.span.start as usize),
+                end_line: compute_line_number(source, object.span.end as usize),
+            };
+            Ok(Py::new(py, node)?.into_any())
+        }
+        _ => convert_simple_assignment_target(py, target.to_simple_assignment_target(), source),
+    }
+}
+
+fn convert_assignment_target_maybe_default(py: Python, target: &oxc_ast::ast::AssignmentTargetMaybeDefault, source: &str) -> PyResult<Py<PyAny>> {
+    match target {
+        oxc_ast::ast::AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(with_default) => {
+            let left = convert_assignment_target(py, &with_default.binding, source)?;
+            convert_assignment_pattern(py, with_default.span, left, &with_default.init, source)
+        }
+        _ => convert_assignment_target(py, target.to_assignment_target(), source),
+    }
+}
+
+fn convert_assignment_pattern(
+    py: Python,
+    span: oxc_span::Span,
+    left: Py<PyAny>,
+    init: &oxc_ast::ast::Expression,
+    source: &str,
+) -> PyResult<Py<PyAny>> {
+    let node = AssignmentPattern {
+        span: Span::from(span),
+        left,
+        right: convert_expression(py, init, source)?,
+        start_line: compute_line_number(source, span.start as usize),
+        end_line: compute_line_number(source, span.end as usize),
+    };
+    Ok(Py::new(py, node)?.into_any())
+}
+
+/// Convert a binding pattern (catch parameters and other declarations) to a Python node.
+///
+/// Identifiers become Identifier nodes; destructuring becomes the same
+/// ObjectPattern / ArrayPattern / AssignmentPattern / RestElement nodes used
+/// for assignment targets.
+pub fn convert_binding_pattern(py: Python, pattern: &oxc_ast::ast::BindingPattern, source: &str) -> PyResult<Py<PyAny>> {
+    use oxc_ast::ast::BindingPatternKind;
+
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => {
+            Ok(Py::new(py, new_identifier(Span::from(ident.span), ident.name.to_string(), source))?.into_any())
+        }
+        BindingPatternKind::ObjectPattern(object) => {
+            let (keys, properties): (Vec<Option<String>>, Vec<Py<PyAny>>) = object.properties.iter()
+                .map(|prop| {
+                    let key = if prop.computed { None } else { prop.key.static_name().map(|n| n.to_string()) };
+                    Ok((key, convert_binding_pattern(py, &prop.value, source)?))
+                })
+                .collect::<PyResult<Vec<_>>>()?
+                .into_iter()
+                .unzip();
+            let rest = object.rest.as_ref()
+                .map(|rest| convert_binding_rest(py, rest, source))
+                .transpose()?;
+            let node = ObjectPattern {
+                span: Span::from(object.span),
+                properties,
+                keys,
+                rest,
+                start_line: compute_line_number(source, object.span.start as usize),
+                end_line: compute_line_number(source, object.span.end as usize),
+            };
+            Ok(Py::new(py, node)?.into_any())
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            let elements = array.elements.iter()
+                .map(|elem| elem.as_ref()
+                    .map(|elem| convert_binding_pattern(py, elem, source))
+                    .transpose())
+                .collect::<PyResult<Vec<_>>>()?;
+            let rest = array.rest.as_ref()
+                .map(|rest| convert_binding_rest(py, rest, source))
+                .transpose()?;
+            let node = ArrayPattern {
+                span: Span::from(array.span),
+                elements,
+                rest,
+                start_line: compute_line_number(source, array.span.start as usize),
+                end_line: compute_line_number(source, array.span.end as usize),
+            };
+            Ok(Py::new(py, node)?.into_any())
+        }
+        BindingPatternKind::AssignmentPattern(assign) => {
+            let left = convert_binding_pattern(py, &assign.left, source)?;
+            convert_assignment_pattern(py, assign.span, left, &assign.right, source)
+        }
+    }
+}
+
+fn convert_binding_rest(py: Python, rest: &oxc_ast::ast::BindingRestElement, source: &str) -> PyResult<Py<PyAny>> {
+    let node = RestElement {
+        span: Span::from(rest.span),
+        argument: convert_binding_pattern(py, &rest.argument, source)?,
+        start_line: compute_line_number(source, rest.span.start as usize),
+        end_line: compute_line_number(source, rest.span.end as usize),
+    };
+    Ok(Py::new(py, node)?.into_any())
+}
+
+fn convert_assignment_target_rest(py: Python, rest: &oxc_ast::ast::AssignmentTargetRest, source: &str) -> PyResult<Py<PyAny>> {
+    let node = RestElement {
+        span: Span::from(rest.span),
+        argument: convert_assignment_target(py, &rest.target, source)?,
+        start_line: compute_line_number(source, rest.span.start as usize),
+        end_line: compute_line_number(source, rest.span.end as usize),
+    };
+    Ok(Py::new(py, node)?.into_any())
+}
+
 // JSX conversion functions are imported from jsx module via crate::conversion
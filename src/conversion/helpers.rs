@@ -1,17 +1,34 @@
 //! Helper conversion functions for imports, exports, identifiers, and literals
 
 use pyo3::prelude::*;
-use oxc_ast::ast::{
-    ImportDeclarationSpecifier, ExportSpecifier, BindingIdentifier, StringLiteral,
-    IdentifierName,
-};
 use oxc_span::GetSpan;
-use crate::nodes::expressions::{self as expressions, Identifier, Literal};
+use crate::nodes::expressions;
 use crate::Span;
 
 // Re-export compute_line_number from parser module
 pub use crate::parser::compute_line_number;
 
+/// Identifier node with its line numbers computed from `source`
+pub fn new_identifier(span: Span, name: String, source: &str) -> expressions::Identifier {
+    let start_line = compute_line_number(source, span.start);
+    let end_line = compute_line_number(source, span.end);
+    expressions::Identifier::new(span, name, start_line, end_line)
+}
+
+/// Literal node with its line numbers computed from `source`
+pub fn new_literal(
+    py: Python,
+    span: Span,
+    value: Py<PyAny>,
+    raw: String,
+    kind: Option<String>,
+    source: &str,
+) -> expressions::Literal {
+    let start_line = compute_line_number(source, span.start);
+    let end_line = compute_line_number(source, span.end);
+    expressions::Literal::new(py, span, value, raw, kind, start_line, end_line)
+}
+
 /// Helper to convert import specifier (Phase 6 helpers + Phase 15 helpers)
 pub fn convert_import_specifier(
     py: Python,
@@ -31,7 +48,7 @@ pub fn convert_import_specifier(
 
             let local = convert_binding_identifier(py, &named.local, source)?;
             let imported = {
-                let node = expressions::Identifier::new(span_converted.clone(), imported_name);
+                let node = new_identifier(span_converted, imported_name, source);
                 Py::new(py, node)?.into_any()
             };
 
@@ -41,6 +58,7 @@ pub fn convert_import_specifier(
                 end_line,
                 imported,
                 local,
+                import_kind: if named.import_kind.is_type() { "type" } else { "value" }.to_string(),
             };
             Ok(Py::new(py, node)?.into_any())
         }
@@ -95,12 +113,12 @@ pub fn convert_export_specifier(
     let exported_name = spec.exported.name().to_string();
 
     let local = {
-        let node = expressions::Identifier::new(span_converted.clone(), local_name);
+        let node = new_identifier(span_converted, local_name, source);
         Py::new(py, node)?.into_any()
     };
 
     let exported = {
-        let node = expressions::Identifier::new(span_converted.clone(), exported_name);
+        let node = new_identifier(span_converted, exported_name, source);
         Py::new(py, node)?.into_any()
     };
 
@@ -115,17 +133,51 @@ pub fn convert_export_specifier(
 }
 
 // Phase 15: Helper to convert BindingIdentifier to Python Identifier object
-pub fn convert_binding_identifier(py: Python, ident: &oxc_ast::ast::BindingIdentifier<'_>, _source: &str) -> PyResult<Py<PyAny>> {
+pub fn convert_binding_identifier(py: Python, ident: &oxc_ast::ast::BindingIdentifier<'_>, source: &str) -> PyResult<Py<PyAny>> {
     let span = ident.span;
     let span_converted = Span::from(span);
     let name = ident.name.to_string();
 
-    let node = expressions::Identifier::new(span_converted, name);
+    let node = new_identifier(span_converted, name, source);
     Ok(Py::new(py, node)?.into_any())
 }
 
+/// Collect every name bound by a binding pattern, in source order.
+///
+/// Flattens destructuring: `[a, { b: c }, ...d]` binds `a`, `c`, and `d`.
+pub fn binding_pattern_names(pattern: &oxc_ast::ast::BindingPattern<'_>) -> Vec<String> {
+    let mut names = Vec::new();
+    collect_binding_names(pattern, &mut names);
+    names
+}
+
+fn collect_binding_names(pattern: &oxc_ast::ast::BindingPattern<'_>, names: &mut Vec<String>) {
+    use oxc_ast::ast::BindingPatternKind;
+
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => names.push(ident.name.to_string()),
+        BindingPatternKind::ObjectPattern(obj) => {
+            for prop in &obj.properties {
+                collect_binding_names(&prop.value, names);
+            }
+            if let Some(rest) = &obj.rest {
+                collect_binding_names(&rest.argument, names);
+            }
+        }
+        BindingPatternKind::ArrayPattern(arr) => {
+            for element in arr.elements.iter().flatten() {
+                collect_binding_names(element, names);
+            }
+            if let Some(rest) = &arr.rest {
+                collect_binding_names(&rest.argument, names);
+            }
+        }
+        BindingPatternKind::AssignmentPattern(assign) => collect_binding_names(&assign.left, names),
+    }
+}
+
 // Phase 15: Helper to convert StringLiteral to Python object
-pub fn convert_literal(py: Python, lit: &oxc_ast::ast::StringLiteral<'_>, _source: &str) -> PyResult<Py<PyAny>> {
+pub fn convert_literal(py: Python, lit: &oxc_ast::ast::StringLiteral<'_>, source: &str) -> PyResult<Py<PyAny>> {
     use pyo3::types::PyString;
 
     let span = lit.span;
@@ -136,20 +188,70 @@ pub fn convert_literal(py: Python, lit: &oxc_ast::ast::StringLiteral<'_>, _sourc
     // Convert value to PyObject
     let value_py = PyString::new(py, &value).into();
 
-    let node = expressions::Literal::new(
+    let node = new_literal(
+        py,
         span_converted,
         value_py,
         raw,
+        Some("string".to_string()),
+        source,
     );
     Ok(Py::new(py, node)?.into_any())
 }
 
+// Helper to convert NumericLiteral to a Literal with a Python int or float value
+pub fn convert_numeric_literal(py: Python, lit: &oxc_ast::ast::NumericLiteral<'_>, source: &str) -> PyResult<Py<PyAny>> {
+    use pyo3::IntoPyObjectExt;
+
+    let span_converted = Span::from(lit.span);
+    let raw = lit.raw.as_ref().map(|r| r.to_string())
+        .unwrap_or_else(|| source[lit.span.start as usize..lit.span.end as usize].to_string());
+
+    // Integral values within i64 range become Python ints, everything else floats
+    let value = lit.value;
+    let value_py = if value.is_finite() && value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+        (value as i64).into_py_any(py)?
+    } else {
+        value.into_py_any(py)?
+    };
+
+    let node = new_literal(py, span_converted, value_py, raw, Some("number".to_string()), source);
+    Ok(Py::new(py, node)?.into_any())
+}
+
+// Helper to convert BigIntLiteral to a BigIntLiteral node (Literal subclass)
+pub fn convert_bigint_literal(py: Python, lit: &oxc_ast::ast::BigIntLiteral<'_>, source: &str) -> PyResult<Py<PyAny>> {
+    use pyo3::types::PyString;
+
+    let span = lit.span;
+    let raw = lit.raw.as_ref().map(|r| r.to_string())
+        .unwrap_or_else(|| source[span.start as usize..span.end as usize].to_string());
+    let value_py = PyString::new(py, lit.value.as_str()).into_any().unbind();
+
+    let base = new_literal(py, Span::from(span), value_py, raw, Some("bigint".to_string()), source);
+    let node = expressions::BigIntLiteral {
+        start_line: compute_line_number(source, span.start as usize),
+        end_line: compute_line_number(source, span.end as usize),
+    };
+    Ok(Py::new(py, PyClassInitializer::from(base).add_subclass(node))?.into_any())
+}
+
+// Helper to convert BooleanLiteral to a Literal with a Python bool value
+pub fn convert_boolean_literal(py: Python, lit: &oxc_ast::ast::BooleanLiteral, source: &str) -> PyResult<Py<PyAny>> {
+    use pyo3::IntoPyObjectExt;
+
+    let span_converted = Span::from(lit.span);
+    let raw = if lit.value { "true" } else { "false" }.to_string();
+    let node = new_literal(py, span_converted, lit.value.into_py_any(py)?, raw, Some("boolean".to_string()), source);
+    Ok(Py::new(py, node)?.into_any())
+}
+
 // Phase 15: Helper to convert IdentifierName to Python Identifier object
-pub fn convert_identifier_name(py: Python, ident: &oxc_ast::ast::IdentifierName<'_>, _source: &str) -> PyResult<Py<PyAny>> {
+pub fn convert_identifier_name(py: Python, ident: &oxc_ast::ast::IdentifierName<'_>, source: &str) -> PyResult<Py<PyAny>> {
     let span = ident.span;
     let span_converted = Span::from(span);
     let name = ident.name.to_string();
 
-    let node = expressions::Identifier::new(span_converted, name);
+    let node = new_identifier(span_converted, name, source);
     Ok(Py::new(py, node)?.into_any())
 }
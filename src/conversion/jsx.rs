@@ -1,12 +1,11 @@
 //! JSX element conversion functions
 
-use crate::nodes::expressions;
 use crate::nodes::jsx::{
-    JSXAttribute, JSXClosingElement, JSXElement, JSXExpressionContainer, JSXFragment,
+    EmptyExpression, JSXAttribute, JSXClosingElement, JSXElement, JSXExpressionContainer, JSXFragment,
     JSXIdentifier, JSXMemberExpression, JSXOpeningElement, JSXSpreadAttribute, JSXText,
 };
 use crate::Span;
-use crate::conversion::{convert_literal, convert_expression};
+use crate::conversion::{convert_literal, convert_expression, new_identifier};
 use pyo3::prelude::*;
 use oxc_span::GetSpan;
 
@@ -60,21 +59,22 @@ pub fn convert_jsx_member_expression(py: Python, member: &oxc_ast::ast::JSXMembe
     let span_converted = Span::from(span);
 
     // Convert object (can be identifier or nested member expression)
-    let object = if let Some(ident) = member.object.get_identifier() {
-        let ident_span = ident.span;
-        let ident_span_converted = Span::from(ident_span);
-        let ident_name = ident.name.to_string();
-        let ident_node = JSXIdentifier {
-            span: ident_span_converted,
-            name: ident_name,
-        };
-        Py::new(py, ident_node)?.into_any()
-    } else {
-        // For now, return a placeholder for complex object expressions
-        Py::new(py, JSXIdentifier {
-            span: Span { start: 0, end: 0 },
-            name: "<object>".to_string(),
-        })?.into_any()
+    let object = match &member.object {
+        oxc_ast::ast::JSXMemberExpressionObject::IdentifierReference(ident) => {
+            Py::new(py, JSXIdentifier {
+                span: Span::from(ident.span),
+                name: ident.name.to_string(),
+            })?.into_any()
+        }
+        oxc_ast::ast::JSXMemberExpressionObject::MemberExpression(inner) => {
+            convert_jsx_member_expression(py, inner)?
+        }
+        oxc_ast::ast::JSXMemberExpressionObject::ThisExpression(this) => {
+            Py::new(py, JSXIdentifier {
+                span: Span::from(this.span),
+                name: "this".to_string(),
+            })?.into_any()
+        }
     };
 
     let property = {
@@ -158,7 +158,7 @@ pub fn convert_jsx_spread_attribute(py: Python, attr: &oxc_ast::ast::JSXSpreadAt
         let arg_span = attr.argument.span();
         let arg_span_converted = Span::from(arg_span);
         let arg_name = "<expression>";
-        let arg_node = expressions::Identifier::new(arg_span_converted, arg_name.to_string());
+        let arg_node = new_identifier(arg_span_converted, arg_name.to_string(), source);
         Py::new(py, arg_node)?
     };
 
@@ -239,9 +239,9 @@ pub fn convert_jsx_expression_container(py: Python, container: &oxc_ast::ast::JS
     // This allows walk() to traverse into the expression and find nested JSX
     let expression = match &container.expression {
         oxc_ast::ast::JSXExpression::EmptyExpression(_) => {
-            // Empty expression {} - create a placeholder
+            // Empty expression {} - a typed placeholder rather than a fake Identifier
             let expr_span = Span::from(container.expression.span());
-            Py::new(py, expressions::Identifier::new(expr_span, "<empty>".to_string()))?.into_any()
+            Py::new(py, EmptyExpression { span: expr_span })?.into_any()
         }
         _ => {
             // Convert as regular expression using convert_expression
@@ -250,9 +250,11 @@ pub fn convert_jsx_expression_container(py: Python, container: &oxc_ast::ast::JS
         }
     };
 
+    let is_empty = matches!(container.expression, oxc_ast::ast::JSXExpression::EmptyExpression(_));
     let node = JSXExpressionContainer {
         span: span_converted,
         expression,
+        is_empty,
     };
     Ok(Py::new(py, node)?.into_any())
 }
@@ -276,7 +278,8 @@ pub fn convert_jsx_child(py: Python, child: &oxc_ast::ast::JSXChild, source: &st
             // JSX spread children {...items} - treat as expression container for now
             Ok(Py::new(py, JSXExpressionContainer {
                 span: Span { start: 0, end: 0 },
-                expression: Py::new(py, expressions::Identifier::new(Span { start: 0, end: 0 }, "<spread>".to_string()))?.into_any(),
+                expression: Py::new(py, new_identifier(Span { start: 0, end: 0 }, "<spread>".to_string(), source))?.into_any(),
+                is_empty: false,
             })?.into_any())
         }
     }
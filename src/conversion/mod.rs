@@ -12,18 +12,21 @@ pub mod typescript;
 // Re-export helper conversion functions
 pub use helpers::{
     convert_binding_identifier, convert_export_specifier, convert_identifier_name,
-    convert_import_specifier, convert_literal, compute_line_number,
+    convert_import_specifier, convert_literal, convert_numeric_literal, convert_boolean_literal,
+    convert_bigint_literal, compute_line_number, binding_pattern_names, new_identifier, new_literal,
 };
 
 // Re-export statement conversion functions
 pub use statements::{
     convert_block_statement, convert_catch_clause, convert_for_statement_init,
     convert_for_statement_left, convert_statement, convert_switch_case,
-    convert_function_body, convert_class_body,
+    convert_function_body, convert_class, convert_class_body, convert_formal_parameters,
 };
 
 // Re-export expression conversion functions
-pub use expressions::convert_expression;
+pub use expressions::{
+    convert_assignment_target, convert_binding_pattern, convert_expression, convert_member_expression, convert_simple_assignment_target,
+};
 
 // Re-export JSX conversion functions
 pub use jsx::{
@@ -35,7 +38,7 @@ pub use jsx::{
 // Re-export TypeScript conversion functions
 pub use typescript::{
     convert_ts_enum_member, convert_ts_interface_body, convert_ts_interface_heritage,
-    convert_ts_property_key, convert_ts_signature, convert_ts_type, convert_ts_type_annotation,
+    convert_ts_property_key, convert_ts_signature, convert_ts_tuple_element, convert_ts_type, convert_ts_type_annotation,
     convert_ts_type_parameter, convert_ts_type_parameter_declaration,
     convert_ts_type_parameter_instantiation,
 };
@@ -9,6 +9,7 @@ use crate::{
     Node,
     FunctionDeclaration,
     ClassDeclaration,
+    Decorator,
     VariableDeclaration,
     VariableDeclarator,
     FormalParameter,
@@ -33,18 +34,25 @@ use crate::{
     ExpressionStatement,
     DebuggerStatement,
     ImportDeclaration,
+    ImportAttribute,
     ExportNamedDeclaration,
     ExportDefaultDeclaration,
     ExportAllDeclaration,
     TSTypeAliasDeclaration,
     TSInterfaceDeclaration,
     TSEnumDeclaration,
+    TSModuleBlock,
+    TSModuleDeclaration,
 };
 use crate::nodes::expressions;
 
 use super::{
+    binding_pattern_names,
     compute_line_number,
+    new_identifier,
     convert_expression,
+    convert_assignment_target,
+    convert_binding_pattern,
     convert_literal,
     convert_import_specifier,
     convert_export_specifier,
@@ -75,25 +83,7 @@ pub fn convert_statement(stmt: &Statement, py: Python, source: &str) -> PyResult
                 None
             };
             // Convert parameters
-            let params: Vec<Py<PyAny>> = func.params.items.iter().map(|param| {
-                let param_span = Span::from(param.span);
-                let param_start = compute_line_number(source, param.span.start as usize);
-                let param_end = compute_line_number(source, param.span.end as usize);
-                let param_name = match &param.pattern.kind {
-                    oxc_ast::ast::BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.to_string()),
-                    _ => None,
-                };
-                let type_annotation = param.pattern.type_annotation.as_ref()
-                    .map(|ta| convert_ts_type_annotation(py, ta, source))
-                    .transpose().ok().flatten();
-                Py::new(py, FormalParameter {
-                    span: param_span,
-                    start_line: param_start,
-                    end_line: param_end,
-                    name: param_name,
-                    type_annotation,
-                }).unwrap().into_any()
-            }).collect();
+            let params = convert_formal_parameters(py, &func.params, source)?;
             // Convert type parameters
             let type_parameters = func.type_parameters.as_ref()
                 .map(|tp| convert_ts_type_parameter_declaration(py, tp, source))
@@ -113,86 +103,12 @@ pub fn convert_statement(stmt: &Statement, py: Python, source: &str) -> PyResult
                 params,
                 type_parameters,
                 return_type,
+                overloads: Vec::new(),
             };
             Ok(Py::new(py, node)?.into_any())
         }
-        Statement::ClassDeclaration(class) => {
-            let name = class.id.as_ref().map(|id| id.name.to_string());
-            // Extract superclass name if present
-            let superclass = class.super_class.as_ref().and_then(|expr| {
-                // Try to get identifier name from superclass expression
-                if let oxc_ast::ast::Expression::Identifier(ident) = expr {
-                    Some(ident.name.to_string())
-                } else {
-                    Some("<expression>".to_string())
-                }
-            });
-            // Convert type parameters
-            let type_parameters = class.type_parameters.as_ref()
-                .map(|tp| convert_ts_type_parameter_declaration(py, tp, source))
-                .transpose()?;
-            // Convert class body (methods)
-            let body = Some(convert_class_body(py, &class.body, source)?);
-            let node = ClassDeclaration {
-                span: span_converted,
-                start_line,
-                end_line,
-                name,
-                superclass,
-                type_parameters,
-                body,
-            };
-            Ok(Py::new(py, node)?.into_any())
-        }
-        Statement::VariableDeclaration(var) => {
-            let kind = match var.kind {
-                oxc_ast::ast::VariableDeclarationKind::Const => "const",
-                oxc_ast::ast::VariableDeclarationKind::Let => "let",
-                oxc_ast::ast::VariableDeclarationKind::Var => "var",
-                oxc_ast::ast::VariableDeclarationKind::Using => "using",
-                oxc_ast::ast::VariableDeclarationKind::AwaitUsing => "await using",
-            }.to_string();
-            // Convert declarators
-            let declarations: Vec<Py<PyAny>> = var.declarations.iter().map(|decl| {
-                let decl_span = Span::from(decl.span);
-                let decl_start_line = compute_line_number(source, decl.span.start as usize);
-                let decl_end_line = compute_line_number(source, decl.span.end as usize);
-                // Convert id (identifier)
-                let id = match &decl.id.kind {
-                    oxc_ast::ast::BindingPatternKind::BindingIdentifier(ident) => {
-                        Some(Py::new(py, expressions::Identifier::new(Span::from(ident.span), ident.name.to_string())).unwrap().into_any())
-                    }
-                    _ => None,
-                };
-                // Convert type annotation if present
-                let type_annotation = decl.id.type_annotation.as_ref()
-                    .map(|ta| convert_ts_type_annotation(py, ta, source))
-                    .transpose().ok().flatten();
-                // Convert init expression if present using full expression conversion
-                // This properly handles JSX, arrow functions, conditionals, etc.
-                let init: Option<Py<PyAny>> = decl.init.as_ref()
-                    .map(|init_expr| convert_expression(py, init_expr, source))
-                    .transpose()
-                    .ok()
-                    .flatten();
-                Py::new(py, VariableDeclarator {
-                    span: decl_span,
-                    start_line: decl_start_line,
-                    end_line: decl_end_line,
-                    id,
-                    init,
-                    type_annotation,
-                }).unwrap().into_any()
-            }).collect();
-            let node = VariableDeclaration {
-                span: span_converted,
-                start_line,
-                end_line,
-                kind,
-                declarations,
-            };
-            Ok(Py::new(py, node)?.into_any())
-        }
+        Statement::ClassDeclaration(class) => convert_class(py, class, source),
+        Statement::VariableDeclaration(var) => convert_variable_declaration(py, var, source),
         // Phase 15: Import/Export Declarations
         Statement::ImportDeclaration(import_decl) => {
             // Convert source module path (StringLiteral)
@@ -209,12 +125,33 @@ pub fn convert_statement(stmt: &Statement, py: Python, source: &str) -> PyResult
                 }
             }
 
+            // Convert import attributes (with { type: "json" })
+            let with_clause = import_decl.with_clause.as_ref()
+                .map(|clause| {
+                    clause.with_entries.iter().map(|attr| {
+                        let key = match &attr.key {
+                            oxc_ast::ast::ImportAttributeKey::Identifier(ident) => ident.name.to_string(),
+                            oxc_ast::ast::ImportAttributeKey::StringLiteral(lit) => lit.value.to_string(),
+                        };
+                        Py::new(py, ImportAttribute {
+                            span: Span::from(attr.span),
+                            start_line: compute_line_number(source, attr.span.start as usize),
+                            end_line: compute_line_number(source, attr.span.end as usize),
+                            key,
+                            value: attr.value.value.to_string(),
+                        })
+                    }).collect::<PyResult<Vec<_>>>()
+                })
+                .transpose()?;
+
             let node = ImportDeclaration {
                 span: span_converted,
                 start_line,
                 end_line,
                 source: source_literal,
                 specifiers,
+                with_clause,
+                import_kind: if import_decl.import_kind.is_type() { "type" } else { "value" }.to_string(),
             };
             Ok(Py::new(py, node)?.into_any())
         }
@@ -247,46 +184,15 @@ pub fn convert_statement(stmt: &Statement, py: Python, source: &str) -> PyResult
                                 params: Vec::new(),
                                 type_parameters: None,
                                 return_type: None,
+                                overloads: Vec::new(),
                             };
                             Py::new(py, decl_node).map(|p| p.into_any())
                         }
                         oxc_ast::ast::Declaration::ClassDeclaration(class) => {
-                            let name = class.id.as_ref().map(|id| id.name.to_string());
-                            let superclass = class.super_class.as_ref().and_then(|expr| {
-                                if let oxc_ast::ast::Expression::Identifier(ident) = expr {
-                                    Some(ident.name.to_string())
-                                } else {
-                                    Some("<expression>".to_string())
-                                }
-                            });
-                            let body = convert_class_body(py, &class.body, source).ok();
-                            let decl_node = ClassDeclaration {
-                                span: span_converted,
-                                start_line,
-                                end_line,
-                                name,
-                                superclass,
-                                type_parameters: None,
-                                body,
-                            };
-                            Py::new(py, decl_node).map(|p| p.into_any())
+                            convert_class(py, class, source)
                         }
                         oxc_ast::ast::Declaration::VariableDeclaration(var) => {
-                            let kind = match var.kind {
-                                oxc_ast::ast::VariableDeclarationKind::Const => "const",
-                                oxc_ast::ast::VariableDeclarationKind::Let => "let",
-                                oxc_ast::ast::VariableDeclarationKind::Var => "var",
-                                oxc_ast::ast::VariableDeclarationKind::Using => "using",
-                                oxc_ast::ast::VariableDeclarationKind::AwaitUsing => "await using",
-                            }.to_string();
-                            let decl_node = VariableDeclaration {
-                                span: span_converted,
-                                start_line,
-                                end_line,
-                                kind,
-                                declarations: Vec::new(),
-                            };
-                            Py::new(py, decl_node).map(|p| p.into_any())
+                            convert_variable_declaration(py, var, source)
                         }
                         oxc_ast::ast::Declaration::TSInterfaceDeclaration(ts_interface) => {
                             let name = ts_interface.id.name.to_string();
@@ -301,6 +207,12 @@ pub fn convert_statement(stmt: &Statement, py: Python, source: &str) -> PyResult
                             };
                             Py::new(py, decl_node).map(|p| p.into_any())
                         }
+                        oxc_ast::ast::Declaration::TSModuleDeclaration(module) => {
+                            convert_ts_module_declaration(py, module, source)
+                        }
+                        oxc_ast::ast::Declaration::TSEnumDeclaration(ts_enum) => {
+                            convert_ts_enum_declaration(py, ts_enum, source)
+                        }
                         oxc_ast::ast::Declaration::TSTypeAliasDeclaration(ts_type) => {
                             let name = ts_type.id.name.to_string();
                             let decl_node = TSTypeAliasDeclaration {
@@ -367,29 +279,12 @@ pub fn convert_statement(stmt: &Statement, py: Python, source: &str) -> PyResult
                         params: Vec::new(),
                         type_parameters: None,
                         return_type: None,
+                        overloads: Vec::new(),
                     };
                     Py::new(py, decl_node)?.into_any()
                 }
                 oxc_ast::ast::ExportDefaultDeclarationKind::ClassDeclaration(class) => {
-                    let name = class.id.as_ref().map(|id| id.name.to_string());
-                    let superclass = class.super_class.as_ref().and_then(|expr| {
-                        if let oxc_ast::ast::Expression::Identifier(ident) = expr {
-                            Some(ident.name.to_string())
-                        } else {
-                            Some("<expression>".to_string())
-                        }
-                    });
-                    let body = convert_class_body(py, &class.body, source).ok();
-                    let decl_node = ClassDeclaration {
-                        span: Span::from(decl_span),
-                        start_line: compute_line_number(source, decl_span.start as usize),
-                        end_line: compute_line_number(source, decl_span.end as usize),
-                        name,
-                        superclass,
-                        type_parameters: None,
-                        body,
-                    };
-                    Py::new(py, decl_node)?.into_any()
+                    convert_class(py, class, source)?
                 }
                 _ => {
                     // For all other cases (expressions, etc.), create a generic Node
@@ -419,7 +314,7 @@ pub fn convert_statement(stmt: &Statement, py: Python, source: &str) -> PyResult
                     let span = export_name.span();
                     let span_converted = Span::from(span);
                     let name = export_name.name().to_string();
-                    let node = expressions::Identifier::new(span_converted, name);
+                    let node = new_identifier(span_converted, name, source);
                     Py::new(py, node).map(|p| p.into_any())
                 })
                 .transpose()?;
@@ -472,27 +367,13 @@ pub fn convert_statement(stmt: &Statement, py: Python, source: &str) -> PyResult
             };
             Ok(Py::new(py, node)?.into_any())
         }
-        Statement::TSEnumDeclaration(ts_enum) => {
-            let name = ts_enum.id.name.to_string();
-            let members: Vec<Py<PyAny>> = ts_enum.body.members.iter()
-                .filter_map(|m| convert_ts_enum_member(py, m, source).ok())
-                .collect();
-            let is_const = ts_enum.r#const;
-            let node = TSEnumDeclaration {
-                span: span_converted,
-                start_line,
-                end_line,
-                name,
-                members,
-                is_const,
-            };
-            Ok(Py::new(py, node)?.into_any())
-        }
+        Statement::TSEnumDeclaration(ts_enum) => convert_ts_enum_declaration(py, ts_enum, source),
+        Statement::TSModuleDeclaration(module) => convert_ts_module_declaration(py, module, source),
         // Phase 13: Additional statement types
         Statement::BreakStatement(break_stmt) => {
             let label = break_stmt.label.as_ref().map(|l| {
                 let label_span = Span::from(l.span);
-                Py::new(py, expressions::Identifier::new(label_span, l.name.to_string())).unwrap().into_any()
+                Py::new(py, new_identifier(label_span, l.name.to_string(), source)).unwrap().into_any()
             });
             let node = BreakStatement {
                 span: span_converted,
@@ -505,7 +386,7 @@ pub fn convert_statement(stmt: &Statement, py: Python, source: &str) -> PyResult
         Statement::ContinueStatement(continue_stmt) => {
             let label = continue_stmt.label.as_ref().map(|l| {
                 let label_span = Span::from(l.span);
-                Py::new(py, expressions::Identifier::new(label_span, l.name.to_string())).unwrap().into_any()
+                Py::new(py, new_identifier(label_span, l.name.to_string(), source)).unwrap().into_any()
             });
             let node = ContinueStatement {
                 span: span_converted,
@@ -518,7 +399,7 @@ pub fn convert_statement(stmt: &Statement, py: Python, source: &str) -> PyResult
         Statement::LabeledStatement(labeled_stmt) => {
             let label = {
                 let label_span = Span::from(labeled_stmt.label.span);
-                Some(Py::new(py, expressions::Identifier::new(label_span, labeled_stmt.label.name.to_string())).unwrap().into_any())
+                Some(Py::new(py, new_identifier(label_span, labeled_stmt.label.name.to_string(), source)).unwrap().into_any())
             };
             let body = Some(convert_statement(&labeled_stmt.body, py, source)?);
             let node = LabeledStatement {
@@ -539,14 +420,7 @@ pub fn convert_statement(stmt: &Statement, py: Python, source: &str) -> PyResult
             Ok(Py::new(py, node)?.into_any())
         }
         Statement::WithStatement(with_stmt) => {
-            // Convert expression to a generic node for now
-            let object_span = Span::from(with_stmt.object.span());
-            let object_start = compute_line_number(source, with_stmt.object.span().start as usize);
-            let object_end = compute_line_number(source, with_stmt.object.span().end as usize);
-            let mut object_node = Node::new("Expression".to_string(), object_span);
-            object_node.start_line = object_start;
-            object_node.end_line = object_end;
-            let object = Some(Py::new(py, object_node)?.into_any());
+            let object = Some(convert_expression(py, &with_stmt.object, source)?);
             let body = Some(convert_statement(&with_stmt.body, py, source)?);
             let node = WithStatement {
                 span: span_converted,
@@ -765,37 +639,66 @@ pub fn convert_statement(stmt: &Statement, py: Python, source: &str) -> PyResult
     }
 }
 
+/// Convert a VariableDeclaration with all of its declarators
+fn convert_variable_declaration(py: Python, var: &oxc_ast::ast::VariableDeclaration, source: &str) -> PyResult<Py<PyAny>> {
+    let span_converted = Span::from(var.span);
+    let start_line = compute_line_number(source, var.span.start as usize);
+    let end_line = compute_line_number(source, var.span.end as usize);
+    let kind = match var.kind {
+        oxc_ast::ast::VariableDeclarationKind::Const => "const",
+        oxc_ast::ast::VariableDeclarationKind::Let => "let",
+        oxc_ast::ast::VariableDeclarationKind::Var => "var",
+        oxc_ast::ast::VariableDeclarationKind::Using => "using",
+        oxc_ast::ast::VariableDeclarationKind::AwaitUsing => "await using",
+    }.to_string();
+    // Convert declarators
+    let declarations: Vec<Py<PyAny>> = var.declarations.iter().map(|decl| {
+        let decl_span = Span::from(decl.span);
+        let decl_start_line = compute_line_number(source, decl.span.start as usize);
+        let decl_end_line = compute_line_number(source, decl.span.end as usize);
+        // Convert id (identifier)
+        let id = match &decl.id.kind {
+            oxc_ast::ast::BindingPatternKind::BindingIdentifier(ident) => {
+                Some(Py::new(py, new_identifier(Span::from(ident.span), ident.name.to_string(), source)).unwrap().into_any())
+            }
+            _ => None,
+        };
+        // Convert type annotation if present
+        let type_annotation = decl.id.type_annotation.as_ref()
+            .map(|ta| convert_ts_type_annotation(py, ta, source))
+            .transpose().ok().flatten();
+        // Convert init expression if present using full expression conversion
+        // This properly handles JSX, arrow functions, conditionals, etc.
+        let init: Option<Py<PyAny>> = decl.init.as_ref()
+            .map(|init_expr| convert_expression(py, init_expr, source))
+            .transpose()
+            .ok()
+            .flatten();
+        Py::new(py, VariableDeclarator {
+            span: decl_span,
+            start_line: decl_start_line,
+            end_line: decl_end_line,
+            id,
+            init,
+            type_annotation,
+            bound_names: binding_pattern_names(&decl.id),
+        }).unwrap().into_any()
+    }).collect();
+    let node = VariableDeclaration {
+        span: span_converted,
+        start_line,
+        end_line,
+        kind,
+        declarations,
+    };
+    Ok(Py::new(py, node)?.into_any())
+}
+
 pub fn convert_for_statement_init(py: Python, init: &oxc_ast::ast::ForStatementInit, source: &str) -> PyResult<Py<PyAny>> {
     use oxc_ast::ast::ForStatementInit;
     match init {
-        ForStatementInit::VariableDeclaration(var) => {
-            let span_converted = Span::from(var.span);
-            let start_line = compute_line_number(source, var.span.start as usize);
-            let end_line = compute_line_number(source, var.span.end as usize);
-            let kind = match var.kind {
-                oxc_ast::ast::VariableDeclarationKind::Const => "const",
-                oxc_ast::ast::VariableDeclarationKind::Let => "let",
-                oxc_ast::ast::VariableDeclarationKind::Var => "var",
-                oxc_ast::ast::VariableDeclarationKind::Using => "using",
-                oxc_ast::ast::VariableDeclarationKind::AwaitUsing => "await using",
-            }.to_string();
-            let node = VariableDeclaration {
-                span: span_converted,
-                start_line,
-                end_line,
-                kind,
-                declarations: Vec::new(),
-            };
-            Ok(Py::new(py, node)?.into_any())
-        }
-        _ => {
-            // Expression init
-            let expr_span = init.span();
-            let mut node = Node::new("Expression".to_string(), Span::from(expr_span));
-            node.start_line = compute_line_number(source, expr_span.start as usize);
-            node.end_line = compute_line_number(source, expr_span.end as usize);
-            Ok(Py::new(py, node)?.into_any())
-        }
+        ForStatementInit::VariableDeclaration(var) => convert_variable_declaration(py, var, source),
+        _ => convert_expression(py, init.to_expression(), source),
     }
 }
 
@@ -803,34 +706,8 @@ pub fn convert_for_statement_init(py: Python, init: &oxc_ast::ast::ForStatementI
 pub fn convert_for_statement_left(py: Python, left: &oxc_ast::ast::ForStatementLeft, source: &str) -> PyResult<Py<PyAny>> {
     use oxc_ast::ast::ForStatementLeft;
     match left {
-        ForStatementLeft::VariableDeclaration(var) => {
-            let span_converted = Span::from(var.span);
-            let start_line = compute_line_number(source, var.span.start as usize);
-            let end_line = compute_line_number(source, var.span.end as usize);
-            let kind = match var.kind {
-                oxc_ast::ast::VariableDeclarationKind::Const => "const",
-                oxc_ast::ast::VariableDeclarationKind::Let => "let",
-                oxc_ast::ast::VariableDeclarationKind::Var => "var",
-                oxc_ast::ast::VariableDeclarationKind::Using => "using",
-                oxc_ast::ast::VariableDeclarationKind::AwaitUsing => "await using",
-            }.to_string();
-            let node = VariableDeclaration {
-                span: span_converted,
-                start_line,
-                end_line,
-                kind,
-                declarations: Vec::new(),
-            };
-            Ok(Py::new(py, node)?.into_any())
-        }
-        _ => {
-            // AssignmentTarget
-            let target_span = left.span();
-            let mut node = Node::new("AssignmentTarget".to_string(), Span::from(target_span));
-            node.start_line = compute_line_number(source, target_span.start as usize);
-            node.end_line = compute_line_number(source, target_span.end as usize);
-            Ok(Py::new(py, node)?.into_any())
-        }
+        ForStatementLeft::VariableDeclaration(var) => convert_variable_declaration(py, var, source),
+        _ => convert_assignment_target(py, left.to_assignment_target(), source),
     }
 }
 
@@ -866,14 +743,10 @@ pub fn convert_catch_clause(py: Python, clause: &oxc_ast::ast::CatchClause, sour
     let start_line = compute_line_number(source, clause_span.start as usize);
     let end_line = compute_line_number(source, clause_span.end as usize);
 
-    let param = clause.param.as_ref().map(|p| {
-        let param_span = p.span();
-        let name = match &p.pattern.kind {
-            oxc_ast::ast::BindingPatternKind::BindingIdentifier(ident) => ident.name.to_string(),
-            _ => "param".to_string(),
-        };
-        Py::new(py, expressions::Identifier::new(Span::from(param_span), name)).unwrap().into_any()
-    });
+    // Destructured bindings become ObjectPattern / ArrayPattern nodes
+    let param = clause.param.as_ref()
+        .map(|p| convert_binding_pattern(py, &p.pattern, source))
+        .transpose()?;
 
     let body = Some(convert_block_statement(py, &clause.body, source)?);
 
@@ -906,6 +779,63 @@ pub fn convert_block_statement(py: Python, block: &oxc_ast::ast::BlockStatement,
     Ok(Py::new(py, node)?.into_any())
 }
 
+/// Convert an enum declaration, including `const` and `declare` enums
+fn convert_ts_enum_declaration(py: Python, ts_enum: &oxc_ast::ast::TSEnumDeclaration, source: &str) -> PyResult<Py<PyAny>> {
+    let members: Vec<Py<PyAny>> = ts_enum.body.members.iter()
+        .filter_map(|m| convert_ts_enum_member(py, m, source).ok())
+        .collect();
+    let node = TSEnumDeclaration {
+        span: Span::from(ts_enum.span),
+        start_line: compute_line_number(source, ts_enum.span.start as usize),
+        end_line: compute_line_number(source, ts_enum.span.end as usize),
+        name: ts_enum.id.name.to_string(),
+        members,
+        is_const: ts_enum.r#const,
+    };
+    Ok(Py::new(py, node)?.into_any())
+}
+
+/// Convert a namespace or ambient module declaration; `namespace A.B {}` nests
+pub fn convert_ts_module_declaration(py: Python, module: &oxc_ast::ast::TSModuleDeclaration, source: &str) -> PyResult<Py<PyAny>> {
+    use oxc_ast::ast::{TSModuleDeclarationBody, TSModuleDeclarationKind, TSModuleDeclarationName};
+
+    let name = match &module.id {
+        TSModuleDeclarationName::Identifier(ident) => ident.name.to_string(),
+        TSModuleDeclarationName::StringLiteral(lit) => lit.value.to_string(),
+    };
+    let kind = match module.kind {
+        TSModuleDeclarationKind::Global => "global",
+        TSModuleDeclarationKind::Module => "module",
+        TSModuleDeclarationKind::Namespace => "namespace",
+    }.to_string();
+    let body = module.body.as_ref().map(|body| match body {
+        TSModuleDeclarationBody::TSModuleDeclaration(nested) => convert_ts_module_declaration(py, nested, source),
+        TSModuleDeclarationBody::TSModuleBlock(block) => {
+            let statements = block.body.iter()
+                .map(|stmt| convert_statement(stmt, py, source))
+                .collect::<PyResult<Vec<_>>>()?;
+            let node = TSModuleBlock {
+                span: Span::from(block.span),
+                start_line: compute_line_number(source, block.span.start as usize),
+                end_line: compute_line_number(source, block.span.end as usize),
+                body: statements,
+            };
+            Ok(Py::new(py, node)?.into_any())
+        }
+    }).transpose()?;
+
+    let node = TSModuleDeclaration {
+        span: Span::from(module.span),
+        start_line: compute_line_number(source, module.span.start as usize),
+        end_line: compute_line_number(source, module.span.end as usize),
+        name,
+        kind,
+        declare: module.declare,
+        body,
+    };
+    Ok(Py::new(py, node)?.into_any())
+}
+
 /// Helper function to get statement type string
 pub fn get_statement_type_str(stmt: &Statement) -> &'static str {
     use oxc_ast::ast::Statement as S;
@@ -968,19 +898,60 @@ pub fn convert_function_body(
     Ok(Py::new(py, node)?.into_any())
 }
 
+/// Helper function to convert a class declaration or class expression
+pub fn convert_class(
+    py: Python,
+    class: &oxc_ast::ast::Class,
+    source: &str,
+) -> PyResult<Py<PyAny>> {
+    let name = class.id.as_ref().map(|id| id.name.to_string());
+    // Extract superclass name if present
+    let superclass = class.super_class.as_ref().map(|expr| {
+        // Try to get identifier name from superclass expression
+        if let oxc_ast::ast::Expression::Identifier(ident) = expr {
+            ident.name.to_string()
+        } else {
+            "<expression>".to_string()
+        }
+    });
+    // TypeScript `implements` clause, as dotted type names
+    let implements = class.implements.iter()
+        .map(|clause| clause.expression.to_string())
+        .collect();
+    // Convert type parameters
+    let type_parameters = class.type_parameters.as_ref()
+        .map(|tp| convert_ts_type_parameter_declaration(py, tp, source))
+        .transpose()?;
+    // Convert class body (methods)
+    let body = Some(convert_class_body(py, &class.body, source)?);
+    let decorators = convert_decorators(py, &class.decorators, source)?;
+    let node = ClassDeclaration {
+        span: Span::from(class.span),
+        start_line: compute_line_number(source, class.span.start as usize),
+        end_line: compute_line_number(source, class.span.end as usize),
+        name,
+        superclass,
+        implements,
+        type_parameters,
+        body,
+        decorators,
+    };
+    Ok(Py::new(py, node)?.into_any())
+}
+
 /// Helper function to convert class body (ClassBody -> list of methods/properties)
 pub fn convert_class_body(
     py: Python,
     body: &oxc_ast::ast::ClassBody,
     source: &str,
 ) -> PyResult<Py<PyAny>> {
-    use oxc_ast::ast::ClassElement;
+    use oxc_ast::ast::{ClassElement, MethodDefinitionKind, MethodDefinitionType, PropertyDefinitionType};
 
     let span_converted = Span::from(body.span);
     let start_line = compute_line_number(source, body.span.start as usize);
     let end_line = compute_line_number(source, body.span.end as usize);
 
-    let mut methods: Vec<Py<PyAny>> = Vec::new();
+    let mut elements: Vec<Py<PyAny>> = Vec::new();
 
     for element in &body.body {
         match element {
@@ -996,47 +967,212 @@ pub fn convert_class_body(
                 let function_body = method.value.body.as_ref()
                     .and_then(|fb| convert_function_body(py, fb, source).ok());
 
-                let params: Vec<Py<PyAny>> = method.value.params.items.iter().map(|param| {
-                    let param_span = Span::from(param.span);
-                    let param_start = compute_line_number(source, param.span.start as usize);
-                    let param_end = compute_line_number(source, param.span.end as usize);
-                    let param_name = match &param.pattern.kind {
-                        oxc_ast::ast::BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.to_string()),
-                        _ => None,
+                let params = convert_formal_parameters(py, &method.value.params, source)?;
+                let decorators = convert_decorators(py, &method.decorators, source)?;
+
+                if method.r#type == MethodDefinitionType::TSAbstractMethodDefinition {
+                    let abstract_node = crate::TSAbstractMethodDefinition {
+                        span: method_span,
+                        start_line: method_start,
+                        end_line: method_end,
+                        name,
+                        is_async,
+                        is_generator,
+                        function_body,
+                        params,
+                        decorators,
+                        r#abstract: true,
                     };
-                    Py::new(py, FormalParameter {
-                        span: param_span,
-                        start_line: param_start,
-                        end_line: param_end,
-                        name: param_name,
-                        type_annotation: None,
-                    }).unwrap().into_any()
-                }).collect();
+                    elements.push(Py::new(py, abstract_node)?.into_any());
+                    continue;
+                }
+
+                let type_parameters = method.value.type_parameters.as_ref()
+                    .map(|tp| convert_ts_type_parameter_declaration(py, tp, source))
+                    .transpose()?;
+                let return_type = method.value.return_type.as_ref()
+                    .map(|rt| convert_ts_type_annotation(py, rt, source))
+                    .transpose()?;
+                let kind = match method.kind {
+                    MethodDefinitionKind::Constructor => "constructor",
+                    MethodDefinitionKind::Method => "method",
+                    MethodDefinitionKind::Get => "get",
+                    MethodDefinitionKind::Set => "set",
+                }.to_string();
 
-                let method_node = crate::MethodDefinition {
+                let method_node = Py::new(py, crate::MethodDefinition {
                     span: method_span,
                     start_line: method_start,
                     end_line: method_end,
                     name,
+                    kind,
                     is_async,
                     is_generator,
                     function_body,
                     params,
+                    decorators,
+                    type_parameters,
+                    return_type,
+                    accessibility: method.accessibility.map(|a| a.as_str().to_string()),
+                })?.into_any();
+                elements.push(method_node);
+            }
+            ClassElement::PropertyDefinition(prop) => {
+                let prop_span = Span::from(prop.span);
+                let prop_start = compute_line_number(source, prop.span.start as usize);
+                let prop_end = compute_line_number(source, prop.span.end as usize);
+
+                let name = prop.key.static_name().map(|n| n.to_string());
+                let value = prop.value.as_ref()
+                    .map(|v| convert_expression(py, v, source))
+                    .transpose()?;
+                let type_annotation = prop.type_annotation.as_ref()
+                    .map(|ta| convert_ts_type_annotation(py, ta, source))
+                    .transpose()?;
+
+                let prop_node = if prop.r#type == PropertyDefinitionType::TSAbstractPropertyDefinition {
+                    Py::new(py, crate::TSAbstractPropertyDefinition {
+                        span: prop_span,
+                        start_line: prop_start,
+                        end_line: prop_end,
+                        name,
+                        value,
+                        type_annotation,
+                        is_static: prop.r#static,
+                        readonly: prop.readonly,
+                        r#abstract: true,
+                    })?.into_any()
+                } else {
+                    Py::new(py, crate::ClassProperty {
+                        span: prop_span,
+                        start_line: prop_start,
+                        end_line: prop_end,
+                        name,
+                        value,
+                        type_annotation,
+                        is_static: prop.r#static,
+                        readonly: prop.readonly,
+                        accessibility: prop.accessibility.map(|a| a.as_str().to_string()),
+                    })?.into_any()
                 };
-                methods.push(Py::new(py, method_node)?.into_any());
+                elements.push(prop_node);
+            }
+            ClassElement::StaticBlock(block) => {
+                let mut body_stmts = Vec::new();
+                for stmt in &block.body {
+                    body_stmts.push(convert_statement(stmt, py, source)?);
+                }
+                elements.push(Py::new(py, crate::StaticBlock {
+                    span: Span::from(block.span),
+                    start_line: compute_line_number(source, block.span.start as usize),
+                    end_line: compute_line_number(source, block.span.end as usize),
+                    body: body_stmts,
+                })?.into_any());
+            }
+            ClassElement::AccessorProperty(prop) => {
+                let value = prop.value.as_ref()
+                    .map(|v| convert_expression(py, v, source))
+                    .transpose()?;
+                let type_annotation = prop.type_annotation.as_ref()
+                    .map(|ta| convert_ts_type_annotation(py, ta, source))
+                    .transpose()?;
+                elements.push(Py::new(py, crate::AccessorProperty {
+                    span: Span::from(prop.span),
+                    start_line: compute_line_number(source, prop.span.start as usize),
+                    end_line: compute_line_number(source, prop.span.end as usize),
+                    name: prop.key.static_name().map(|n| n.to_string()),
+                    value,
+                    type_annotation,
+                    is_static: prop.r#static,
+                    computed: prop.computed,
+                })?.into_any());
             }
-            _ => {
-                // Skip other class elements for now (properties, static blocks, etc.)
+            ClassElement::TSIndexSignature(sig) => {
+                let mut node = Node::new("TSIndexSignature".to_string(), Span::from(sig.span));
+                node.start_line = compute_line_number(source, sig.span.start as usize);
+                node.end_line = compute_line_number(source, sig.span.end as usize);
+                elements.push(Py::new(py, node)?.into_any());
             }
         }
     }
 
-    // Return ClassBody struct with methods exposed
+    // Return ClassBody struct with all elements
     let class_body = crate::ClassBody {
         span: span_converted,
         start_line,
         end_line,
-        methods,
+        body: elements,
     };
     Ok(Py::new(py, class_body)?.into_any())
 }
+
+/// Convert class or method decorators, keeping their source order
+fn convert_decorators(
+    py: Python,
+    decorators: &[oxc_ast::ast::Decorator],
+    source: &str,
+) -> PyResult<Vec<Py<PyAny>>> {
+    decorators.iter().map(|decorator| {
+        let expression = convert_expression(py, &decorator.expression, source)?;
+        Py::new(py, Decorator {
+            span: Span::from(decorator.span),
+            start_line: compute_line_number(source, decorator.span.start as usize),
+            end_line: compute_line_number(source, decorator.span.end as usize),
+            expression,
+        }).map(|p| p.into_any())
+    }).collect()
+}
+
+/// Convert function parameters into FormalParameter nodes, with a trailing
+/// RestElement for `...rest`
+pub fn convert_formal_parameters(
+    py: Python,
+    params: &oxc_ast::ast::FormalParameters,
+    source: &str,
+) -> PyResult<Vec<Py<PyAny>>> {
+    let mut nodes = params.items.iter()
+        .map(|param| convert_formal_parameter(py, param.span, &param.pattern, source))
+        .collect::<PyResult<Vec<_>>>()?;
+    if let Some(rest) = &params.rest {
+        let argument = convert_formal_parameter(py, rest.argument.span(), &rest.argument, source)?;
+        nodes.push(Py::new(py, expressions::RestElement {
+            span: Span::from(rest.span),
+            argument,
+            start_line: compute_line_number(source, rest.span.start as usize),
+            end_line: compute_line_number(source, rest.span.end as usize),
+        })?.into_any());
+    }
+    Ok(nodes)
+}
+
+/// FormalParameter for one binding; destructuring patterns have no name
+fn convert_formal_parameter(
+    py: Python,
+    span: oxc_span::Span,
+    pattern: &oxc_ast::ast::BindingPattern,
+    source: &str,
+) -> PyResult<Py<PyAny>> {
+    use oxc_ast::ast::BindingPatternKind;
+
+    // `c: T = 1` is still the identifier `c` (and its annotation), with a default
+    let target = match &pattern.kind {
+        BindingPatternKind::AssignmentPattern(assign) => &assign.left,
+        _ => pattern,
+    };
+    let name = match &target.kind {
+        BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.to_string()),
+        _ => None,
+    };
+    let type_annotation = pattern.type_annotation.as_ref()
+        .or(target.type_annotation.as_ref())
+        .map(|ta| convert_ts_type_annotation(py, ta, source))
+        .transpose()?;
+    Py::new(py, FormalParameter {
+        span: Span::from(span),
+        start_line: compute_line_number(source, span.start as usize),
+        end_line: compute_line_number(source, span.end as usize),
+        name,
+        type_annotation,
+        bound_names: binding_pattern_names(pattern),
+    }).map(|p| p.into_any())
+}
@@ -6,10 +6,19 @@ use crate::nodes::typescript::{
     TSTypeReference, TSUnionType, TSIntersectionType,
     TSTypeAnnotation, TSTypeParameterDeclaration, TSTypeParameter,
     TSPropertySignature, TSMethodSignature,
-    TSInterfaceBody, TSEnumMember,
+    TSInterfaceBody, TSInterfaceHeritage, TSEnumMember,
+    TSConditionalType, TSInferType, TSImportType, TSLiteralType,
+    TSArrayType, TSTupleType, TSOptionalType, TSRestType,
+    TSAnyKeyword, TSBigIntKeyword, TSBooleanKeyword, TSIntrinsicKeyword,
+    TSNeverKeyword, TSNullKeyword, TSNumberKeyword, TSObjectKeyword,
+    TSStringKeyword, TSSymbolKeyword, TSUndefinedKeyword, TSUnknownKeyword,
+    TSVoidKeyword, TSThisType,
+};
+use crate::conversion::expressions::{convert_expression, convert_template_literal, convert_unary_expression};
+use crate::conversion::helpers::{
+    compute_line_number, convert_literal, convert_numeric_literal, convert_boolean_literal,
+    convert_bigint_literal, new_identifier,
 };
-use crate::nodes::expressions;
-use crate::conversion::helpers::compute_line_number;
 
 // =============================================================================
 // Phase 16: TypeScript Type Conversion Functions
@@ -30,11 +39,11 @@ pub fn convert_ts_type(py: Python, ts_type: &oxc_ast::ast::TSType, source: &str)
             let type_name = match &type_ref.type_name {
                 oxc_ast::ast::TSTypeName::IdentifierReference(ident) => {
                     let ident_span = Span::from(ident.span);
-                    Some(Py::new(py, expressions::Identifier::new(ident_span, ident.name.to_string()))?.into_any())
+                    Some(Py::new(py, new_identifier(ident_span, ident.name.to_string(), source))?.into_any())
                 }
                 oxc_ast::ast::TSTypeName::QualifiedName(qname) => {
                     let ident_span = Span::from(qname.span());
-                    Some(Py::new(py, expressions::Identifier::new(ident_span, format!("{}.{}", qname.left, qname.right.name)))?.into_any())
+                    Some(Py::new(py, new_identifier(ident_span, format!("{}.{}", qname.left, qname.right.name), source))?.into_any())
                 }
                 _ => None,
             };
@@ -51,20 +60,68 @@ pub fn convert_ts_type(py: Python, ts_type: &oxc_ast::ast::TSType, source: &str)
             let types: Vec<Py<PyAny>> = intersection.types.iter().filter_map(|t| convert_ts_type(py, t, source).ok()).collect();
             Ok(Py::new(py, TSIntersectionType { span: span_converted, start_line, end_line, types })?.into_any())
         }
-        _ => {
-            let type_str = match ts_type {
-                TSType::TSAnyKeyword(_) => "TSAnyKeyword",
-                TSType::TSBooleanKeyword(_) => "TSBooleanKeyword",
-                TSType::TSNeverKeyword(_) => "TSNeverKeyword",
-                TSType::TSNullKeyword(_) => "TSNullKeyword",
-                TSType::TSNumberKeyword(_) => "TSNumberKeyword",
-                TSType::TSStringKeyword(_) => "TSStringKeyword",
-                TSType::TSUndefinedKeyword(_) => "TSUndefinedKeyword",
-                TSType::TSUnknownKeyword(_) => "TSUnknownKeyword",
-                TSType::TSVoidKeyword(_) => "TSVoidKeyword",
-                _ => "TSType",
+        TSType::TSConditionalType(cond) => {
+            let check_type = convert_ts_type(py, &cond.check_type, source)?;
+            let extends_type = convert_ts_type(py, &cond.extends_type, source)?;
+            let true_type = convert_ts_type(py, &cond.true_type, source)?;
+            let false_type = convert_ts_type(py, &cond.false_type, source)?;
+            Ok(Py::new(py, TSConditionalType { span: span_converted, start_line, end_line, check_type, extends_type, true_type, false_type })?.into_any())
+        }
+        TSType::TSInferType(infer) => {
+            let type_parameter = convert_ts_type_parameter(py, &infer.type_parameter, source)?;
+            Ok(Py::new(py, TSInferType { span: span_converted, start_line, end_line, type_parameter })?.into_any())
+        }
+        TSType::TSLiteralType(lit_type) => {
+            use oxc_ast::ast::TSLiteral;
+            let literal = match &lit_type.literal {
+                TSLiteral::StringLiteral(lit) => convert_literal(py, lit, source)?,
+                TSLiteral::NumericLiteral(lit) => convert_numeric_literal(py, lit, source)?,
+                TSLiteral::BooleanLiteral(lit) => convert_boolean_literal(py, lit, source)?,
+                TSLiteral::BigIntLiteral(lit) => convert_bigint_literal(py, lit, source)?,
+                TSLiteral::UnaryExpression(unary) => convert_unary_expression(py, unary, source)?,
+                TSLiteral::TemplateLiteral(template) => convert_template_literal(py, template, source)?,
             };
-            let mut node = Node::new(type_str.to_string(), span_converted);
+            Ok(Py::new(py, TSLiteralType { span: span_converted, start_line, end_line, literal })?.into_any())
+        }
+        TSType::TSImportType(import) => {
+            let argument = convert_ts_type(py, &import.argument, source)?;
+            // Qualified names (import("m").a.b) collapse to a single dotted Identifier
+            let qualifier = import.qualifier.as_ref().map(|q| {
+                let q_span = q.span();
+                let name = source[q_span.start as usize..q_span.end as usize].to_string();
+                Py::new(py, new_identifier(Span::from(q_span), name, source)).map(|p| p.into_any())
+            }).transpose()?;
+            let type_arguments = import.type_arguments.as_ref()
+                .map(|ta| convert_ts_type_parameter_instantiation(py, ta, source))
+                .transpose()?;
+            Ok(Py::new(py, TSImportType { span: span_converted, start_line, end_line, argument, qualifier, type_arguments })?.into_any())
+        }
+        TSType::TSArrayType(array) => {
+            let element_type = convert_ts_type(py, &array.element_type, source)?;
+            Ok(Py::new(py, TSArrayType { span: span_converted, start_line, end_line, element_type })?.into_any())
+        }
+        TSType::TSTupleType(tuple) => {
+            let element_types = tuple.element_types.iter()
+                .map(|elem| convert_ts_tuple_element(py, elem, source))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(Py::new(py, TSTupleType { span: span_converted, start_line, end_line, element_types })?.into_any())
+        }
+        TSType::TSAnyKeyword(_) => Ok(Py::new(py, TSAnyKeyword { span: span_converted, start_line, end_line })?.into_any()),
+        TSType::TSBigIntKeyword(_) => Ok(Py::new(py, TSBigIntKeyword { span: span_converted, start_line, end_line })?.into_any()),
+        TSType::TSBooleanKeyword(_) => Ok(Py::new(py, TSBooleanKeyword { span: span_converted, start_line, end_line })?.into_any()),
+        TSType::TSIntrinsicKeyword(_) => Ok(Py::new(py, TSIntrinsicKeyword { span: span_converted, start_line, end_line })?.into_any()),
+        TSType::TSNeverKeyword(_) => Ok(Py::new(py, TSNeverKeyword { span: span_converted, start_line, end_line })?.into_any()),
+        TSType::TSNullKeyword(_) => Ok(Py::new(py, TSNullKeyword { span: span_converted, start_line, end_line })?.into_any()),
+        TSType::TSNumberKeyword(_) => Ok(Py::new(py, TSNumberKeyword { span: span_converted, start_line, end_line })?.into_any()),
+        TSType::TSObjectKeyword(_) => Ok(Py::new(py, TSObjectKeyword { span: span_converted, start_line, end_line })?.into_any()),
+        TSType::TSStringKeyword(_) => Ok(Py::new(py, TSStringKeyword { span: span_converted, start_line, end_line })?.into_any()),
+        TSType::TSSymbolKeyword(_) => Ok(Py::new(py, TSSymbolKeyword { span: span_converted, start_line, end_line })?.into_any()),
+        TSType::TSUndefinedKeyword(_) => Ok(Py::new(py, TSUndefinedKeyword { span: span_converted, start_line, end_line })?.into_any()),
+        TSType::TSUnknownKeyword(_) => Ok(Py::new(py, TSUnknownKeyword { span: span_converted, start_line, end_line })?.into_any()),
+        TSType::TSVoidKeyword(_) => Ok(Py::new(py, TSVoidKeyword { span: span_converted, start_line, end_line })?.into_any()),
+        TSType::TSThisType(_) => Ok(Py::new(py, TSThisType { span: span_converted, start_line, end_line })?.into_any()),
+        _ => {
+            let mut node = Node::new("TSType".to_string(), span_converted);
             node.start_line = start_line;
             node.end_line = end_line;
             Ok(Py::new(py, node)?.into_any())
@@ -72,6 +129,27 @@ pub fn convert_ts_type(py: Python, ts_type: &oxc_ast::ast::TSType, source: &str)
     }
 }
 
+/// Convert a tuple element, handling the optional (`T?`) and rest (`...T`) forms
+pub fn convert_ts_tuple_element(py: Python, elem: &oxc_ast::ast::TSTupleElement, source: &str) -> PyResult<Py<PyAny>> {
+    use oxc_ast::ast::TSTupleElement;
+
+    match elem {
+        TSTupleElement::TSOptionalType(optional) => {
+            let type_annotation = convert_ts_type(py, &optional.type_annotation, source)?;
+            let start_line = compute_line_number(source, optional.span.start as usize);
+            let end_line = compute_line_number(source, optional.span.end as usize);
+            Ok(Py::new(py, TSOptionalType { span: Span::from(optional.span), start_line, end_line, type_annotation })?.into_any())
+        }
+        TSTupleElement::TSRestType(rest) => {
+            let type_annotation = convert_ts_type(py, &rest.type_annotation, source)?;
+            let start_line = compute_line_number(source, rest.span.start as usize);
+            let end_line = compute_line_number(source, rest.span.end as usize);
+            Ok(Py::new(py, TSRestType { span: Span::from(rest.span), start_line, end_line, type_annotation })?.into_any())
+        }
+        _ => convert_ts_type(py, elem.to_ts_type(), source),
+    }
+}
+
 pub fn convert_ts_type_annotation(py: Python, ts_ann: &oxc_ast::ast::TSTypeAnnotation, source: &str) -> PyResult<Py<PyAny>> {
     let span = ts_ann.span;
     let span_converted = Span::from(span);
@@ -98,7 +176,17 @@ pub fn convert_ts_type_parameter(py: Python, param: &oxc_ast::ast::TSTypeParamet
     let name = param.name.name.to_string();
     let constraint = param.constraint.as_ref().map(|c| convert_ts_type(py, c, source)).transpose()?;
     let default = param.default.as_ref().map(|d| convert_ts_type(py, d, source)).transpose()?;
-    Ok(Py::new(py, TSTypeParameter { span: span_converted, start_line, end_line, name, constraint, default })?.into_any())
+    Ok(Py::new(py, TSTypeParameter {
+        span: span_converted,
+        start_line,
+        end_line,
+        name,
+        constraint,
+        default,
+        in_modifier: param.r#in,
+        out_modifier: param.out,
+        const_modifier: param.r#const,
+    })?.into_any())
 }
 
 pub fn convert_ts_type_parameter_instantiation(py: Python, tp_inst: &oxc_ast::ast::TSTypeParameterInstantiation, source: &str) -> PyResult<Py<PyAny>> {
@@ -131,13 +219,14 @@ pub fn convert_ts_signature(py: Python, sig: &oxc_ast::ast::TSSignature, source:
         TSSignature::TSPropertySignature(prop) => {
             let key = convert_ts_property_key(py, &prop.key, source)?;
             let type_annotation = prop.type_annotation.as_ref().map(|ta| convert_ts_type_annotation(py, ta, source)).transpose()?;
-            Ok(Py::new(py, TSPropertySignature { span: span_converted, start_line, end_line, key: Some(key), optional: prop.optional, readonly: prop.readonly, type_annotation })?.into_any())
+            Ok(Py::new(py, TSPropertySignature { span: span_converted, start_line, end_line, key: Some(key), computed: prop.computed, optional: prop.optional, readonly: prop.readonly, type_annotation })?.into_any())
         }
         TSSignature::TSMethodSignature(method) => {
             let key = convert_ts_property_key(py, &method.key, source)?;
             let params: Vec<Py<PyAny>> = Vec::new();
             let return_type = method.return_type.as_ref().map(|rt| convert_ts_type_annotation(py, rt, source)).transpose()?;
-            Ok(Py::new(py, TSMethodSignature { span: span_converted, start_line, end_line, key: Some(key), params, return_type })?.into_any())
+            let type_parameters = method.type_parameters.as_ref().map(|tp| convert_ts_type_parameter_declaration(py, tp, source)).transpose()?;
+            Ok(Py::new(py, TSMethodSignature { span: span_converted, start_line, end_line, key: Some(key), computed: method.computed, params, return_type, type_parameters, optional: method.optional })?.into_any())
         }
         _ => {
             let mut node = Node::new("TSSignature".to_string(), span_converted);
@@ -148,15 +237,19 @@ pub fn convert_ts_signature(py: Python, sig: &oxc_ast::ast::TSSignature, source:
     }
 }
 
-pub fn convert_ts_property_key(py: Python, key: &oxc_ast::ast::PropertyKey, _source: &str) -> PyResult<Py<PyAny>> {
+pub fn convert_ts_property_key(py: Python, key: &oxc_ast::ast::PropertyKey, source: &str) -> PyResult<Py<PyAny>> {
     use oxc_ast::ast::PropertyKey;
     use oxc_span::GetSpan;
     let span = key.span();
     let span_converted = Span::from(span);
     match key {
-        PropertyKey::StaticIdentifier(ident) => Ok(Py::new(py, expressions::Identifier::new(span_converted, ident.name.to_string()))?.into_any()),
-        PropertyKey::PrivateIdentifier(ident) => Ok(Py::new(py, expressions::Identifier::new(span_converted, format!("#{}", ident.name)))?.into_any()),
-        _ => Ok(Py::new(py, expressions::Identifier::new(span_converted, "computed".to_string()))?.into_any()),
+        PropertyKey::StaticIdentifier(ident) => Ok(Py::new(py, new_identifier(span_converted, ident.name.to_string(), source))?.into_any()),
+        PropertyKey::PrivateIdentifier(ident) => Ok(Py::new(py, new_identifier(span_converted, format!("#{}", ident.name), source))?.into_any()),
+        // String/numeric keys and computed keys convert as regular expressions
+        _ => match key.as_expression() {
+            Some(expr) => convert_expression(py, expr, source),
+            None => Ok(Py::new(py, new_identifier(span_converted, "computed".to_string(), source))?.into_any()),
+        },
     }
 }
 
@@ -165,36 +258,38 @@ pub fn convert_ts_interface_heritage(py: Python, heritage: &oxc_ast::ast::TSInte
     let span_converted = Span::from(span);
     let start_line = compute_line_number(source, span.start as usize);
     let end_line = compute_line_number(source, span.end as usize);
-    let mut node = Node::new("TSInterfaceHeritage".to_string(), span_converted);
-    node.start_line = start_line;
-    node.end_line = end_line;
-    Ok(Py::new(py, node)?.into_any())
+    let expression = convert_expression(py, &heritage.expression, source)?;
+    let type_arguments = heritage.type_arguments.as_ref().map(|ta| convert_ts_type_parameter_instantiation(py, ta, source)).transpose()?;
+    Ok(Py::new(py, TSInterfaceHeritage { span: span_converted, start_line, end_line, expression, type_arguments })?.into_any())
 }
 
 pub fn convert_ts_enum_member(py: Python, member: &oxc_ast::ast::TSEnumMember, source: &str) -> PyResult<Py<PyAny>> {
     use oxc_ast::ast::Expression;
-    use oxc_span::GetSpan;
+    use oxc_ast::ast::UnaryOperator;
     let span = member.span;
     let span_converted = Span::from(span);
     let start_line = compute_line_number(source, span.start as usize);
     let end_line = compute_line_number(source, span.end as usize);
     let id = match &member.id {
-        oxc_ast::ast::TSEnumMemberName::Identifier(ident) => Some(Py::new(py, expressions::Identifier::new(Span::from(ident.span), ident.name.to_string()))?.into_any()),
-        oxc_ast::ast::TSEnumMemberName::String(s) => Some(Py::new(py, expressions::Identifier::new(Span::from(s.span), s.value.to_string()))?.into_any()),
+        oxc_ast::ast::TSEnumMemberName::Identifier(ident) => Some(Py::new(py, new_identifier(Span::from(ident.span), ident.name.to_string(), source))?.into_any()),
+        oxc_ast::ast::TSEnumMemberName::String(s) => Some(Py::new(py, new_identifier(Span::from(s.span), s.value.to_string(), source))?.into_any()),
         _ => None,
     };
-    // Convert initializer to a generic Node (preserves that initializer exists)
-    let initializer = member.initializer.as_ref().map(|init| {
-        let init_span = Span::from(init.span());
-        let type_str = match init {
-            Expression::NumericLiteral(_) => "NumericLiteral",
-            Expression::StringLiteral(_) => "StringLiteral",
-            _ => "Expression",
-        };
-        let mut node = Node::new(type_str.to_string(), init_span);
-        node.start_line = compute_line_number(source, init.span().start as usize);
-        node.end_line = compute_line_number(source, init.span().end as usize);
-        Py::new(py, node).map(|p| p.into_any())
-    }).transpose()?;
-    Ok(Py::new(py, TSEnumMember { span: span_converted, start_line, end_line, id, initializer })?.into_any())
+    let initializer = member.initializer.as_ref()
+        .map(|init| convert_expression(py, init, source))
+        .transpose()?;
+    let (string_value, numeric_value) = match &member.initializer {
+        Some(Expression::StringLiteral(lit)) => (Some(lit.value.to_string()), None),
+        Some(Expression::NumericLiteral(lit)) => (None, Some(lit.value)),
+        // `-1` and `+1` are unary expressions around the literal
+        Some(Expression::UnaryExpression(unary)) => match (&unary.argument, unary.operator) {
+            (Expression::NumericLiteral(lit), UnaryOperator::UnaryNegation) => (None, Some(-lit.value)),
+            (Expression::NumericLiteral(lit), UnaryOperator::UnaryPlus) => (None, Some(lit.value)),
+            _ => (None, None),
+        },
+        _ => (None, None),
+    };
+    Ok(Py::new(py, TSEnumMember {
+        span: span_converted, start_line, end_line, id, initializer, string_value, numeric_value,
+    })?.into_any())
 }
@@ -1,9 +1,12 @@
 //! Core AST infrastructure types
 
 use oxc_allocator::Allocator as OxcAllocator;
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
 use pyo3::prelude::*;
 
+use crate::parser::compute_line_number;
+use crate::traversal::{child_nodes, collect_dependencies, nodes_of_type, WalkIterator};
+
 // =============================================================================
 // Phase 8: Base Node Structure
 // =============================================================================
@@ -99,6 +102,26 @@ impl Node {
         (self.start_line, self.end_line)
     }
 
+    /// Indented tree string of this node and its children.
+    ///
+    /// Args:
+    ///     depth: Indentation level of the first line (two spaces per level)
+    #[pyo3(signature = (depth=0))]
+    pub fn to_repr(slf: &Bound<'_, Self>, depth: usize) -> String {
+        tree_repr(slf.py(), slf.as_any(), depth)
+    }
+
+    /// Type, span, and line numbers as a plain dict.
+    #[getter]
+    fn __dict__<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    /// Plain dict of this node's fields (see `__dict__`).
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("{}(span={}..{})", self.node_type, self.span.start, self.span.end)
     }
@@ -137,6 +160,10 @@ pub struct Program {
     /// End line number (1-indexed)
     #[pyo3(get)]
     pub end_line: usize,
+
+    /// Hashbang (shebang) line text after `#!`, if the source starts with one
+    #[pyo3(get)]
+    pub hashbang: Option<String>,
 }
 
 #[pymethods]
@@ -148,6 +175,7 @@ impl Program {
             body,
             start_line: 1,
             end_line: 1,
+            hashbang: None,
         }
     }
 
@@ -194,6 +222,37 @@ impl Program {
         (self.start_line, self.end_line)
     }
 
+    /// Iterate over top-level statements (`for stmt in program`).
+    fn __iter__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        iter_body(py, &self.body)
+    }
+
+    /// Number of top-level statements.
+    fn __len__(&self) -> usize {
+        self.body.len()
+    }
+
+    /// Get a top-level statement by index (negative indices count from the end).
+    fn __getitem__(&self, py: Python, idx: isize) -> PyResult<Py<PyAny>> {
+        body_item(py, &self.body, idx)
+    }
+
+    /// Walk this node's subtree; same as `walk(node, max_depth)`.
+    #[pyo3(signature = (max_depth=None))]
+    pub fn walk(slf: &Bound<'_, Self>, max_depth: Option<usize>) -> WalkIterator {
+        WalkIterator::new_with_limit(slf.clone().into_any().unbind(), max_depth)
+    }
+
+    /// All nodes of `type_name` in this node's subtree (itself included), in walk order.
+    pub fn find_type(slf: &Bound<'_, Self>, type_name: &str) -> Vec<Py<PyAny>> {
+        nodes_of_type(slf.py(), slf.clone().into_any().unbind(), type_name)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("Program(body={} statements)", self.body.len())
     }
@@ -203,6 +262,283 @@ impl Program {
     }
 }
 
+/// Python iterator over a node's `body` list, shared by container nodes' `__iter__`.
+pub(crate) fn iter_body(py: Python, body: &[Py<PyAny>]) -> PyResult<Py<PyAny>> {
+    let list = pyo3::types::PyList::new(py, body.iter().map(|node| node.clone_ref(py)))?;
+    Ok(list.try_iter()?.into_any().unbind())
+}
+
+/// Index into a node's `body` list with Python semantics (negative indices, IndexError).
+pub(crate) fn body_item(py: Python, body: &[Py<PyAny>], idx: isize) -> PyResult<Py<PyAny>> {
+    let len = body.len() as isize;
+    let resolved = if idx < 0 { idx + len } else { idx };
+    if resolved < 0 || resolved >= len {
+        return Err(pyo3::exceptions::PyIndexError::new_err("index out of range"));
+    }
+    Ok(body[resolved as usize].clone_ref(py))
+}
+
+/// Source text of a function's body node (empty if there is no body).
+pub(crate) fn body_source(py: Python, body: Option<&Py<PyAny>>, source: &str) -> String {
+    body.and_then(|body| crate::traversal::node_span(body.bind(py)))
+        .map(|span| slice_source(source, span.start, span.end))
+        .unwrap_or_default()
+}
+
+/// Source text from the start of a function up to its body (the whole node if bodiless).
+pub(crate) fn signature_source(py: Python, span: Span, body: Option<&Py<PyAny>>, source: &str) -> String {
+    let end = body.and_then(|body| crate::traversal::node_span(body.bind(py)))
+        .map_or(span.end, |body_span| body_span.start);
+    slice_source(source, span.start, end)
+}
+
+/// Name of each parameter; None for destructuring patterns and rest elements.
+///
+/// A parameter with a default (`c = 1`) is named by its left side.
+pub(crate) fn param_names(py: Python, params: &[Py<PyAny>]) -> Vec<Option<String>> {
+    params.iter()
+        .map(|param| {
+            let mut param = param.bind(py).clone();
+            if let Ok(name) = param.extract::<String>() {
+                return Some(name);
+            }
+            if node_type_name(&param) == "AssignmentPattern" {
+                param = param.getattr("left").ok()?;
+            }
+            param.getattr("name").ok()?.extract::<Option<String>>().ok().flatten()
+        })
+        .collect()
+}
+
+/// True if the last parameter is a RestElement (`...rest`).
+pub(crate) fn has_rest_param(py: Python, params: &[Py<PyAny>]) -> bool {
+    params.last().is_some_and(|param| {
+        param.bind(py).getattr("type")
+            .and_then(|t| t.extract::<String>())
+            .is_ok_and(|t| t == "RestElement")
+    })
+}
+
+/// Indented multiline tree of a node and its descendants, one node per line.
+///
+/// Each line shows the node type, its `name` and parameter count when present,
+/// and its span; `depth` sets the indentation of the first line.
+pub(crate) fn tree_repr(py: Python, node: &Bound<'_, PyAny>, depth: usize) -> String {
+    let mut lines = Vec::new();
+    push_tree_lines(py, node, depth, &mut lines);
+    lines.join("\n")
+}
+
+fn push_tree_lines(py: Python, node: &Bound<'_, PyAny>, depth: usize, lines: &mut Vec<String>) {
+    let mut label = node.getattr("type")
+        .and_then(|t| t.extract::<String>())
+        .unwrap_or_default();
+    if let Ok(name) = node.getattr("name").and_then(|n| n.extract::<String>()) {
+        label.push_str(&format!(" name='{}'", name));
+    }
+    if let Ok(params) = node.getattr("params").and_then(|p| p.extract::<Vec<Py<PyAny>>>()) {
+        label.push_str(&format!(" params={}", params.len()));
+    }
+    if let Some(span) = crate::traversal::node_span(node) {
+        label.push_str(&format!(" span={}..{}", span.start, span.end));
+    }
+    lines.push(format!("{}{}", "  ".repeat(depth), label));
+
+    for child in child_nodes(py, node) {
+        push_tree_lines(py, child.bind(py), depth + 1, lines);
+    }
+}
+
+/// Deprecated getters, as (class name, getter name), left out of `node_to_dict`.
+const DEPRECATED_GETTERS: &[(&str, &str)] = &[("ClassBody", "methods")];
+
+/// Plain dict of a node's fields, found by introspecting its class's getters.
+///
+/// Span values become `{"start": ..., "end": ...}`; child nodes and lists of
+/// nodes are included as-is. Deprecated getters are skipped.
+pub(crate) fn node_to_dict<'py>(node: &Bound<'py, PyAny>) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+    let py = node.py();
+    let dict = pyo3::types::PyDict::new(py);
+    let class = node.get_type();
+    let class_name = class.name()?.extract::<String>()?;
+    let getter_type = py.import("types")?.getattr("GetSetDescriptorType")?;
+    for name in class.dir()? {
+        let name = name.extract::<String>()?;
+        if name.starts_with('_') || !class.getattr(name.as_str())?.is_instance(&getter_type)? {
+            continue;
+        }
+        if DEPRECATED_GETTERS.contains(&(class_name.as_str(), name.as_str())) {
+            continue;
+        }
+        let value = node.getattr(name.as_str())?;
+        match value.extract::<Span>() {
+            Ok(span) => {
+                let span_dict = pyo3::types::PyDict::new(py);
+                span_dict.set_item("start", span.start)?;
+                span_dict.set_item("end", span.end)?;
+                dict.set_item(name, span_dict)?;
+            }
+            Err(_) => dict.set_item(name, value)?,
+        }
+    }
+    Ok(dict)
+}
+
+/// Convert a field value to plain JSON-compatible data, recursing into nodes.
+///
+/// Objects with a `to_dict()` method (nodes, errors) use it; other pyclasses
+/// (spans, comments) fall back to `node_to_dict`.
+fn json_value<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple};
+
+    let py = value.py();
+    if value.is_none()
+        || value.is_instance_of::<PyBool>()
+        || value.is_instance_of::<PyInt>()
+        || value.is_instance_of::<PyFloat>()
+        || value.is_instance_of::<PyString>()
+    {
+        return Ok(value.clone());
+    }
+    if value.is_instance_of::<PyList>() || value.is_instance_of::<PyTuple>() {
+        let items = value.try_iter()?
+            .map(|item| json_value(&item?))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(PyList::new(py, items)?.into_any());
+    }
+    let dict = if let Ok(dict) = value.cast::<PyDict>() {
+        dict.clone()
+    } else if value.hasattr("to_dict")? {
+        value.call_method0("to_dict")?.cast_into::<PyDict>()?
+    } else {
+        node_to_dict(value)?
+    };
+    let converted = PyDict::new(py);
+    for (key, item) in dict.iter() {
+        converted.set_item(key, json_value(&item)?)?;
+    }
+    Ok(converted.into_any())
+}
+
+/// AST node restored from JSON by `ParseResult.from_json()`.
+///
+/// Every field written by `to_json()` is an attribute (`node.type`,
+/// `node.body`, ...); child nodes are restored as JsonNode objects and spans
+/// as Span, so `walk()` and the ParseResult queries work on restored trees.
+/// Node-specific methods such as `VariableDeclarator.id_names()` are not
+/// available, since only field values are serialized.
+///
+/// Example:
+///     >>> restored = ParseResult.from_json(result.to_json())
+///     >>> restored.program.body[0].declarations[0].id.name
+///     'x'
+#[pyclass]
+pub struct JsonNode {
+    /// Field values, keyed by attribute name
+    fields: Py<pyo3::types::PyDict>,
+}
+
+#[pymethods]
+impl JsonNode {
+    fn __getattr__(&self, py: Python, name: &str) -> PyResult<Py<PyAny>> {
+        self.fields.bind(py).get_item(name)?
+            .map(Bound::unbind)
+            .ok_or_else(|| pyo3::exceptions::PyAttributeError::new_err(
+                format!("'{}' node has no attribute '{}'", self.node_type(py), name)
+            ))
+    }
+
+    /// Extract source text for this node using its span.
+    pub fn get_text(&self, py: Python, source: &str) -> String {
+        let span = self.span(py);
+        slice_source(source, span.start, span.end)
+    }
+
+    /// Get line range for this node (1-indexed, inclusive).
+    pub fn get_line_range(&self, py: Python, _source: &str) -> (usize, usize) {
+        let line = |name: &str| -> usize {
+            self.fields.bind(py).get_item(name).ok().flatten()
+                .and_then(|value| value.extract().ok())
+                .unwrap_or(0)
+        };
+        (line("start_line"), line("end_line"))
+    }
+
+    /// Plain dict of this node's fields, with spans as `{"start": ..., "end": ...}`.
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+        let dict = pyo3::types::PyDict::new(py);
+        for (key, value) in self.fields.bind(py).iter() {
+            match value.extract::<Span>() {
+                Ok(span) => {
+                    let span_dict = pyo3::types::PyDict::new(py);
+                    span_dict.set_item("start", span.start)?;
+                    span_dict.set_item("end", span.end)?;
+                    dict.set_item(key, span_dict)?;
+                }
+                Err(_) => dict.set_item(key, value)?,
+            }
+        }
+        Ok(dict)
+    }
+
+    fn __repr__(&self, py: Python) -> String {
+        let span = self.span(py);
+        format!("{}(span={}..{})", self.node_type(py), span.start, span.end)
+    }
+}
+
+impl JsonNode {
+    fn node_type(&self, py: Python) -> String {
+        self.fields.bind(py).get_item("type").ok().flatten()
+            .and_then(|t| t.extract().ok())
+            .unwrap_or_default()
+    }
+
+    fn span(&self, py: Python) -> Span {
+        self.fields.bind(py).get_item("span").ok().flatten()
+            .and_then(|span| span.extract().ok())
+            .unwrap_or(Span { start: 0, end: 0 })
+    }
+}
+
+/// Rebuild a value written by `json_value`: objects with a `type` become
+/// JsonNode, `{"start", "end"}` objects become Span, and lists recurse.
+fn restore_json_value<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    use pyo3::types::{PyDict, PyList};
+
+    let py = value.py();
+    if let Ok(list) = value.cast::<PyList>() {
+        let items = list.iter()
+            .map(|item| restore_json_value(&item))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(PyList::new(py, items)?.into_any());
+    }
+    let Ok(dict) = value.cast::<PyDict>() else {
+        return Ok(value.clone());
+    };
+    if dict.len() == 2 {
+        if let (Some(start), Some(end)) = (dict.get_item("start")?, dict.get_item("end")?) {
+            return Ok(Py::new(py, Span { start: start.extract()?, end: end.extract()? })?.into_bound(py).into_any());
+        }
+    }
+    let fields = PyDict::new(py);
+    for (key, item) in dict.iter() {
+        fields.set_item(key, restore_json_value(&item)?)?;
+    }
+    if fields.contains("type")? {
+        Ok(Py::new(py, JsonNode { fields: fields.unbind() })?.into_bound(py).into_any())
+    } else {
+        Ok(fields.into_any())
+    }
+}
+
+/// Source text for a byte range, clamped to the source (empty if not on char boundaries).
+pub(crate) fn slice_source(source: &str, start: usize, end: usize) -> String {
+    let start = start.min(source.len());
+    let end = end.min(source.len());
+    source.get(start..end).unwrap_or("").to_string()
+}
+
 /// Source code location with byte offsets.
 ///
 /// A `Span` represents a range in the source code from `start` to `end`.
@@ -221,6 +557,9 @@ impl Program {
 ///     >>> text = source[span.start:span.end]
 ///     >>> print(text)
 ///     const
+///     >>> 3 in span, 5 in span
+///     (True, False)
+///     >>> start, end = span
 #[pyclass(frozen)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Span {
@@ -256,6 +595,105 @@ impl Span {
             )),
         }
     }
+
+    /// Check if a byte offset falls within the span (`offset in span`)
+    fn __contains__(&self, offset: i64) -> bool {
+        usize::try_from(offset).is_ok_and(|offset| self.start <= offset && offset < self.end)
+    }
+
+    /// Smallest span covering both spans (union)
+    fn __add__(&self, other: &Self) -> Self {
+        Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
+    /// Overlapping part of both spans, or None if they don't overlap
+    fn __and__(&self, other: &Self) -> Option<Self> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        (start < end).then_some(Self { start, end })
+    }
+
+    /// True for non-empty spans; takes precedence over `__len__`
+    fn __bool__(&self) -> bool {
+        self.start != self.end
+    }
+
+    /// Iterate over (start, end) so `start, end = span` works
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let pair = pyo3::types::PyTuple::new(py, [self.start, self.end])?;
+        Ok(pair.try_iter()?.into_any())
+    }
+
+    /// Always 2, matching a `(start, end)` tuple
+    fn __len__(&self) -> usize {
+        2
+    }
+
+    /// `span[0]` is start and `span[1]` is end; negative indices count from the end
+    fn __getitem__(&self, idx: isize) -> PyResult<usize> {
+        match idx {
+            0 | -2 => Ok(self.start),
+            1 | -1 => Ok(self.end),
+            _ => Err(pyo3::exceptions::PyIndexError::new_err("Span index out of range")),
+        }
+    }
+
+    /// Full source line containing the span start, without its line break.
+    ///
+    /// Example:
+    ///     >>> Span(7, 10).line_slice("hello\nworld\n")
+    ///     'world'
+    pub fn line_slice(&self, source: &str) -> String {
+        let (line_start, line_end) = self.line_bounds(source);
+        source[line_start..line_end].to_string()
+    }
+
+    /// The span's line followed by a line of `^` under the span's columns.
+    ///
+    /// Spans running past the end of the line are underlined up to the line
+    /// end; empty spans get a single caret.
+    ///
+    /// Example:
+    ///     >>> print(Span(7, 10).caret_annotation("hello\nworld\n"))
+    ///     world
+    ///      ^^^
+    pub fn caret_annotation(&self, source: &str) -> String {
+        let (line_start, line_end) = self.line_bounds(source);
+        let line = &source[line_start..line_end];
+        let start = floor_char_boundary(source, self.start).clamp(line_start, line_end);
+        let end = floor_char_boundary(source, self.end).clamp(start, line_end);
+
+        // Keep tabs in the padding so carets line up with tab-indented code
+        let padding: String = source[line_start..start]
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let width = source[start..end].chars().count().max(1);
+        format!("{}\n{}{}", line, padding, "^".repeat(width))
+    }
+}
+
+impl Span {
+    /// Byte range of the line containing `start`, excluding `\n` / `\r\n`.
+    fn line_bounds(&self, source: &str) -> (usize, usize) {
+        let offset = floor_char_boundary(source, self.start);
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..].find('\n').map_or(source.len(), |i| offset + i);
+        let line_end = if source[line_start..line_end].ends_with('\r') { line_end - 1 } else { line_end };
+        (line_start, line_end)
+    }
+}
+
+/// Clamp `offset` to the source length and back it up to a char boundary.
+fn floor_char_boundary(source: &str, offset: usize) -> usize {
+    let mut offset = offset.min(source.len());
+    while !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
 }
 
 /// Convert from oxc_span::Span to our Span
@@ -346,11 +784,75 @@ impl Allocator {
         *guard = OxcAllocator::default();
     }
 
+    /// Parse several sources with this allocator, resetting it between them.
+    ///
+    /// The allocator is reset before each parse and once more when the batch
+    /// finishes or fails, so callers never need to call reset() themselves.
+    ///
+    /// Args:
+    ///     sources: Source strings to parse
+    ///     source_type: Optional source type applied to every source (see parse())
+    ///
+    /// Returns:
+    ///     List of ParseResult, one per source, in input order
+    ///
+    /// Example:
+    ///     allocator = oxc_python.Allocator()
+    ///     results = allocator.parse_many([file1, file2, file3])
+    #[pyo3(signature = (sources, source_type=None))]
+    pub fn parse_many(&self, py: Python, sources: Vec<String>, source_type: Option<String>) -> PyResult<Vec<ParseResult>> {
+        let mut results = Vec::with_capacity(sources.len());
+        for source in &sources {
+            self.reset();
+            let result = crate::parser::parse(py, source, Some(self), source_type.as_deref(), None, false, false);
+            match result {
+                Ok(result) => results.push(result),
+                Err(err) => {
+                    self.reset();
+                    return Err(err);
+                }
+            }
+        }
+        self.reset();
+        Ok(results)
+    }
+
+    /// Enter a `with` block, returning the allocator itself.
+    ///
+    /// Example:
+    ///     with oxc_python.Allocator() as allocator:
+    ///         result = oxc_python.parse(source, allocator=allocator)
+    fn __enter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    /// Reset the allocator on leaving a `with` block.
+    ///
+    /// Returns False so exceptions raised inside the block propagate. The
+    /// allocator stays usable afterwards; its arena is freed when the object
+    /// is dropped.
+    #[pyo3(signature = (_exc_type=None, _exc_value=None, _traceback=None))]
+    fn __exit__(
+        &self,
+        _exc_type: Option<&Bound<'_, PyAny>>,
+        _exc_value: Option<&Bound<'_, PyAny>>,
+        _traceback: Option<&Bound<'_, PyAny>>,
+    ) -> bool {
+        self.reset();
+        false
+    }
+
     fn __repr__(&self) -> String {
         "Allocator()".to_string()
     }
 }
 
+impl Default for Allocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // =============================================================================
 // Phase 18: Comment Extraction
 // =============================================================================
@@ -373,6 +875,22 @@ pub struct Comment {
     /// True for block comments (/* */), False for line comments (//)
     #[pyo3(get)]
     pub is_block: bool,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+
+    /// Start column (0-indexed, in characters from the start of the line)
+    #[pyo3(get)]
+    pub start_col: usize,
+
+    /// End column (0-indexed, exclusive)
+    #[pyo3(get)]
+    pub end_col: usize,
 }
 
 #[pymethods]
@@ -384,8 +902,8 @@ impl Comment {
             self.text.clone()
         };
         format!(
-            "Comment(text={:?}, span={}..{}, is_block={})",
-            text_preview, self.span.start, self.span.end, self.is_block
+            "Comment(text={:?}, span={}..{}, is_block={}, line={})",
+            text_preview, self.span.start, self.span.end, self.is_block, self.start_line
         )
     }
 }
@@ -409,22 +927,56 @@ pub struct ParseError {
     #[pyo3(get)]
     pub span: Span,
 
-    /// Error severity ("error" or "warning")
+    /// Error severity ("error", "warning", or "advice")
     #[pyo3(get)]
     pub severity: String,
+
+    /// Diagnostic code (e.g. "parse(unexpected-token)"), if the diagnostic has one
+    #[pyo3(get)]
+    pub code: Option<String>,
+
+    /// File the error came from, if a filename was passed to parse()
+    #[pyo3(get)]
+    pub source_file: Option<String>,
+
+    /// Line of the error start (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
 }
 
 #[pymethods]
 impl ParseError {
     #[new]
-    pub fn new(message: String, span: Span, severity: String) -> Self {
+    #[pyo3(signature = (message, span, severity, code=None, source_file=None, start_line=1))]
+    pub fn new(
+        message: String,
+        span: Span,
+        severity: String,
+        code: Option<String>,
+        source_file: Option<String>,
+        start_line: usize,
+    ) -> Self {
         Self {
             message,
             span,
             severity,
+            code,
+            source_file,
+            start_line,
         }
     }
 
+    /// Convert to a plain dict with message, start, end, severity, and code keys.
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("message", &self.message)?;
+        dict.set_item("start", self.span.start)?;
+        dict.set_item("end", self.span.end)?;
+        dict.set_item("severity", &self.severity)?;
+        dict.set_item("code", &self.code)?;
+        Ok(dict)
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ParseError(severity='{}', message='{}', span={}..{})",
@@ -437,8 +989,8 @@ impl ParseError {
     }
 }
 
-/// Convert oxc diagnostic errors to ParseError list
-pub fn convert_errors(errors: Vec<OxcDiagnostic>) -> Vec<ParseError> {
+/// Convert oxc diagnostic errors to ParseError list, tagging each with `source_file`
+pub fn convert_errors(errors: Vec<OxcDiagnostic>, source: &str, source_file: Option<&str>) -> Vec<ParseError> {
     errors
         .into_iter()
         .map(|error| {
@@ -446,18 +998,31 @@ pub fn convert_errors(errors: Vec<OxcDiagnostic>) -> Vec<ParseError> {
             // This gives us the main error message
             let message = format!("{}", error);
 
-            // Extract span - OxcDiagnostic stores span information we need to extract
-            // Since the exact API is unclear, we'll use a default span for now
-            // In the future, this should be improved to extract actual span data
-            let span = Span { start: 0, end: 0 };
+            // Use the first labeled span as the error location
+            let span = error
+                .labels
+                .as_ref()
+                .and_then(|labels| labels.first())
+                .map(|label| Span { start: label.offset(), end: label.offset() + label.len() })
+                .unwrap_or(Span { start: 0, end: 0 });
 
-            // Determine severity - default to "error"
-            let severity = "error".to_string();
+            let severity = match error.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Advice => "advice",
+            }
+            .to_string();
+
+            let code = error.code.is_some().then(|| error.code.to_string());
+            let start_line = compute_line_number(source, span.start);
 
             ParseError {
                 message,
                 span,
                 severity,
+                code,
+                source_file: source_file.map(str::to_string),
+                start_line,
             }
         })
         .collect()
@@ -484,22 +1049,68 @@ pub struct ParseResult {
     /// True if parser hit unrecoverable error
     #[pyo3(get)]
     pub panicked: bool,
+
+    /// Filename passed to parse(), if any
+    #[pyo3(get)]
+    pub source_file: Option<String>,
+
+    /// Source type the code was parsed as ("module", "script", "jsx", "typescript", or "tsx")
+    #[pyo3(get)]
+    pub source_type: String,
+
+    /// True if parse() was called with strict_mode=True
+    #[pyo3(get)]
+    pub strict_mode: bool,
+
+    /// FNV-1a hash of the parsed source (see hash_source())
+    #[pyo3(get)]
+    pub source_hash: String,
+
+    /// Cached result of `has_jsx` (computed on first access)
+    pub has_jsx_cache: Option<bool>,
+
+    /// Cached result of `has_typescript` (computed on first access)
+    pub has_typescript_cache: Option<bool>,
+
+    /// Cached result of `import_paths()` (computed on first call)
+    pub import_paths_cache: Option<Vec<String>>,
+
+    /// Cached result of `export_names()` (computed on first call)
+    pub export_names_cache: Option<Vec<String>>,
+
+    /// Cached result of `required_modules()` (computed on first call)
+    pub required_modules_cache: Option<Vec<String>>,
 }
 
 #[pymethods]
 impl ParseResult {
     #[new]
+    #[pyo3(signature = (program, errors, comments, panicked, source_file=None, source_type="module".to_string(), strict_mode=false, source_hash=String::new()))]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         program: Option<Py<PyAny>>,
         errors: Vec<ParseError>,
         comments: Vec<Comment>,
         panicked: bool,
+        source_file: Option<String>,
+        source_type: String,
+        strict_mode: bool,
+        source_hash: String,
     ) -> Self {
         Self {
             program,
             errors,
             comments,
             panicked,
+            source_file,
+            source_type,
+            strict_mode,
+            source_hash,
+            has_jsx_cache: None,
+            has_typescript_cache: None,
+            import_paths_cache: None,
+            export_names_cache: None,
+            required_modules_cache: None,
         }
     }
 
@@ -511,12 +1122,323 @@ impl ParseResult {
         self.errors.is_empty() && !self.panicked
     }
 
-    fn __repr__(&self) -> String {
+    /// True if parsing reported errors or panicked (the opposite of `is_valid`).
+    #[getter]
+    pub fn has_errors(&self) -> bool {
+        !self.is_valid()
+    }
+
+    /// True if the tree contains any JSX node. Computed once, then cached.
+    #[getter]
+    pub fn has_jsx(&mut self, py: Python) -> bool {
+        if self.has_jsx_cache.is_none() {
+            self.has_jsx_cache = Some(self.any_node(py, |node| node_type_name(node).starts_with("JSX")));
+        }
+        self.has_jsx_cache.unwrap_or(false)
+    }
+
+    /// True if the tree uses any TypeScript syntax. Computed once, then cached.
+    ///
+    /// Looks for `TS*` nodes (types, `as`, `!`, generics, ...) and for class
+    /// members with an accessibility or `readonly` modifier.
+    #[getter]
+    pub fn has_typescript(&mut self, py: Python) -> bool {
+        if self.has_typescript_cache.is_none() {
+            self.has_typescript_cache = Some(self.any_node(py, is_typescript_node));
+        }
+        self.has_typescript_cache.unwrap_or(false)
+    }
+
+    /// Module specifiers of every import, in source order. Computed once, then cached.
+    ///
+    /// Covers `import` declarations, re-exports (`export ... from`), and
+    /// dynamic `import()` calls with a string literal argument.
+    ///
+    /// Example:
+    ///     >>> oxc_python.parse("import a from 'a'; export * from 'b';").import_paths()
+    ///     ['a', 'b']
+    pub fn import_paths(&mut self, py: Python) -> PyResult<Vec<String>> {
+        if self.import_paths_cache.is_none() {
+            self.import_paths_cache = Some(self.dependency_specifiers(py, |kind| kind != "require")?);
+        }
+        Ok(self.import_paths_cache.clone().unwrap_or_default())
+    }
+
+    /// Names exported from the module, in source order. Computed once, then cached.
+    ///
+    /// A default export is listed as "default"; `export * from` without a
+    /// name contributes nothing.
+    ///
+    /// Example:
+    ///     >>> oxc_python.parse("export const a = 1; export default a;").export_names()
+    ///     ['a', 'default']
+    pub fn export_names(&mut self, py: Python) -> PyResult<Vec<String>> {
+        if self.export_names_cache.is_none() {
+            let mut names = Vec::new();
+            for statement in self.top_level_statements(py) {
+                let statement = statement.bind(py);
+                match node_type_name(statement).as_str() {
+                    "ExportNamedDeclaration" => {
+                        names.extend(statement.call_method0("exported_names")?.extract::<Vec<String>>()?);
+                    }
+                    "ExportDefaultDeclaration" => names.push("default".to_string()),
+                    "ExportAllDeclaration" => {
+                        let exported = statement.getattr("exported")?;
+                        if !exported.is_none() {
+                            names.push(exported.getattr("name")?.extract()?);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            self.export_names_cache = Some(names);
+        }
+        Ok(self.export_names_cache.clone().unwrap_or_default())
+    }
+
+    /// String arguments of CommonJS `require()` calls, in source order.
+    /// Computed once, then cached.
+    ///
+    /// Example:
+    ///     >>> oxc_python.parse("const fs = require('fs');").required_modules()
+    ///     ['fs']
+    pub fn required_modules(&mut self, py: Python) -> PyResult<Vec<String>> {
+        if self.required_modules_cache.is_none() {
+            self.required_modules_cache = Some(self.dependency_specifiers(py, |kind| kind == "require")?);
+        }
+        Ok(self.required_modules_cache.clone().unwrap_or_default())
+    }
+
+    /// Human-readable multiline summary for debugging.
+    ///
+    /// Lists the source type, statement and comment counts, every error with
+    /// its line number and span, and the five most common node types.
+    ///
+    /// Example:
+    ///     >>> print(oxc_python.parse("let x = 1;").summary())
+    pub fn summary(&self, py: Python) -> String {
+        let mut lines = vec![format!("ParseResult ({})", self.source_type)];
+        if let Some(file) = &self.source_file {
+            lines.push(format!("  source_file: {}", file));
+        }
+        lines.push(format!("  statements: {}", self.statement_count(py)));
+        lines.push(format!("  comments: {}", self.comments.len()));
+        lines.push(format!("  errors: {}", self.errors.len()));
+        for error in &self.errors {
+            lines.push(format!(
+                "    line {}: {} (span {}..{})",
+                error.start_line, error.message, error.span.start, error.span.end
+            ));
+        }
+        if self.panicked {
+            lines.push("  panicked: True".to_string());
+        }
+
+        let counts = self.node_type_counts(py);
+        if !counts.is_empty() {
+            lines.push("  node types:".to_string());
+            for (name, count) in counts.iter().take(5) {
+                lines.push(format!("    {}: {}", name, count));
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Serialize the result, including the full AST, to a JSON string.
+    ///
+    /// Every node becomes an object of its fields (as in `to_dict()`), with
+    /// child nodes nested; spans become `{"start": ..., "end": ...}`.
+    ///
+    /// Args:
+    ///     indent: Indentation for pretty-printing (None for compact output)
+    ///
+    /// Example:
+    ///     >>> data = json.loads(oxc_python.parse("let x = 1;").to_json())
+    ///     >>> data["program"]["body"][0]["type"]
+    ///     'VariableDeclaration'
+    #[pyo3(signature = (indent=None))]
+    pub fn to_json(&self, py: Python, indent: Option<usize>) -> PyResult<String> {
+        let data = pyo3::types::PyDict::new(py);
+        data.set_item("source_type", &self.source_type)?;
+        data.set_item("source_file", &self.source_file)?;
+        data.set_item("strict_mode", self.strict_mode)?;
+        data.set_item("panicked", self.panicked)?;
+        data.set_item("source_hash", &self.source_hash)?;
+        let program = match &self.program {
+            Some(program) => json_value(program.bind(py))?,
+            None => py.None().into_bound(py),
+        };
+        data.set_item("program", program)?;
+        let errors = self.errors.iter()
+            .map(|error| {
+                let dict = error.to_dict(py)?;
+                dict.set_item("start_line", error.start_line)?;
+                Ok(dict)
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        data.set_item("errors", errors)?;
+        let comments = self.comments.iter()
+            .map(|comment| json_value(Py::new(py, comment.clone())?.bind(py)))
+            .collect::<PyResult<Vec<_>>>()?;
+        data.set_item("comments", comments)?;
+
+        let kwargs = pyo3::types::PyDict::new(py);
+        kwargs.set_item("indent", indent)?;
+        py.import("json")?
+            .call_method("dumps", (data,), Some(&kwargs))?
+            .extract()
+    }
+
+    /// Rebuild a ParseResult from `to_json()` output, e.g. an AST cached on disk.
+    ///
+    /// Nodes are restored as JsonNode objects exposing the serialized fields,
+    /// so `from_json(s).to_json() == s`.
+    ///
+    /// Args:
+    ///     json_str: JSON string produced by `to_json()`
+    ///
+    /// Example:
+    ///     >>> cached = result.to_json()
+    ///     >>> restored = oxc_python.ParseResult.from_json(cached)
+    ///     >>> restored.program.body[0].type
+    ///     'VariableDeclaration'
+    #[staticmethod]
+    pub fn from_json(py: Python, json_str: &str) -> PyResult<ParseResult> {
+        let data = py.import("json")?.call_method1("loads", (json_str,))?;
+        let field = |name: &str| data.get_item(name);
+        let source_file: Option<String> = field("source_file")?.extract()?;
+
+        let program = field("program")?;
+        let program = (!program.is_none())
+            .then(|| restore_json_value(&program).map(Bound::unbind))
+            .transpose()?;
+        let errors = field("errors")?.try_iter()?
+            .map(|error| {
+                let error = error?;
+                Ok(ParseError {
+                    message: error.get_item("message")?.extract()?,
+                    span: Span { start: error.get_item("start")?.extract()?, end: error.get_item("end")?.extract()? },
+                    severity: error.get_item("severity")?.extract()?,
+                    code: error.get_item("code")?.extract()?,
+                    source_file: source_file.clone(),
+                    start_line: error.get_item("start_line")?.extract()?,
+                })
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        let comments = field("comments")?.try_iter()?
+            .map(|comment| {
+                let comment = comment?;
+                let span = comment.get_item("span")?;
+                Ok(Comment {
+                    text: comment.get_item("text")?.extract()?,
+                    span: Span { start: span.get_item("start")?.extract()?, end: span.get_item("end")?.extract()? },
+                    is_block: comment.get_item("is_block")?.extract()?,
+                    start_line: comment.get_item("start_line")?.extract()?,
+                    end_line: comment.get_item("end_line")?.extract()?,
+                    start_col: comment.get_item("start_col")?.extract()?,
+                    end_col: comment.get_item("end_col")?.extract()?,
+                })
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        Ok(ParseResult::new(
+            program,
+            errors,
+            comments,
+            field("panicked")?.extract()?,
+            source_file,
+            field("source_type")?.extract()?,
+            field("strict_mode")?.extract()?,
+            field("source_hash")?.extract()?,
+        ))
+    }
+
+    fn __repr__(&self, py: Python) -> String {
         format!(
-            "ParseResult(is_valid={}, errors={}, comments={})",
-            self.is_valid(),
+            "ParseResult(is_valid={}, source_type='{}', statements={}, errors={}, comments={})",
+            if self.is_valid() { "True" } else { "False" },
+            self.source_type,
+            self.statement_count(py),
             self.errors.len(),
             self.comments.len()
         )
     }
 }
+
+impl ParseResult {
+    fn statement_count(&self, py: Python) -> usize {
+        self.program
+            .as_ref()
+            .and_then(|program| program.bind(py).len().ok())
+            .unwrap_or(0)
+    }
+
+    fn top_level_statements(&self, py: Python) -> Vec<Py<PyAny>> {
+        self.program
+            .as_ref()
+            .and_then(|program| program.bind(py).getattr("body").ok())
+            .and_then(|body| body.extract::<Vec<Py<PyAny>>>().ok())
+            .unwrap_or_default()
+    }
+
+    /// Specifiers from `collect_dependencies()` whose kind passes `keep`, in source order.
+    fn dependency_specifiers(&self, py: Python, keep: impl Fn(&str) -> bool) -> PyResult<Vec<String>> {
+        let Some(program) = &self.program else { return Ok(Vec::new()) };
+        Ok(collect_dependencies(py, program.clone_ref(py), None)?
+            .iter()
+            .map(|dep| dep.borrow(py))
+            .filter(|dep| keep(&dep.kind))
+            .map(|dep| dep.specifier.clone())
+            .collect())
+    }
+
+    /// Walk the tree until a node whose type starts with `prefix` is found.
+    fn any_node(&self, py: Python, predicate: impl Fn(&Bound<'_, PyAny>) -> bool) -> bool {
+        let mut stack: Vec<Py<PyAny>> = self.program.iter().map(|p| p.clone_ref(py)).collect();
+        while let Some(node) = stack.pop() {
+            let node = node.bind(py);
+            if predicate(node) {
+                return true;
+            }
+            stack.extend(child_nodes(py, node));
+        }
+        false
+    }
+
+    /// Count node types across the whole tree, most common first (ties by name).
+    fn node_type_counts(&self, py: Python) -> Vec<(String, usize)> {
+        let mut counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+        let mut stack: Vec<Py<PyAny>> = self.program.iter().map(|p| p.clone_ref(py)).collect();
+        while let Some(node) = stack.pop() {
+            let node = node.bind(py);
+            if let Ok(name) = node.getattr("type").and_then(|t| t.extract::<String>()) {
+                *counts.entry(name).or_insert(0) += 1;
+            }
+            stack.extend(child_nodes(py, node));
+        }
+        let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        counts
+    }
+}
+
+fn node_type_name(node: &Bound<'_, PyAny>) -> String {
+    node.getattr("type")
+        .and_then(|t| t.extract::<String>())
+        .unwrap_or_default()
+}
+
+/// True for nodes that only occur in TypeScript source.
+fn is_typescript_node(node: &Bound<'_, PyAny>) -> bool {
+    match node_type_name(node).as_str() {
+        name if name.starts_with("TS") => true,
+        "ClassProperty" | "MethodDefinition" => {
+            let has_accessibility = node.getattr("accessibility").is_ok_and(|a| !a.is_none());
+            let readonly = node.getattr("readonly")
+                .and_then(|r| r.extract::<bool>())
+                .unwrap_or(false);
+            has_accessibility || readonly
+        }
+        _ => false,
+    }
+}
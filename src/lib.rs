@@ -8,7 +8,8 @@
 //! The implementation is organized into several modules:
 //! - `core`: Core types (Program, Node, Span, Allocator, Comment, ParseError, ParseResult)
 //! - `parser`: Parsing functions and comment extraction
-//! - `traversal`: AST traversal utilities (walk iterator)
+//! - `traversal`: AST traversal utilities (walk iterator, offset and range lookups)
+//! - `scope`: Scope analysis (scope tree with declared bindings)
 //! - `nodes`: AST node types (statements, expressions, JSX, TypeScript)
 //! - `conversion`: Conversion functions from oxc AST to Python objects
 //!
@@ -28,6 +29,7 @@ use pyo3::prelude::*;
 mod core;
 mod parser;
 mod traversal;
+mod scope;
 mod nodes;
 mod conversion;
 
@@ -38,6 +40,7 @@ mod conversion;
 pub use core::{
     Allocator,
     Comment,
+    JsonNode,
     Node,
     ParseError,
     ParseResult,
@@ -51,7 +54,13 @@ pub use core::{
 
 pub use parser::{
     parse,
+    parse_many_with_progress,
+    parse_typescript_type,
+    hash_source,
     extract_comments,
+    build_line_offset_table,
+    compute_line_col,
+    LineIndex,
 };
 
 // =============================================================================
@@ -59,22 +68,56 @@ pub use parser::{
 // =============================================================================
 
 pub use traversal::{
+    BFSWalkIterator,
+    ClassInfo,
+    collect_dependencies,
+    ContextWalkIterator,
+    Dependency,
+    extract_class_hierarchy,
+    extract_class_info,
+    extract_function_signatures,
+    extract_ts_interface_names,
+    extract_ts_type_alias_names,
+    extract_ts_type_names,
+    FunctionSignature,
+    node_at_offset,
+    node_count,
+    node_path_to,
+    node_type_histogram,
+    nodes_in_range,
+    visit,
     walk,
+    walk_breadth_first,
+    walk_top_level,
+    walk_types,
+    walk_with_context,
     WalkIterator,
 };
 
+// =============================================================================
+// Public re-exports: Scope Analysis
+// =============================================================================
+
+pub use scope::{
+    collect_scopes,
+    Scope,
+};
+
 // =============================================================================
 // Public re-exports: Statement Node Types
 // =============================================================================
 
 pub use nodes::statements::{
+    AccessorProperty,
     BlockStatement,
     BreakStatement,
     CatchClause,
     ClassBody,
     ClassDeclaration,
+    ClassProperty,
     ContinueStatement,
     DebuggerStatement,
+    Decorator,
     DoWhileStatement,
     EmptyStatement,
     ExpressionStatement,
@@ -88,7 +131,10 @@ pub use nodes::statements::{
     MethodDefinition,
     ReturnStatement,
     SwitchCase,
+    StaticBlock,
     SwitchStatement,
+    TSAbstractMethodDefinition,
+    TSAbstractPropertyDefinition,
     ThrowStatement,
     TryStatement,
     VariableDeclaration,
@@ -103,15 +149,35 @@ pub use nodes::statements::{
 
 pub use nodes::expressions::{
     ArrayExpression,
+    ArrayPattern,
     ArrowFunctionExpression,
+    AssignmentExpression,
+    AssignmentPattern,
+    AwaitExpression,
+    BigIntLiteral,
     BinaryExpression,
     CallExpression,
     ConditionalExpression,
     Identifier,
+    ImportExpression,
     Literal,
+    LogicalExpression,
     MemberExpression,
+    NewExpression,
     ObjectExpression,
+    ObjectPattern,
+    ParenthesizedExpression,
+    RegExpLiteral,
+    RestElement,
+    SequenceExpression,
+    SpreadElement,
+    Super,
+    TemplateElement,
+    TemplateLiteral,
+    ThisExpression,
     UnaryExpression,
+    UpdateExpression,
+    YieldExpression,
 };
 
 // =============================================================================
@@ -123,6 +189,7 @@ pub use nodes::statements::{
     ExportDefaultDeclaration,
     ExportNamedDeclaration,
     ExportSpecifier,
+    ImportAttribute,
     ImportDeclaration,
     ImportDefaultSpecifier,
     ImportNamespaceSpecifier,
@@ -134,19 +201,49 @@ pub use nodes::statements::{
 // =============================================================================
 
 pub use nodes::typescript::{
+    TSAnyKeyword,
+    TSArrayType,
+    TSAsExpression,
+    TSBigIntKeyword,
+    TSBooleanKeyword,
+    TSConditionalType,
     TSEnumDeclaration,
     TSEnumMember,
+    TSImportType,
+    TSInferType,
+    TSInstantiationExpression,
     TSInterfaceBody,
     TSInterfaceDeclaration,
+    TSInterfaceHeritage,
     TSIntersectionType,
+    TSIntrinsicKeyword,
+    TSLiteralType,
     TSMethodSignature,
+    TSModuleBlock,
+    TSModuleDeclaration,
+    TSNeverKeyword,
+    TSNonNullExpression,
+    TSNullKeyword,
+    TSNumberKeyword,
+    TSObjectKeyword,
+    TSOptionalType,
     TSPropertySignature,
+    TSRestType,
+    TSSatisfiesExpression,
+    TSStringKeyword,
+    TSSymbolKeyword,
+    TSThisType,
+    TSTupleType,
     TSTypeAliasDeclaration,
     TSTypeAnnotation,
+    TSTypeAssertion,
     TSTypeParameter,
     TSTypeParameterDeclaration,
     TSTypeReference,
+    TSUndefinedKeyword,
     TSUnionType,
+    TSUnknownKeyword,
+    TSVoidKeyword,
 };
 
 // =============================================================================
@@ -154,6 +251,7 @@ pub use nodes::typescript::{
 // =============================================================================
 
 pub use nodes::jsx::{
+    EmptyExpression,
     JSXAttribute,
     JSXClosingElement,
     JSXElement,
@@ -173,10 +271,16 @@ pub use nodes::jsx::{
 pub use conversion::{
     convert_statement,
     convert_expression,
+    convert_member_expression,
+    convert_simple_assignment_target,
+    convert_assignment_target,
     convert_import_specifier,
     convert_export_specifier,
     convert_binding_identifier,
     convert_literal,
+    convert_numeric_literal,
+    convert_boolean_literal,
+    convert_bigint_literal,
     convert_identifier_name,
     convert_jsx_name,
     convert_jsx_member_expression,
@@ -192,6 +296,7 @@ pub use conversion::{
     convert_function_body,
     convert_class_body,
     convert_ts_type,
+    convert_ts_tuple_element,
     convert_ts_type_annotation,
     convert_ts_type_parameter_declaration,
     convert_ts_type_parameter,
@@ -237,12 +342,22 @@ fn oxc_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     // Phase 5: Node Base Class
     m.add_class::<Node>()?;
+    m.add_class::<JsonNode>()?;
 
     // Phase 7: Allocator & Memory Management
     m.add_class::<Allocator>()?;
 
     // Phase 8: parse() Function
     m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_many_with_progress, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_typescript_type, m)?)?;
+    m.add_function(wrap_pyfunction!(hash_source, m)?)?;
+
+    // Phase 12: Line Number Computation
+    m.add_function(wrap_pyfunction!(compute_line_number, m)?)?;
+    m.add_function(wrap_pyfunction!(compute_line_col, m)?)?;
+    m.add_function(wrap_pyfunction!(build_line_offset_table, m)?)?;
+    m.add_class::<LineIndex>()?;
 
     // Phase 9: Program Node
     m.add_class::<Program>()?;
@@ -250,12 +365,44 @@ fn oxc_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Phase 10: Walk Iterator
     m.add_class::<WalkIterator>()?;
     m.add_function(wrap_pyfunction!(walk, m)?)?;
+    m.add_function(wrap_pyfunction!(walk_top_level, m)?)?;
+    m.add_function(wrap_pyfunction!(walk_types, m)?)?;
+    m.add_class::<ContextWalkIterator>()?;
+    m.add_function(wrap_pyfunction!(walk_with_context, m)?)?;
+    m.add_class::<BFSWalkIterator>()?;
+    m.add_function(wrap_pyfunction!(walk_breadth_first, m)?)?;
+    m.add_function(wrap_pyfunction!(node_at_offset, m)?)?;
+    m.add_function(wrap_pyfunction!(nodes_in_range, m)?)?;
+    m.add_function(wrap_pyfunction!(node_count, m)?)?;
+    m.add_function(wrap_pyfunction!(node_type_histogram, m)?)?;
+    m.add_function(wrap_pyfunction!(visit, m)?)?;
+    m.add_function(wrap_pyfunction!(node_path_to, m)?)?;
+    m.add_class::<FunctionSignature>()?;
+    m.add_function(wrap_pyfunction!(extract_function_signatures, m)?)?;
+    m.add_class::<ClassInfo>()?;
+    m.add_function(wrap_pyfunction!(extract_class_hierarchy, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_class_info, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_ts_type_names, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_ts_interface_names, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_ts_type_alias_names, m)?)?;
+    m.add_class::<Dependency>()?;
+    m.add_function(wrap_pyfunction!(collect_dependencies, m)?)?;
+
+    // Scope Analysis
+    m.add_class::<Scope>()?;
+    m.add_function(wrap_pyfunction!(collect_scopes, m)?)?;
 
     // Phase 13: Specialized Statement Node Types
     m.add_class::<FunctionDeclaration>()?;
     m.add_class::<MethodDefinition>()?;
     m.add_class::<ClassBody>()?;
     m.add_class::<ClassDeclaration>()?;
+    m.add_class::<ClassProperty>()?;
+    m.add_class::<AccessorProperty>()?;
+    m.add_class::<StaticBlock>()?;
+    m.add_class::<Decorator>()?;
+    m.add_class::<TSAbstractMethodDefinition>()?;
+    m.add_class::<TSAbstractPropertyDefinition>()?;
     m.add_class::<VariableDeclaration>()?;
     m.add_class::<VariableDeclarator>()?;
     m.add_class::<FormalParameter>()?;
@@ -283,17 +430,38 @@ fn oxc_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Phase 14: Expression Node Types
     m.add_class::<ArrowFunctionExpression>()?;
     m.add_class::<CallExpression>()?;
+    m.add_class::<NewExpression>()?;
     m.add_class::<MemberExpression>()?;
     m.add_class::<BinaryExpression>()?;
+    m.add_class::<LogicalExpression>()?;
     m.add_class::<UnaryExpression>()?;
+    m.add_class::<UpdateExpression>()?;
+    m.add_class::<AwaitExpression>()?;
+    m.add_class::<YieldExpression>()?;
     m.add_class::<ConditionalExpression>()?;
+    m.add_class::<SequenceExpression>()?;
+    m.add_class::<ThisExpression>()?;
+    m.add_class::<Super>()?;
     m.add_class::<ObjectExpression>()?;
     m.add_class::<ArrayExpression>()?;
     m.add_class::<Identifier>()?;
     m.add_class::<Literal>()?;
+    m.add_class::<RegExpLiteral>()?;
+    m.add_class::<BigIntLiteral>()?;
+    m.add_class::<ParenthesizedExpression>()?;
+    m.add_class::<AssignmentExpression>()?;
+    m.add_class::<ArrayPattern>()?;
+    m.add_class::<ObjectPattern>()?;
+    m.add_class::<AssignmentPattern>()?;
+    m.add_class::<RestElement>()?;
+    m.add_class::<SpreadElement>()?;
+    m.add_class::<ImportExpression>()?;
+    m.add_class::<TemplateLiteral>()?;
+    m.add_class::<TemplateElement>()?;
 
     // Phase 15: Import/Export Declaration Node Types
     m.add_class::<ImportDeclaration>()?;
+    m.add_class::<ImportAttribute>()?;
     m.add_class::<ImportSpecifier>()?;
     m.add_class::<ImportDefaultSpecifier>()?;
     m.add_class::<ImportNamespaceSpecifier>()?;
@@ -312,10 +480,40 @@ fn oxc_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<TSPropertySignature>()?;
     m.add_class::<TSMethodSignature>()?;
     m.add_class::<TSInterfaceBody>()?;
+    m.add_class::<TSInterfaceHeritage>()?;
     m.add_class::<TSEnumMember>()?;
+    m.add_class::<TSModuleDeclaration>()?;
+    m.add_class::<TSModuleBlock>()?;
     m.add_class::<TSTypeParameterDeclaration>()?;
     m.add_class::<TSUnionType>()?;
     m.add_class::<TSIntersectionType>()?;
+    m.add_class::<TSConditionalType>()?;
+    m.add_class::<TSInferType>()?;
+    m.add_class::<TSImportType>()?;
+    m.add_class::<TSAsExpression>()?;
+    m.add_class::<TSSatisfiesExpression>()?;
+    m.add_class::<TSTypeAssertion>()?;
+    m.add_class::<TSNonNullExpression>()?;
+    m.add_class::<TSInstantiationExpression>()?;
+    m.add_class::<TSArrayType>()?;
+    m.add_class::<TSTupleType>()?;
+    m.add_class::<TSOptionalType>()?;
+    m.add_class::<TSRestType>()?;
+    m.add_class::<TSLiteralType>()?;
+    m.add_class::<TSAnyKeyword>()?;
+    m.add_class::<TSBigIntKeyword>()?;
+    m.add_class::<TSBooleanKeyword>()?;
+    m.add_class::<TSIntrinsicKeyword>()?;
+    m.add_class::<TSNeverKeyword>()?;
+    m.add_class::<TSNullKeyword>()?;
+    m.add_class::<TSNumberKeyword>()?;
+    m.add_class::<TSObjectKeyword>()?;
+    m.add_class::<TSStringKeyword>()?;
+    m.add_class::<TSSymbolKeyword>()?;
+    m.add_class::<TSUndefinedKeyword>()?;
+    m.add_class::<TSUnknownKeyword>()?;
+    m.add_class::<TSVoidKeyword>()?;
+    m.add_class::<TSThisType>()?;
 
     // Phase 17: JSX Node Types
     m.add_class::<JSXElement>()?;
@@ -328,6 +526,7 @@ fn oxc_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<JSXMemberExpression>()?;
     m.add_class::<JSXText>()?;
     m.add_class::<JSXExpressionContainer>()?;
+    m.add_class::<EmptyExpression>()?;
 
     Ok(())
 }
@@ -6,17 +6,33 @@
 //! Expression types:
 //! - ArrowFunctionExpression: (x) => x + 1 (CRITICAL for ChunkHound)
 //! - CallExpression: foo(a, b, c)
+//! - NewExpression: new Foo(a, b)
 //! - MemberExpression: obj.property or obj[computed]
 //! - BinaryExpression: a + b, x == y, etc.
+//! - LogicalExpression: a && b, a || b, a ?? b
 //! - UnaryExpression: !x, -y, typeof z
+//! - UpdateExpression: i++, --j
+//! - AwaitExpression / YieldExpression: await x, yield x, yield* xs
 //! - ConditionalExpression: test ? consequent : alternate
+//! - SequenceExpression: a = 1, b = 2
+//! - ThisExpression / Super: this, super
 //! - ObjectExpression: {key: value}
 //! - ArrayExpression: [1, 2, 3]
 //! - Identifier: variable or function names
 //! - Literal: numbers, strings, booleans, null
+//! - RegExpLiteral: /pattern/flags (subclass of Literal)
+//! - BigIntLiteral: 42n (subclass of Literal)
+//! - ParenthesizedExpression: (expr) (only with preserve_parens=True)
+//! - AssignmentExpression: x = 1, [a, b] = pair
+//! - ArrayPattern / ObjectPattern / AssignmentPattern / RestElement: destructuring targets
+//! - ImportExpression: import("./module")
+//! - TemplateLiteral / TemplateElement: `hello ${name}`
 
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 use crate::Span;
+use crate::core::{body_source, has_rest_param, node_to_dict, param_names, signature_source, tree_repr};
+use crate::traversal::{nodes_of_type, WalkIterator};
 
 /// Arrow function expression: (x) => x + 1
 ///
@@ -47,18 +63,35 @@ pub struct ArrowFunctionExpression {
     /// Whether function is generator (rare for arrows, but possible)
     #[pyo3(get)]
     pub is_generator: bool,
+
+    /// Whether the body is a bare expression (`x => x * 2`) rather than a block
+    #[pyo3(get)]
+    pub is_concise: bool,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
 }
 
 #[pymethods]
 impl ArrowFunctionExpression {
     /// Create a new ArrowFunctionExpression node
     #[new]
+    #[pyo3(signature = (span, params, body, is_async, is_generator, is_concise=false, start_line=0, end_line=0))]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         span: Span,
         params: Vec<Py<PyAny>>,
         body: Option<Py<PyAny>>,
         is_async: bool,
         is_generator: bool,
+        is_concise: bool,
+        start_line: usize,
+        end_line: usize,
     ) -> Self {
         Self {
             span,
@@ -66,6 +99,9 @@ impl ArrowFunctionExpression {
             body,
             is_async,
             is_generator,
+            is_concise,
+            start_line,
+            end_line,
         }
     }
 
@@ -84,14 +120,67 @@ impl ArrowFunctionExpression {
 
     /// Get line range for this node
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
-        // Placeholder: will compute from span in future phases
-        (1, 1)
+        (self.start_line, self.end_line)
+    }
+
+    /// Extract the body text: the block, or the expression of a concise arrow.
+    pub fn body_source(&self, py: Python, source: &str) -> String {
+        body_source(py, self.body.as_ref(), source)
+    }
+
+    /// Extract the signature text: everything from the start up to the body.
+    pub fn signature_source(&self, py: Python, source: &str) -> String {
+        signature_source(py, self.span, self.body.as_ref(), source)
+    }
+
+    /// Parameter names in order; None for destructuring patterns and `...rest`.
+    pub fn param_names(&self, py: Python) -> Vec<Option<String>> {
+        param_names(py, &self.params)
+    }
+
+    /// Number of parameters, including a trailing rest parameter.
+    pub fn param_count(&self) -> usize {
+        self.params.len()
+    }
+
+    /// True if the last parameter is a rest parameter (`...args`).
+    pub fn has_rest_param(&self, py: Python) -> bool {
+        has_rest_param(py, &self.params)
+    }
+
+    fn __rich_repr__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let items = pyo3::types::PyList::empty(py);
+        items.append(("span", self.span))?;
+        items.append(("params", self.params.len()))?;
+        items.append(("is_async", self.is_async))?;
+        Ok(items.into_any().unbind())
+    }
+
+    fn __str__(slf: &Bound<'_, Self>) -> String {
+        tree_repr(slf.py(), slf.as_any(), 0)
+    }
+
+    /// Walk this node's subtree; same as `walk(node, max_depth)`.
+    #[pyo3(signature = (max_depth=None))]
+    pub fn walk(slf: &Bound<'_, Self>, max_depth: Option<usize>) -> WalkIterator {
+        WalkIterator::new_with_limit(slf.clone().into_any().unbind(), max_depth)
+    }
+
+    /// All nodes of `type_name` in this node's subtree (itself included), in walk order.
+    pub fn find_type(slf: &Bound<'_, Self>, type_name: &str) -> Vec<Py<PyAny>> {
+        nodes_of_type(slf.py(), slf.clone().into_any().unbind(), type_name)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
     }
 
     fn __repr__(&self) -> String {
         format!(
-            "ArrowFunctionExpression(async={}, generator={}, span={}..{})",
-            self.is_async, self.is_generator, self.span.start, self.span.end
+            "ArrowFunctionExpression(async={}, generator={}, concise={}, lines={}-{}, span={}..{})",
+            self.is_async, self.is_generator, self.is_concise, self.start_line, self.end_line,
+            self.span.start, self.span.end
         )
     }
 }
@@ -117,21 +206,40 @@ pub struct CallExpression {
     /// Arguments passed to the function
     #[pyo3(get)]
     pub arguments: Vec<Py<PyAny>>,
+
+    /// Generic type arguments (TSTypeParameterInstantiation), e.g. `<string>`
+    #[pyo3(get)]
+    pub type_arguments: Option<Py<PyAny>>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
 }
 
 #[pymethods]
 impl CallExpression {
     /// Create a new CallExpression node
     #[new]
+    #[pyo3(signature = (span, callee, arguments, type_arguments=None, start_line=0, end_line=0))]
     pub fn new(
         span: Span,
         callee: Option<Py<PyAny>>,
         arguments: Vec<Py<PyAny>>,
+        type_arguments: Option<Py<PyAny>>,
+        start_line: usize,
+        end_line: usize,
     ) -> Self {
         Self {
             span,
             callee,
             arguments,
+            type_arguments,
+            start_line,
+            end_line,
         }
     }
 
@@ -150,7 +258,24 @@ impl CallExpression {
 
     /// Get line range for this node
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
-        (1, 1)
+        (self.start_line, self.end_line)
+    }
+
+    /// Flat name of the called function.
+    ///
+    /// `foo()` gives "foo", `console.log()` gives "console.log" and
+    /// `this.method()` gives "this.method". Returns None when any part of the
+    /// callee cannot be named (computed keys, calls, other expressions).
+    pub fn callee_name(&self, py: Python) -> PyResult<Option<String>> {
+        match &self.callee {
+            Some(callee) => dotted_name(callee.bind(py)),
+            None => Ok(None),
+        }
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
     }
 
     fn __repr__(&self) -> String {
@@ -158,6 +283,88 @@ impl CallExpression {
     }
 }
 
+/// New expression: new Foo(a, b)
+///
+/// Example in source code:
+///     new Date()
+///     new Map<string, number>(entries)
+#[pyclass]
+pub struct NewExpression {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Constructor being called (Identifier, MemberExpression, etc.)
+    #[pyo3(get)]
+    pub callee: Option<Py<PyAny>>,
+
+    /// Arguments passed to the constructor (SpreadElement for `...args`)
+    #[pyo3(get)]
+    pub arguments: Vec<Py<PyAny>>,
+
+    /// Generic type arguments (TSTypeParameterInstantiation), e.g. `<string, number>`
+    #[pyo3(get)]
+    pub type_arguments: Option<Py<PyAny>>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl NewExpression {
+    /// Node type property (always "NewExpression")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "NewExpression"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("NewExpression(args={}, span={}..{})", self.arguments.len(), self.span.start, self.span.end)
+    }
+}
+
+/// Dotted name of an Identifier, `this`, or a chain of named member accesses.
+fn dotted_name(node: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+    match node.getattr("type")?.extract::<String>()?.as_str() {
+        "Identifier" => node.getattr("name")?.extract(),
+        "ThisExpression" => Ok(Some("this".to_string())),
+        "MemberExpression" => {
+            let member = node.extract::<PyRef<MemberExpression>>()?;
+            let Some(property) = member.property_name(node.py())? else {
+                return Ok(None);
+            };
+            let object = match &member.object {
+                Some(object) => dotted_name(object.bind(node.py()))?,
+                None => None,
+            };
+            Ok(object.map(|object| format!("{}.{}", object, property)))
+        }
+        _ => Ok(None),
+    }
+}
+
 /// Member expression: obj.property or obj[computed]
 ///
 /// Represents property access in two forms:
@@ -186,23 +393,36 @@ pub struct MemberExpression {
     /// Whether access is computed (obj[x]) vs static (obj.x)
     #[pyo3(get)]
     pub computed: bool,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
 }
 
 #[pymethods]
 impl MemberExpression {
     /// Create a new MemberExpression node
     #[new]
+    #[pyo3(signature = (span, object, property, computed, start_line=0, end_line=0))]
     pub fn new(
         span: Span,
         object: Option<Py<PyAny>>,
         property: Option<Py<PyAny>>,
         computed: bool,
+        start_line: usize,
+        end_line: usize,
     ) -> Self {
         Self {
             span,
             object,
             property,
             computed,
+            start_line,
+            end_line,
         }
     }
 
@@ -221,7 +441,31 @@ impl MemberExpression {
 
     /// Get line range for this node
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
-        (1, 1)
+        (self.start_line, self.end_line)
+    }
+
+    /// Resolved property name.
+    ///
+    /// Returns the identifier name for static access (`obj.foo`), the string
+    /// value for a string literal key (`obj["foo"]`), and None for any other
+    /// computed key.
+    pub fn property_name(&self, py: Python) -> PyResult<Option<String>> {
+        let Some(property) = &self.property else {
+            return Ok(None);
+        };
+        let property = property.bind(py);
+        if !self.computed {
+            return property.getattr("name")?.extract();
+        }
+        if property.getattr("type")?.extract::<String>()? != "Literal" {
+            return Ok(None);
+        }
+        Ok(property.getattr("value")?.extract::<String>().ok())
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
     }
 
     fn __repr__(&self) -> String {
@@ -239,7 +483,6 @@ impl MemberExpression {
 ///     a - b
 ///     x * y
 ///     x === y
-///     a && b
 #[pyclass]
 pub struct BinaryExpression {
     /// Source location
@@ -250,30 +493,43 @@ pub struct BinaryExpression {
     #[pyo3(get)]
     pub left: Option<Py<PyAny>>,
 
-    /// Operator: "+", "-", "*", "/", "%", "==", "!=", "===", "!==", "<", ">", "<=", ">=", "&&", "||", etc.
+    /// Operator: "+", "-", "*", "/", "%", "==", "!=", "===", "!==", "<", ">", "<=", ">=", "in", etc.
     #[pyo3(get)]
     pub operator: String,
 
     /// Right operand
     #[pyo3(get)]
     pub right: Option<Py<PyAny>>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
 }
 
 #[pymethods]
 impl BinaryExpression {
     /// Create a new BinaryExpression node
     #[new]
+    #[pyo3(signature = (span, left, operator, right, start_line=0, end_line=0))]
     pub fn new(
         span: Span,
         left: Option<Py<PyAny>>,
         operator: String,
         right: Option<Py<PyAny>>,
+        start_line: usize,
+        end_line: usize,
     ) -> Self {
         Self {
             span,
             left,
             operator,
             right,
+            start_line,
+            end_line,
         }
     }
 
@@ -292,7 +548,30 @@ impl BinaryExpression {
 
     /// Get line range for this node
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
-        (1, 1)
+        (self.start_line, self.end_line)
+    }
+
+    /// True for equality, relational, `in`, and `instanceof` operators
+    pub fn is_comparison(&self) -> bool {
+        matches!(
+            self.operator.as_str(),
+            "==" | "!=" | "===" | "!==" | "<" | ">" | "<=" | ">=" | "in" | "instanceof"
+        )
+    }
+
+    /// True for `+`, `-`, `*`, `/`, `%`, and `**`
+    pub fn is_arithmetic(&self) -> bool {
+        matches!(self.operator.as_str(), "+" | "-" | "*" | "/" | "%" | "**")
+    }
+
+    /// True for `&`, `|`, `^`, `<<`, `>>`, and `>>>`
+    pub fn is_bitwise(&self) -> bool {
+        matches!(self.operator.as_str(), "&" | "|" | "^" | "<<" | ">>" | ">>>")
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
     }
 
     fn __repr__(&self) -> String {
@@ -300,6 +579,72 @@ impl BinaryExpression {
     }
 }
 
+/// Logical expression: left && right, left || right, left ?? right
+///
+/// Short-circuit operators are kept apart from BinaryExpression so tools can
+/// tell conditional evaluation from arithmetic and comparisons.
+///
+/// Example in source code:
+///     isOpen && <Modal />
+///     name || "anonymous"
+///     value ?? fallback
+#[pyclass]
+pub struct LogicalExpression {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Left operand
+    #[pyo3(get)]
+    pub left: Py<PyAny>,
+
+    /// Operator: "&&", "||", or "??"
+    #[pyo3(get)]
+    pub operator: String,
+
+    /// Right operand (only evaluated depending on the left)
+    #[pyo3(get)]
+    pub right: Py<PyAny>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl LogicalExpression {
+    /// Node type property (always "LogicalExpression")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "LogicalExpression"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("LogicalExpression(op='{}', span={}..{})", self.operator, self.span.start, self.span.end)
+    }
+}
+
 /// Unary expression: op argument
 ///
 /// Represents operations with one operand and an operator.
@@ -325,21 +670,34 @@ pub struct UnaryExpression {
     /// Operand
     #[pyo3(get)]
     pub argument: Option<Py<PyAny>>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
 }
 
 #[pymethods]
 impl UnaryExpression {
     /// Create a new UnaryExpression node
     #[new]
+    #[pyo3(signature = (span, operator, argument, start_line=0, end_line=0))]
     pub fn new(
         span: Span,
         operator: String,
         argument: Option<Py<PyAny>>,
+        start_line: usize,
+        end_line: usize,
     ) -> Self {
         Self {
             span,
             operator,
             argument,
+            start_line,
+            end_line,
         }
     }
 
@@ -358,7 +716,42 @@ impl UnaryExpression {
 
     /// Get line range for this node
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
-        (1, 1)
+        (self.start_line, self.end_line)
+    }
+
+    /// True for logical negation (`!x`)
+    pub fn is_negation(&self) -> bool {
+        self.operator == "!"
+    }
+
+    /// True for arithmetic negation (`-x`)
+    pub fn is_arithmetic_negation(&self) -> bool {
+        self.operator == "-"
+    }
+
+    /// True for `typeof x`
+    pub fn is_typeof(&self) -> bool {
+        self.operator == "typeof"
+    }
+
+    /// True for `void x`
+    pub fn is_void(&self) -> bool {
+        self.operator == "void"
+    }
+
+    /// True for `delete x`
+    pub fn is_delete(&self) -> bool {
+        self.operator == "delete"
+    }
+
+    /// True for bitwise NOT (`~x`)
+    pub fn is_bitwise_not(&self) -> bool {
+        self.operator == "~"
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
     }
 
     fn __repr__(&self) -> String {
@@ -366,54 +759,44 @@ impl UnaryExpression {
     }
 }
 
-/// Conditional expression: test ? consequent : alternate
-///
-/// The ternary operator expression.
+/// Update expression: increment or decrement
 ///
 /// Example in source code:
-///     x > 0 ? 'positive' : 'non-positive'
-///     isAdmin ? showAdminPanel() : showUserPanel()
+///     i++
+///     --count
 #[pyclass]
-pub struct ConditionalExpression {
+pub struct UpdateExpression {
     /// Source location
     #[pyo3(get)]
     pub span: Span,
 
-    /// Condition to test
+    /// Operator: "++" or "--"
     #[pyo3(get)]
-    pub test: Option<Py<PyAny>>,
+    pub operator: String,
 
-    /// Value if condition is true
+    /// True for prefix form (`--j`), False for postfix (`j++`)
     #[pyo3(get)]
-    pub consequent: Option<Py<PyAny>>,
+    pub prefix: bool,
 
-    /// Value if condition is false
+    /// Operand (Identifier or MemberExpression)
     #[pyo3(get)]
-    pub alternate: Option<Py<PyAny>>,
+    pub argument: Option<Py<PyAny>>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
 }
 
 #[pymethods]
-impl ConditionalExpression {
-    /// Create a new ConditionalExpression node
-    #[new]
-    pub fn new(
-        span: Span,
-        test: Option<Py<PyAny>>,
-        consequent: Option<Py<PyAny>>,
-        alternate: Option<Py<PyAny>>,
-    ) -> Self {
-        Self {
-            span,
-            test,
-            consequent,
-            alternate,
-        }
-    }
-
-    /// Node type property (always "ConditionalExpression")
+impl UpdateExpression {
+    /// Node type property (always "UpdateExpression")
     #[getter]
     pub fn r#type(&self) -> &str {
-        "ConditionalExpression"
+        "UpdateExpression"
     }
 
     /// Extract source text for this node
@@ -425,45 +808,48 @@ impl ConditionalExpression {
 
     /// Get line range for this node
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
-        (1, 1)
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
     }
 
     fn __repr__(&self) -> String {
-        format!("ConditionalExpression(span={}..{})", self.span.start, self.span.end)
+        format!("UpdateExpression(op='{}', prefix={}, span={}..{})", self.operator, self.prefix, self.span.start, self.span.end)
     }
 }
 
-/// Object expression: {key: value, ...}
-///
-/// Represents object literals.
+/// Await expression: await promise
 ///
 /// Example in source code:
-///     {a: 1, b: 2}
-///     {x, y, z}
-///     {[computed]: value}
+///     await fetch(url)
 #[pyclass]
-pub struct ObjectExpression {
+pub struct AwaitExpression {
     /// Source location
     #[pyo3(get)]
     pub span: Span,
 
-    /// Object properties
+    /// Awaited expression
     #[pyo3(get)]
-    pub properties: Vec<Py<PyAny>>,
+    pub argument: Py<PyAny>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
 }
 
 #[pymethods]
-impl ObjectExpression {
-    /// Create a new ObjectExpression node
-    #[new]
-    pub fn new(span: Span, properties: Vec<Py<PyAny>>) -> Self {
-        Self { span, properties }
-    }
-
-    /// Node type property (always "ObjectExpression")
+impl AwaitExpression {
+    /// Node type property (always "AwaitExpression")
     #[getter]
     pub fn r#type(&self) -> &str {
-        "ObjectExpression"
+        "AwaitExpression"
     }
 
     /// Extract source text for this node
@@ -475,45 +861,53 @@ impl ObjectExpression {
 
     /// Get line range for this node
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
-        (1, 1)
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
     }
 
     fn __repr__(&self) -> String {
-        format!("ObjectExpression(props={}, span={}..{})", self.properties.len(), self.span.start, self.span.end)
+        format!("AwaitExpression(span={}..{})", self.span.start, self.span.end)
     }
 }
 
-/// Array expression: [1, 2, 3]
-///
-/// Represents array literals.
+/// Yield expression inside a generator: yield value / yield* iterable
 ///
 /// Example in source code:
-///     [1, 2, 3]
-///     []
-///     [a, b, c]
+///     yield item
+///     yield* other()
 #[pyclass]
-pub struct ArrayExpression {
+pub struct YieldExpression {
     /// Source location
     #[pyo3(get)]
     pub span: Span,
 
-    /// Array elements
+    /// Yielded expression (None for a bare `yield`)
     #[pyo3(get)]
-    pub elements: Vec<Py<PyAny>>,
+    pub argument: Option<Py<PyAny>>,
+
+    /// True for `yield*` (delegating to another iterable)
+    #[pyo3(get)]
+    pub delegate: bool,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
 }
 
 #[pymethods]
-impl ArrayExpression {
-    /// Create a new ArrayExpression node
-    #[new]
-    pub fn new(span: Span, elements: Vec<Py<PyAny>>) -> Self {
-        Self { span, elements }
-    }
-
-    /// Node type property (always "ArrayExpression")
+impl YieldExpression {
+    /// Node type property (always "YieldExpression")
     #[getter]
     pub fn r#type(&self) -> &str {
-        "ArrayExpression"
+        "YieldExpression"
     }
 
     /// Extract source text for this node
@@ -525,45 +919,80 @@ impl ArrayExpression {
 
     /// Get line range for this node
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
-        (1, 1)
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
     }
 
     fn __repr__(&self) -> String {
-        format!("ArrayExpression(elements={}, span={}..{})", self.elements.len(), self.span.start, self.span.end)
+        format!("YieldExpression(delegate={}, span={}..{})", self.delegate, self.span.start, self.span.end)
     }
 }
 
-/// Identifier: variable or function name
+/// Conditional expression: test ? consequent : alternate
 ///
-/// Represents variable or function references by name.
+/// The ternary operator expression.
 ///
 /// Example in source code:
-///     x
-///     myVariable
-///     functionName
+///     x > 0 ? 'positive' : 'non-positive'
+///     isAdmin ? showAdminPanel() : showUserPanel()
 #[pyclass]
-pub struct Identifier {
+pub struct ConditionalExpression {
     /// Source location
     #[pyo3(get)]
     pub span: Span,
 
-    /// Name of the identifier
+    /// Condition to test
     #[pyo3(get)]
-    pub name: String,
+    pub test: Option<Py<PyAny>>,
+
+    /// Value if condition is true
+    #[pyo3(get)]
+    pub consequent: Option<Py<PyAny>>,
+
+    /// Value if condition is false
+    #[pyo3(get)]
+    pub alternate: Option<Py<PyAny>>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
 }
 
 #[pymethods]
-impl Identifier {
-    /// Create a new Identifier node
+impl ConditionalExpression {
+    /// Create a new ConditionalExpression node
     #[new]
-    pub fn new(span: Span, name: String) -> Self {
-        Self { span, name }
+    #[pyo3(signature = (span, test, consequent, alternate, start_line=0, end_line=0))]
+    pub fn new(
+        span: Span,
+        test: Option<Py<PyAny>>,
+        consequent: Option<Py<PyAny>>,
+        alternate: Option<Py<PyAny>>,
+        start_line: usize,
+        end_line: usize,
+    ) -> Self {
+        Self {
+            span,
+            test,
+            consequent,
+            alternate,
+            start_line,
+            end_line,
+        }
     }
 
-    /// Node type property (always "Identifier")
+    /// Node type property (always "ConditionalExpression")
     #[getter]
     pub fn r#type(&self) -> &str {
-        "Identifier"
+        "ConditionalExpression"
     }
 
     /// Extract source text for this node
@@ -575,52 +1004,228 @@ impl Identifier {
 
     /// Get line range for this node
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
-        (1, 1)
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
     }
 
     fn __repr__(&self) -> String {
-        format!("Identifier('{}', span={}..{})", self.name, self.span.start, self.span.end)
+        format!("ConditionalExpression(span={}..{})", self.span.start, self.span.end)
     }
 }
 
-/// Literal value: number, string, boolean, null
+/// Sequence expression (comma operator): a = 1, b = 2
 ///
-/// Represents literal values in the source code.
+/// Evaluates each expression in order and yields the value of the last one.
 ///
 /// Example in source code:
-///     42
-///     3.14
-///     "hello"
-///     true
-///     false
-///     null
+///     for (i = 0, j = n; i < j; i++, j--) {}
+///     (init(), run())
 #[pyclass]
-pub struct Literal {
+pub struct SequenceExpression {
     /// Source location
     #[pyo3(get)]
     pub span: Span,
 
-    /// Parsed value (int, float, string, bool, None)
+    /// Expressions in evaluation order
     #[pyo3(get)]
-    pub value: Py<PyAny>,
+    pub expressions: Vec<Py<PyAny>>,
 
-    /// Raw source text representation
+    /// Start line number (1-indexed)
     #[pyo3(get)]
-    pub raw: String,
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
 }
 
 #[pymethods]
-impl Literal {
-    /// Create a new Literal node
+impl SequenceExpression {
+    /// Node type property (always "SequenceExpression")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "SequenceExpression"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SequenceExpression(expressions={}, span={}..{})", self.expressions.len(), self.span.start, self.span.end)
+    }
+}
+
+/// This expression: this
+///
+/// Example in source code:
+///     this.count += 1
+///     const self = this
+#[pyclass]
+pub struct ThisExpression {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl ThisExpression {
+    /// Node type property (always "ThisExpression")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "ThisExpression"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ThisExpression(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// Super reference in a class: super
+///
+/// Appears as the callee of `super(...)` or the object of `super.method()`.
+///
+/// Example in source code:
+///     super(props)
+///     super.render()
+#[pyclass]
+pub struct Super {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl Super {
+    /// Node type property (always "Super")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "Super"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Super(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// Object expression: {key: value, ...}
+///
+/// Represents object literals.
+///
+/// Example in source code:
+///     {a: 1, b: 2}
+///     {x, y, z}
+///     {[computed]: value}
+#[pyclass]
+pub struct ObjectExpression {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Object properties
+    #[pyo3(get)]
+    pub properties: Vec<Py<PyAny>>,
+
+    /// Static key name of each entry in `properties` (None for computed keys and spreads)
+    #[pyo3(get)]
+    pub keys: Vec<Option<String>>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl ObjectExpression {
+    /// Create a new ObjectExpression node
     #[new]
-    pub fn new(span: Span, value: Py<PyAny>, raw: String) -> Self {
-        Self { span, value, raw }
+    #[pyo3(signature = (span, properties, keys=None, start_line=0, end_line=0))]
+    pub fn new(
+        span: Span,
+        properties: Vec<Py<PyAny>>,
+        keys: Option<Vec<Option<String>>>,
+        start_line: usize,
+        end_line: usize,
+    ) -> Self {
+        let keys = keys.unwrap_or_else(|| vec![None; properties.len()]);
+        Self { span, properties, keys, start_line, end_line }
     }
 
-    /// Node type property (always "Literal")
+    /// Node type property (always "ObjectExpression")
     #[getter]
     pub fn r#type(&self) -> &str {
-        "Literal"
+        "ObjectExpression"
     }
 
     /// Extract source text for this node
@@ -632,10 +1237,969 @@ impl Literal {
 
     /// Get line range for this node
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
-        (1, 1)
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
     }
 
     fn __repr__(&self) -> String {
-        format!("Literal(raw='{}', span={}..{})", self.raw, self.span.start, self.span.end)
+        format!("ObjectExpression(props={}, span={}..{})", self.properties.len(), self.span.start, self.span.end)
+    }
+
+    /// Map static key names to value nodes.
+    ///
+    /// Computed keys (`[k]: v`) and spreads (`...rest`) are omitted; for
+    /// duplicate keys the last value wins, as in JavaScript.
+    ///
+    /// Example:
+    ///     { timeout: 5000, [k]: 1 }  // {"timeout": Literal(5000)}
+    pub fn property_map(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let map = PyDict::new(py);
+        for (key, value) in self.keys.iter().zip(&self.properties) {
+            if let Some(key) = key {
+                map.set_item(key, value)?;
+            }
+        }
+        Ok(map.unbind())
+    }
+
+    /// True if the object has a property with this static key
+    pub fn has_key(&self, key: &str) -> bool {
+        self.keys.iter().any(|k| k.as_deref() == Some(key))
+    }
+
+    /// Value node for a static key (the last one if repeated), or None
+    pub fn get_value(&self, py: Python, key: &str) -> Option<Py<PyAny>> {
+        self.keys.iter().zip(&self.properties)
+            .rev()
+            .find(|(k, _)| k.as_deref() == Some(key))
+            .map(|(_, value)| value.clone_ref(py))
+    }
+}
+
+/// Array expression: [1, 2, 3]
+///
+/// Represents array literals.
+///
+/// Example in source code:
+///     [1, 2, 3]
+///     []
+///     [a, b, c]
+#[pyclass]
+pub struct ArrayExpression {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Array elements
+    #[pyo3(get)]
+    pub elements: Vec<Py<PyAny>>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl ArrayExpression {
+    /// Create a new ArrayExpression node
+    #[new]
+    #[pyo3(signature = (span, elements, start_line=0, end_line=0))]
+    pub fn new(span: Span, elements: Vec<Py<PyAny>>, start_line: usize, end_line: usize) -> Self {
+        Self { span, elements, start_line, end_line }
+    }
+
+    /// Node type property (always "ArrayExpression")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "ArrayExpression"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ArrayExpression(elements={}, span={}..{})", self.elements.len(), self.span.start, self.span.end)
+    }
+}
+
+/// Identifier: variable or function name
+///
+/// Represents variable or function references by name.
+///
+/// Example in source code:
+///     x
+///     myVariable
+///     functionName
+#[pyclass]
+pub struct Identifier {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Name of the identifier
+    #[pyo3(get)]
+    pub name: String,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl Identifier {
+    /// Create a new Identifier node
+    #[new]
+    #[pyo3(signature = (span, name, start_line=0, end_line=0))]
+    pub fn new(span: Span, name: String, start_line: usize, end_line: usize) -> Self {
+        Self { span, name, start_line, end_line }
+    }
+
+    /// Node type property (always "Identifier")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "Identifier"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Identifier('{}', span={}..{})", self.name, self.span.start, self.span.end)
+    }
+}
+
+/// Literal value: number, string, boolean, null
+///
+/// Represents literal values in the source code.
+///
+/// Example in source code:
+///     42
+///     3.14
+///     "hello"
+///     true
+///     false
+///     null
+#[pyclass(subclass)]
+pub struct Literal {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Parsed value (int, float, string, bool, None)
+    #[pyo3(get)]
+    pub value: Py<PyAny>,
+
+    /// Raw source text representation
+    #[pyo3(get)]
+    pub raw: String,
+
+    /// Literal kind: "string", "number", "boolean", "null", "regex", or "bigint"
+    #[pyo3(get)]
+    pub kind: String,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl Literal {
+    /// Create a new Literal node
+    ///
+    /// When `kind` is omitted it is inferred from the Python type of `value`.
+    #[new]
+    #[pyo3(signature = (span, value, raw, kind=None, start_line=0, end_line=0))]
+    pub fn new(py: Python, span: Span, value: Py<PyAny>, raw: String, kind: Option<String>, start_line: usize, end_line: usize) -> Self {
+        let kind = kind.unwrap_or_else(|| literal_kind(value.bind(py)).to_string());
+        Self { span, value, raw, kind, start_line, end_line }
+    }
+
+    /// Node type property (always "Literal")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "Literal"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Literal(raw='{}', span={}..{})", self.raw, self.span.start, self.span.end)
+    }
+
+    /// True for string literals
+    pub fn is_string(&self) -> bool {
+        self.kind == "string"
+    }
+
+    /// True for numeric literals (not bigints)
+    pub fn is_number(&self) -> bool {
+        self.kind == "number"
+    }
+
+    /// True for `null`
+    pub fn is_null(&self) -> bool {
+        self.kind == "null"
+    }
+
+    /// Truthiness under JavaScript semantics.
+    ///
+    /// False for `null`, `false`, `""`, `0`, `NaN`, and `0n`; True otherwise
+    /// (regular expressions are always truthy).
+    pub fn is_truthy(&self, py: Python) -> bool {
+        let value = self.value.bind(py);
+        match self.kind.as_str() {
+            "null" => false,
+            "regex" => true,
+            "bigint" => value.extract::<String>().is_ok_and(|digits| !digits.trim_start_matches('0').is_empty()),
+            "number" => value.extract::<f64>().is_ok_and(|n| n != 0.0 && !n.is_nan()),
+            _ => value.is_truthy().unwrap_or(false),
+        }
+    }
+}
+
+/// Literal kind for a Python value, used when a Literal is built without one.
+fn literal_kind(value: &Bound<'_, PyAny>) -> &'static str {
+    if value.is_none() {
+        "null"
+    } else if value.is_instance_of::<pyo3::types::PyBool>() {
+        "boolean"
+    } else if value.is_instance_of::<pyo3::types::PyInt>() || value.is_instance_of::<pyo3::types::PyFloat>() {
+        "number"
+    } else {
+        "string"
+    }
+}
+
+/// Regular expression literal: /pattern/flags
+///
+/// Subclass of Literal, so `isinstance(node, Literal)` still holds. The
+/// inherited `value` is None because a regex has no plain Python equivalent;
+/// use `pattern` and `flags` instead.
+///
+/// Example in source code:
+///     /^hello/gi
+#[pyclass(extends = Literal)]
+pub struct RegExpLiteral {
+    /// Pattern text between the slashes
+    #[pyo3(get)]
+    pub pattern: String,
+
+    /// Flags after the closing slash (e.g. "gi")
+    #[pyo3(get)]
+    pub flags: String,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl RegExpLiteral {
+    /// Node type property (always "RegExpLiteral")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "RegExpLiteral"
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("RegExpLiteral(pattern='{}', flags='{}')", self.pattern, self.flags)
+    }
+}
+
+/// BigInt literal: 42n
+///
+/// Subclass of Literal. The inherited `value` is the base-10 digit string
+/// without the `n` suffix (Python ints could hold it, but a string keeps the
+/// distinction from regular numbers explicit); `raw` is the source text.
+///
+/// Example in source code:
+///     9007199254740993n
+///     0xFFn
+#[pyclass(extends = Literal)]
+pub struct BigIntLiteral {
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl BigIntLiteral {
+    /// Node type property (always "BigIntLiteral")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "BigIntLiteral"
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    fn __repr__(slf: PyRef<'_, Self>) -> String {
+        let base = slf.as_super();
+        format!("BigIntLiteral(raw='{}', span={}..{})", base.raw, base.span.start, base.span.end)
+    }
+}
+
+/// Parenthesized expression: (expr)
+///
+/// Only produced when parsing with `preserve_parens=True`; otherwise the
+/// parentheses are dropped and the inner expression appears directly.
+///
+/// Example in source code:
+///     (a + b) * c
+#[pyclass]
+pub struct ParenthesizedExpression {
+    /// Source location (including the parentheses)
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Wrapped expression
+    #[pyo3(get)]
+    pub expression: Py<PyAny>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl ParenthesizedExpression {
+    /// Node type property (always "ParenthesizedExpression")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "ParenthesizedExpression"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ParenthesizedExpression(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// Assignment expression: target = value
+///
+/// The left side is an Identifier, MemberExpression, or (for destructuring)
+/// an ArrayPattern / ObjectPattern.
+///
+/// Example in source code:
+///     x = 1
+///     count += 2
+///     [a, b] = [b, a]
+#[pyclass]
+pub struct AssignmentExpression {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Assignment operator ("=", "+=", "??=", ...)
+    #[pyo3(get)]
+    pub operator: String,
+
+    /// Assignment target
+    #[pyo3(get)]
+    pub left: Py<PyAny>,
+
+    /// Assigned value
+    #[pyo3(get)]
+    pub right: Py<PyAny>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl AssignmentExpression {
+    /// Node type property (always "AssignmentExpression")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "AssignmentExpression"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("AssignmentExpression(operator='{}', span={}..{})", self.operator, self.span.start, self.span.end)
+    }
+}
+
+/// Array destructuring target: [a, b, ...rest]
+///
+/// Example in source code:
+///     [a, , b] = values
+#[pyclass]
+pub struct ArrayPattern {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Element targets (None for holes)
+    #[pyo3(get)]
+    pub elements: Vec<Option<Py<PyAny>>>,
+
+    /// Trailing rest element (RestElement)
+    #[pyo3(get)]
+    pub rest: Option<Py<PyAny>>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl ArrayPattern {
+    /// Node type property (always "ArrayPattern")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "ArrayPattern"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ArrayPattern(elements={}, span={}..{})", self.elements.len(), self.span.start, self.span.end)
+    }
+}
+
+/// Object destructuring target: { x, y: z, ...rest }
+///
+/// Like ObjectExpression, `properties` holds the property values, i.e. the
+/// targets being assigned (`z` in `{ y: z }`), and `keys` holds the matching
+/// key names (`y`).
+///
+/// Example in source code:
+///     ({ x, y } = point)
+#[pyclass]
+pub struct ObjectPattern {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Property targets
+    #[pyo3(get)]
+    pub properties: Vec<Py<PyAny>>,
+
+    /// Static key name of each entry in `properties` (None for computed keys)
+    #[pyo3(get)]
+    pub keys: Vec<Option<String>>,
+
+    /// Trailing rest element (RestElement)
+    #[pyo3(get)]
+    pub rest: Option<Py<PyAny>>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl ObjectPattern {
+    /// Node type property (always "ObjectPattern")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "ObjectPattern"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ObjectPattern(properties={}, span={}..{})", self.properties.len(), self.span.start, self.span.end)
+    }
+}
+
+/// Destructuring target with a default value: target = default
+///
+/// Example in source code:
+///     [a = 1] = values
+#[pyclass]
+pub struct AssignmentPattern {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Assignment target
+    #[pyo3(get)]
+    pub left: Py<PyAny>,
+
+    /// Default value
+    #[pyo3(get)]
+    pub right: Py<PyAny>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl AssignmentPattern {
+    /// Node type property (always "AssignmentPattern")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "AssignmentPattern"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("AssignmentPattern(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// Rest target in a destructuring pattern: ...rest
+///
+/// Example in source code:
+///     [first, ...others] = values
+#[pyclass]
+pub struct RestElement {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Target receiving the remaining elements
+    #[pyo3(get)]
+    pub argument: Py<PyAny>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl RestElement {
+    /// Node type property (always "RestElement")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "RestElement"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("RestElement(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// Spread in a call argument list or array literal: ...items
+///
+/// Example in source code:
+///     f(...args)
+///     [...arr, extra]
+#[pyclass]
+pub struct SpreadElement {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Expression being spread
+    #[pyo3(get)]
+    pub argument: Py<PyAny>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl SpreadElement {
+    /// Node type property (always "SpreadElement")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "SpreadElement"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SpreadElement(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// Dynamic import expression: import(source)
+///
+/// Example in source code:
+///     import("./module.js")
+///     import(`./locale/${lang}.js`, { with: { type: "json" } })
+#[pyclass]
+pub struct ImportExpression {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Module specifier expression (usually a string Literal)
+    #[pyo3(get)]
+    pub source: Py<PyAny>,
+
+    /// Optional second argument (import options)
+    #[pyo3(get)]
+    pub options: Option<Py<PyAny>>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl ImportExpression {
+    /// Node type property (always "ImportExpression")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "ImportExpression"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ImportExpression(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// Template literal: `hello ${name}`
+///
+/// `quasis` holds the string parts and `expressions` the embedded
+/// expressions between them, so there is always one more quasi than
+/// expression.
+///
+/// Example in source code:
+///     `hello ${name}, you are ${age} years old`
+#[pyclass]
+pub struct TemplateLiteral {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// String parts (list of TemplateElement), in source order
+    #[pyo3(get)]
+    pub quasis: Vec<Py<PyAny>>,
+
+    /// Embedded `${...}` expressions, in source order
+    #[pyo3(get)]
+    pub expressions: Vec<Py<PyAny>>,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl TemplateLiteral {
+    /// Node type property (always "TemplateLiteral")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "TemplateLiteral"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TemplateLiteral(quasis={}, expressions={}, span={}..{})",
+            self.quasis.len(), self.expressions.len(), self.span.start, self.span.end
+        )
+    }
+}
+
+/// String part of a template literal, between backticks and `${...}`
+///
+/// Example in source code:
+///     `hello ${name}!`  // quasis "hello " and "!"
+#[pyclass]
+pub struct TemplateElement {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Text with escape sequences processed (None for invalid escapes in tagged templates)
+    #[pyo3(get)]
+    pub cooked: Option<String>,
+
+    /// Text exactly as written in the source
+    #[pyo3(get)]
+    pub raw: String,
+
+    /// True for the last quasi of the template
+    #[pyo3(get)]
+    pub tail: bool,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl TemplateElement {
+    /// Node type property (always "TemplateElement")
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "TemplateElement"
+    }
+
+    /// Extract source text for this node
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TemplateElement(raw={:?}, tail={}, span={}..{})", self.raw, self.tail, self.span.start, self.span.end)
     }
 }
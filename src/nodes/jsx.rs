@@ -3,7 +3,25 @@
 //! Implements JSX AST node types as PyO3 classes for React/JSX support.
 
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 use crate::Span;
+use crate::core::node_to_dict;
+
+/// Resolve a JSX element name node (JSXIdentifier or JSXMemberExpression) to a string.
+fn jsx_name_str(name: &Bound<'_, PyAny>) -> PyResult<String> {
+    if let Ok(ident) = name.extract::<PyRef<'_, JSXIdentifier>>() {
+        return Ok(ident.name.clone());
+    }
+    let member = name.extract::<PyRef<'_, JSXMemberExpression>>()?;
+    let py = name.py();
+    let object = jsx_name_str(member.object.bind(py))?;
+    Ok(format!("{}.{}", object, member.property.borrow(py).name))
+}
+
+/// Lowercase names are intrinsic elements; member expressions are always components.
+fn is_intrinsic_name(name: &str) -> bool {
+    !name.contains('.') && name.chars().next().is_some_and(|c| c.is_ascii_lowercase())
+}
 
 // =============================================================================
 // JSX Element Nodes
@@ -38,10 +56,25 @@ impl JSXElement {
         "JSXElement"
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("JSXElement(span={})", self.span.start)
     }
 
+    /// Element name as a plain string, from the opening element ("div", "React.Fragment")
+    pub fn component_name(&self, py: Python) -> PyResult<String> {
+        self.opening_element.borrow(py).name_str(py)
+    }
+
+    /// True for custom components (`<MyButton>`, `<UI.Button>`), False for HTML tags
+    pub fn is_component(&self, py: Python) -> bool {
+        jsx_name_str(self.opening_element.borrow(py).name.bind(py)).is_ok_and(|name| !is_intrinsic_name(&name))
+    }
+
     pub fn get_text(&self, source: &str) -> String {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
@@ -82,6 +115,11 @@ impl JSXOpeningElement {
         "JSXOpeningElement"
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("JSXOpeningElement(self_closing={})", self.self_closing)
     }
@@ -95,6 +133,16 @@ impl JSXOpeningElement {
         let end_line = source[..self.span.end.min(source.len())].matches('\n').count() + 1;
         (start_line, end_line)
     }
+
+    /// Element name as a plain string ("div", "Foo.Bar", "svg:rect")
+    pub fn name_str(&self, py: Python) -> PyResult<String> {
+        jsx_name_str(self.name.bind(py))
+    }
+
+    /// True for intrinsic (HTML) elements, i.e. names starting with a lowercase letter
+    pub fn is_intrinsic(&self, py: Python) -> bool {
+        jsx_name_str(self.name.bind(py)).is_ok_and(|name| is_intrinsic_name(&name))
+    }
 }
 
 /// JSX closing element: </div>
@@ -117,10 +165,25 @@ impl JSXClosingElement {
         "JSXClosingElement"
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("JSXClosingElement(span={})", self.span.start)
     }
 
+    /// Element name as a plain string ("div", "Foo.Bar", "svg:rect")
+    pub fn name_str(&self, py: Python) -> PyResult<String> {
+        jsx_name_str(self.name.bind(py))
+    }
+
+    /// True for intrinsic (HTML) elements, i.e. names starting with a lowercase letter
+    pub fn is_intrinsic(&self, py: Python) -> bool {
+        jsx_name_str(self.name.bind(py)).is_ok_and(|name| is_intrinsic_name(&name))
+    }
+
     pub fn get_text(&self, source: &str) -> String {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
@@ -157,6 +220,11 @@ impl JSXFragment {
         "JSXFragment"
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("JSXFragment(children={})", self.children.len())
     }
@@ -200,6 +268,11 @@ impl JSXAttribute {
         "JSXAttribute"
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("JSXAttribute(span={})", self.span.start)
     }
@@ -235,6 +308,11 @@ impl JSXSpreadAttribute {
         "JSXSpreadAttribute"
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("JSXSpreadAttribute(span={})", self.span.start)
     }
@@ -274,6 +352,11 @@ impl JSXIdentifier {
         "JSXIdentifier"
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("JSXIdentifier(name='{}')", self.name)
     }
@@ -313,6 +396,11 @@ impl JSXMemberExpression {
         "JSXMemberExpression"
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("JSXMemberExpression(span={})", self.span.start)
     }
@@ -356,6 +444,11 @@ impl JSXText {
         "JSXText"
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("JSXText(value='{}')", self.value)
     }
@@ -371,6 +464,42 @@ impl JSXText {
     }
 }
 
+/// Empty expression inside a JSX expression container: {}
+///
+/// Stands in for the missing expression so `expression` is never None.
+#[pyclass]
+pub struct EmptyExpression {
+    #[pyo3(get)]
+    pub span: Span,
+}
+
+#[pymethods]
+impl EmptyExpression {
+    #[getter]
+    pub fn r#type(&self) -> &str {
+        "EmptyExpression"
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("EmptyExpression(span={}..{})", self.span.start, self.span.end)
+    }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, source: &str) -> (usize, usize) {
+        let start_line = source[..self.span.start.min(source.len())].matches('\n').count() + 1;
+        let end_line = source[..self.span.end.min(source.len())].matches('\n').count() + 1;
+        (start_line, end_line)
+    }
+}
+
 /// JSX expression container: {expression}
 ///
 /// Represents a JavaScript expression embedded in JSX.
@@ -379,9 +508,13 @@ pub struct JSXExpressionContainer {
     #[pyo3(get)]
     pub span: Span,
 
-    /// The JavaScript expression
+    /// The JavaScript expression (EmptyExpression for `{}`)
     #[pyo3(get)]
     pub expression: Py<PyAny>,
+
+    /// True for an empty container `{}` (including comment-only `{/* ... */}`)
+    #[pyo3(get)]
+    pub is_empty: bool,
 }
 
 #[pymethods]
@@ -391,6 +524,11 @@ impl JSXExpressionContainer {
         "JSXExpressionContainer"
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("JSXExpressionContainer(span={})", self.span.start)
     }
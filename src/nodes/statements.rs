@@ -5,7 +5,11 @@
 //! and import/export statements.
 
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 use crate::Span;
+use crate::nodes::expressions::Identifier;
+use crate::core::{body_item, body_source, has_rest_param, iter_body, node_to_dict, param_names, signature_source, tree_repr};
+use crate::traversal::{cyclomatic_complexity, nodes_of_type, WalkIterator};
 
 // =============================================================================
 // Phase 13: Specialized Statement Node Types
@@ -55,6 +59,10 @@ pub struct FunctionDeclaration {
     /// Return type annotation
     #[pyo3(get)]
     pub return_type: Option<Py<PyAny>>,
+
+    /// TypeScript overload signatures preceding this implementation
+    #[pyo3(get)]
+    pub overloads: Vec<Py<PyAny>>,
 }
 
 #[pymethods]
@@ -76,6 +84,69 @@ impl FunctionDeclaration {
         (self.start_line, self.end_line)
     }
 
+    /// Extract the body block text, braces included ("" if bodiless).
+    pub fn body_source(&self, py: Python, source: &str) -> String {
+        body_source(py, self.body.as_ref(), source)
+    }
+
+    /// Extract the signature text: everything from the start up to the body.
+    pub fn signature_source(&self, py: Python, source: &str) -> String {
+        signature_source(py, self.span, self.body.as_ref(), source)
+    }
+
+    /// Parameter names in order; None for destructuring patterns and `...rest`.
+    pub fn param_names(&self, py: Python) -> Vec<Option<String>> {
+        param_names(py, &self.params)
+    }
+
+    /// Number of parameters, including a trailing rest parameter.
+    pub fn param_count(&self) -> usize {
+        self.params.len()
+    }
+
+    /// True if the last parameter is a rest parameter (`...args`).
+    pub fn has_rest_param(&self, py: Python) -> bool {
+        has_rest_param(py, &self.params)
+    }
+
+    /// Cyclomatic complexity: 1 plus one per branch, loop, case, catch, ternary, `&&`, and `||`.
+    ///
+    /// Nested functions and classes are not counted.
+    pub fn complexity(&self, py: Python) -> PyResult<usize> {
+        cyclomatic_complexity(py, self.body.as_ref())
+    }
+
+    fn __rich_repr__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let items = pyo3::types::PyList::empty(py);
+        items.append(("name", &self.name))?;
+        items.append(("span", self.span))?;
+        items.append(("params", self.params.len()))?;
+        items.append(("is_async", self.is_async))?;
+        items.append(("is_generator", self.is_generator))?;
+        items.append(("overloads", self.overloads.len()))?;
+        Ok(items.into_any().unbind())
+    }
+
+    fn __str__(slf: &Bound<'_, Self>) -> String {
+        tree_repr(slf.py(), slf.as_any(), 0)
+    }
+
+    /// Walk this node's subtree; same as `walk(node, max_depth)`.
+    #[pyo3(signature = (max_depth=None))]
+    pub fn walk(slf: &Bound<'_, Self>, max_depth: Option<usize>) -> WalkIterator {
+        WalkIterator::new_with_limit(slf.clone().into_any().unbind(), max_depth)
+    }
+
+    /// All nodes of `type_name` in this node's subtree (itself included), in walk order.
+    pub fn find_type(slf: &Bound<'_, Self>, type_name: &str) -> Vec<Py<PyAny>> {
+        nodes_of_type(slf.py(), slf.clone().into_any().unbind(), type_name)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         let body_info = if self.body.is_some() { "with body" } else { "no body" };
         format!(
@@ -100,6 +171,9 @@ pub struct MethodDefinition {
     pub end_line: usize,
     #[pyo3(get)]
     pub name: Option<String>,
+    /// Method kind: "constructor", "method", "get", or "set"
+    #[pyo3(get)]
+    pub kind: String,
     #[pyo3(get)]
     pub is_async: bool,
     #[pyo3(get)]
@@ -109,6 +183,18 @@ pub struct MethodDefinition {
     pub function_body: Option<Py<PyAny>>,
     #[pyo3(get)]
     pub params: Vec<Py<PyAny>>,
+    /// Decorators applied to the method, in source order
+    #[pyo3(get)]
+    pub decorators: Vec<Py<PyAny>>,
+    /// Generic type parameters (TSTypeParameterDeclaration for `m<T>()`)
+    #[pyo3(get)]
+    pub type_parameters: Option<Py<PyAny>>,
+    /// Return type annotation (TSTypeAnnotation for `m(): string`)
+    #[pyo3(get)]
+    pub return_type: Option<Py<PyAny>>,
+    /// TypeScript accessibility modifier: "public", "private", or "protected"
+    #[pyo3(get)]
+    pub accessibility: Option<String>,
 }
 
 #[pymethods]
@@ -125,6 +211,31 @@ impl MethodDefinition {
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
         (self.start_line, self.end_line)
     }
+    /// Extract the body block text, braces included ("" if bodiless).
+    pub fn body_source(&self, py: Python, source: &str) -> String {
+        body_source(py, self.function_body.as_ref(), source)
+    }
+    /// Extract the signature text: everything from the start up to the body.
+    pub fn signature_source(&self, py: Python, source: &str) -> String {
+        signature_source(py, self.span, self.function_body.as_ref(), source)
+    }
+    /// Parameter names in order; None for destructuring patterns and `...rest`.
+    pub fn param_names(&self, py: Python) -> Vec<Option<String>> {
+        param_names(py, &self.params)
+    }
+    /// Number of parameters, including a trailing rest parameter.
+    pub fn param_count(&self) -> usize {
+        self.params.len()
+    }
+    /// True if the last parameter is a rest parameter (`...args`).
+    pub fn has_rest_param(&self, py: Python) -> bool {
+        has_rest_param(py, &self.params)
+    }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "MethodDefinition(name={:?}, is_async={}, span={}..{})",
@@ -136,7 +247,7 @@ impl MethodDefinition {
 /// ClassBody node containing class methods and properties.
 ///
 /// Represents the body of a class, which contains methods, properties,
-/// and other class elements. The methods field allows walk() to traverse
+/// and other class elements. The body field allows walk() to traverse
 /// into the class body and find nested JSX, functions, etc.
 #[pyclass]
 pub struct ClassBody {
@@ -146,9 +257,9 @@ pub struct ClassBody {
     pub start_line: usize,
     #[pyo3(get)]
     pub end_line: usize,
-    /// List of methods in this class body
+    /// All converted class elements (methods, properties, abstract members)
     #[pyo3(get)]
-    pub methods: Vec<Py<PyAny>>,
+    pub body: Vec<Py<PyAny>>,
 }
 
 #[pymethods]
@@ -157,6 +268,242 @@ impl ClassBody {
     fn r#type(&self) -> &'static str {
         "ClassBody"
     }
+
+    /// MethodDefinition elements of `body`, in source order.
+    ///
+    /// Deprecated: emits DeprecationWarning; use `body`, which also holds
+    /// properties and abstract members.
+    #[getter]
+    fn methods(&self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
+        PyErr::warn(
+            py,
+            &py.get_type::<pyo3::exceptions::PyDeprecationWarning>(),
+            c"ClassBody.methods is deprecated; use ClassBody.body",
+            1,
+        )?;
+        Ok(self.body
+            .iter()
+            .filter(|element| element.bind(py).is_instance_of::<MethodDefinition>())
+            .map(|element| element.clone_ref(py))
+            .collect())
+    }
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+    fn __iter__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        iter_body(py, &self.body)
+    }
+    fn __len__(&self) -> usize {
+        self.body.len()
+    }
+    fn __getitem__(&self, py: Python, idx: isize) -> PyResult<Py<PyAny>> {
+        body_item(py, &self.body, idx)
+    }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ClassBody(body={}, span={}..{})",
+            self.body.len(), self.span.start, self.span.end
+        )
+    }
+}
+
+/// ClassProperty node for class field definitions.
+///
+/// Represents: `count = 0;` or `static readonly name: string;` in a class body.
+#[pyclass]
+pub struct ClassProperty {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    /// Property name (None for computed keys)
+    #[pyo3(get)]
+    pub name: Option<String>,
+    /// Initializer expression
+    #[pyo3(get)]
+    pub value: Option<Py<PyAny>>,
+    /// TypeScript type annotation
+    #[pyo3(get)]
+    pub type_annotation: Option<Py<PyAny>>,
+    #[pyo3(get)]
+    pub is_static: bool,
+    #[pyo3(get)]
+    pub readonly: bool,
+    /// TypeScript accessibility modifier: "public", "private", or "protected"
+    #[pyo3(get)]
+    pub accessibility: Option<String>,
+}
+
+#[pymethods]
+impl ClassProperty {
+    #[getter]
+    fn r#type(&self) -> &'static str {
+        "ClassProperty"
+    }
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ClassProperty(name={:?}, is_static={}, span={}..{})",
+            self.name, self.is_static, self.span.start, self.span.end
+        )
+    }
+}
+
+/// AccessorProperty node for auto-accessor class fields.
+///
+/// Represents: `accessor count = 0;` or `static accessor name: string;`
+#[pyclass]
+pub struct AccessorProperty {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    /// Property name (None for computed keys)
+    #[pyo3(get)]
+    pub name: Option<String>,
+    /// Initializer expression
+    #[pyo3(get)]
+    pub value: Option<Py<PyAny>>,
+    /// TypeScript type annotation
+    #[pyo3(get)]
+    pub type_annotation: Option<Py<PyAny>>,
+    #[pyo3(get)]
+    pub is_static: bool,
+    #[pyo3(get)]
+    pub computed: bool,
+}
+
+#[pymethods]
+impl AccessorProperty {
+    #[getter]
+    fn r#type(&self) -> &'static str {
+        "AccessorProperty"
+    }
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AccessorProperty(name={:?}, is_static={}, span={}..{})",
+            self.name, self.is_static, self.span.start, self.span.end
+        )
+    }
+}
+
+/// StaticBlock node for class static initialization blocks.
+///
+/// Represents: `static { init(); }` in a class body.
+#[pyclass]
+pub struct StaticBlock {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    /// Statements in the block
+    #[pyo3(get)]
+    pub body: Vec<Py<PyAny>>,
+}
+
+#[pymethods]
+impl StaticBlock {
+    #[getter]
+    fn r#type(&self) -> &'static str {
+        "StaticBlock"
+    }
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "StaticBlock(body={}, span={}..{})",
+            self.body.len(), self.span.start, self.span.end
+        )
+    }
+}
+
+/// TSAbstractMethodDefinition node for abstract class methods.
+///
+/// Mirrors MethodDefinition; abstract methods never have a function body.
+/// Represents: `abstract greet(): void;`
+#[pyclass]
+pub struct TSAbstractMethodDefinition {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub name: Option<String>,
+    #[pyo3(get)]
+    pub is_async: bool,
+    #[pyo3(get)]
+    pub is_generator: bool,
+    #[pyo3(get)]
+    pub function_body: Option<Py<PyAny>>,
+    #[pyo3(get)]
+    pub params: Vec<Py<PyAny>>,
+    #[pyo3(get)]
+    pub decorators: Vec<Py<PyAny>>,
+    /// Always true
+    #[pyo3(get)]
+    pub r#abstract: bool,
+}
+
+#[pymethods]
+impl TSAbstractMethodDefinition {
+    #[getter]
+    fn r#type(&self) -> &'static str {
+        "TSAbstractMethodDefinition"
+    }
     pub fn get_text(&self, source: &str) -> String {
         let start = self.span.start.min(source.len());
         let end = self.span.end.min(source.len());
@@ -165,10 +512,68 @@ impl ClassBody {
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
         (self.start_line, self.end_line)
     }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!(
-            "ClassBody(methods={}, span={}..{})",
-            self.methods.len(), self.span.start, self.span.end
+            "TSAbstractMethodDefinition(name={:?}, span={}..{})",
+            self.name, self.span.start, self.span.end
+        )
+    }
+}
+
+/// TSAbstractPropertyDefinition node for abstract class fields.
+///
+/// Mirrors ClassProperty. Represents: `abstract name: string;`
+#[pyclass]
+pub struct TSAbstractPropertyDefinition {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub name: Option<String>,
+    #[pyo3(get)]
+    pub value: Option<Py<PyAny>>,
+    #[pyo3(get)]
+    pub type_annotation: Option<Py<PyAny>>,
+    #[pyo3(get)]
+    pub is_static: bool,
+    #[pyo3(get)]
+    pub readonly: bool,
+    /// Always true
+    #[pyo3(get)]
+    pub r#abstract: bool,
+}
+
+#[pymethods]
+impl TSAbstractPropertyDefinition {
+    #[getter]
+    fn r#type(&self) -> &'static str {
+        "TSAbstractPropertyDefinition"
+    }
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TSAbstractPropertyDefinition(name={:?}, span={}..{})",
+            self.name, self.span.start, self.span.end
         )
     }
 }
@@ -198,6 +603,10 @@ pub struct ClassDeclaration {
     #[pyo3(get)]
     pub superclass: Option<String>,
 
+    /// Interface names from a TypeScript `implements` clause
+    #[pyo3(get)]
+    pub implements: Vec<String>,
+
     /// Type parameters for generics
     #[pyo3(get)]
     pub type_parameters: Option<Py<PyAny>>,
@@ -205,6 +614,10 @@ pub struct ClassDeclaration {
     /// Class body (list of methods, properties, etc.)
     #[pyo3(get)]
     pub body: Option<Py<PyAny>>,
+
+    /// Decorators applied to the class, in source order
+    #[pyo3(get)]
+    pub decorators: Vec<Py<PyAny>>,
 }
 
 #[pymethods]
@@ -226,6 +639,55 @@ impl ClassDeclaration {
         (self.start_line, self.end_line)
     }
 
+    /// Number of methods in the class body, including the constructor and abstract methods.
+    pub fn method_count(&self, py: Python) -> PyResult<usize> {
+        Ok(self.members_of_type(py, &["MethodDefinition", "TSAbstractMethodDefinition"])?.len())
+    }
+
+    /// Number of field definitions in the class body, including abstract properties.
+    pub fn property_count(&self, py: Python) -> PyResult<usize> {
+        Ok(self.members_of_type(py, &["ClassProperty", "AccessorProperty", "TSAbstractPropertyDefinition"])?.len())
+    }
+
+    /// The `constructor` MethodDefinition, or None if the class does not declare one.
+    pub fn constructor(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        for method in self.members_of_type(py, &["MethodDefinition"])? {
+            if method.bind(py).getattr("kind")?.extract::<String>()? == "constructor" {
+                return Ok(Some(method));
+            }
+        }
+        Ok(None)
+    }
+
+    fn __rich_repr__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let items = pyo3::types::PyList::empty(py);
+        items.append(("name", &self.name))?;
+        items.append(("span", self.span))?;
+        items.append(("superclass", &self.superclass))?;
+        items.append(("decorators", self.decorators.len()))?;
+        Ok(items.into_any().unbind())
+    }
+
+    fn __str__(slf: &Bound<'_, Self>) -> String {
+        tree_repr(slf.py(), slf.as_any(), 0)
+    }
+
+    /// Walk this node's subtree; same as `walk(node, max_depth)`.
+    #[pyo3(signature = (max_depth=None))]
+    pub fn walk(slf: &Bound<'_, Self>, max_depth: Option<usize>) -> WalkIterator {
+        WalkIterator::new_with_limit(slf.clone().into_any().unbind(), max_depth)
+    }
+
+    /// All nodes of `type_name` in this node's subtree (itself included), in walk order.
+    pub fn find_type(slf: &Bound<'_, Self>, type_name: &str) -> Vec<Py<PyAny>> {
+        nodes_of_type(slf.py(), slf.clone().into_any().unbind(), type_name)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ClassDeclaration(name={:?}, superclass={:?}, span={}..{})",
@@ -234,6 +696,23 @@ impl ClassDeclaration {
     }
 }
 
+impl ClassDeclaration {
+    /// Class body elements whose `type` is one of `types`, in source order.
+    fn members_of_type(&self, py: Python, types: &[&str]) -> PyResult<Vec<Py<PyAny>>> {
+        let Some(body) = &self.body else {
+            return Ok(Vec::new());
+        };
+        let mut members = Vec::new();
+        for member in body.bind(py).getattr("body")?.extract::<Vec<Py<PyAny>>>()? {
+            let member_type = member.bind(py).getattr("type")?.extract::<String>()?;
+            if types.contains(&member_type.as_str()) {
+                members.push(member);
+            }
+        }
+        Ok(members)
+    }
+}
+
 /// VariableDeclaration node with specialized fields.
 ///
 /// Contains the kind of declaration (const/let/var).
@@ -275,6 +754,8 @@ pub struct VariableDeclarator {
     pub init: Option<Py<PyAny>>,
     #[pyo3(get)]
     pub type_annotation: Option<Py<PyAny>>,
+    /// Names bound by `id`, flattened from any destructuring pattern
+    pub bound_names: Vec<String>,
 }
 
 #[pymethods]
@@ -285,6 +766,20 @@ impl VariableDeclarator {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Name of a simple identifier binding; None when `id` is a destructuring pattern.
+    pub fn id_name(&self, py: Python) -> PyResult<Option<String>> {
+        match &self.id {
+            Some(id) => id.bind(py).getattr("name")?.extract(),
+            None => Ok(None),
+        }
+    }
+    /// All names bound by this declarator, including those inside destructuring patterns.
+    pub fn id_names(&self) -> Vec<String> { self.bound_names.clone() }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("VariableDeclarator(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -301,6 +796,8 @@ pub struct FormalParameter {
     pub name: Option<String>,
     #[pyo3(get)]
     pub type_annotation: Option<Py<PyAny>>,
+    /// Names bound by the parameter, flattened from any destructuring pattern
+    pub bound_names: Vec<String>,
 }
 
 #[pymethods]
@@ -311,6 +808,13 @@ impl FormalParameter {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// All names bound by this parameter, including those inside destructuring patterns.
+    pub fn id_names(&self) -> Vec<String> { self.bound_names.clone() }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("FormalParameter(name={:?}, span={}..{})", self.name, self.span.start, self.span.end) }
 }
 
@@ -333,6 +837,52 @@ impl VariableDeclaration {
         (self.start_line, self.end_line)
     }
 
+    /// True for `const` declarations
+    pub fn is_const(&self) -> bool {
+        self.kind == "const"
+    }
+
+    /// True for `let` declarations
+    pub fn is_let(&self) -> bool {
+        self.kind == "let"
+    }
+
+    /// True for `var` declarations
+    pub fn is_var(&self) -> bool {
+        self.kind == "var"
+    }
+
+    /// True for `using` declarations (explicit resource management)
+    pub fn is_using(&self) -> bool {
+        self.kind == "using"
+    }
+
+    /// True for `await using` declarations (explicit resource management)
+    pub fn is_await_using(&self) -> bool {
+        self.kind == "await using"
+    }
+
+    /// Identifier name of the first declarator; None if there is none or it destructures
+    pub fn first_declarator_name(&self, py: Python) -> PyResult<Option<String>> {
+        match self.declarations.first() {
+            Some(declarator) => declarator.bind(py).extract::<PyRef<VariableDeclarator>>()?.id_name(py),
+            None => Ok(None),
+        }
+    }
+
+    /// Identifier name when this declares exactly one simple variable (`const x = ...`)
+    pub fn single_name(&self, py: Python) -> PyResult<Option<String>> {
+        if self.declarations.len() != 1 {
+            return Ok(None);
+        }
+        self.first_declarator_name(py)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "VariableDeclaration(kind={:?}, span={}..{})",
@@ -341,6 +891,46 @@ impl VariableDeclaration {
     }
 }
 
+/// Decorator node for class and method decorators.
+///
+/// Represents: `@Injectable()` or `@readonly` before a class or class member.
+#[pyclass]
+pub struct Decorator {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    /// Decorator expression (Identifier, CallExpression, MemberExpression, ...)
+    #[pyo3(get)]
+    pub expression: Py<PyAny>,
+}
+
+#[pymethods]
+impl Decorator {
+    #[getter]
+    fn r#type(&self) -> &'static str {
+        "Decorator"
+    }
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Decorator(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
 /// BlockStatement node (function body, if body, etc.)
 ///
 /// Contains a list of statements that form the block.
@@ -382,6 +972,37 @@ impl BlockStatement {
         (self.start_line, self.end_line)
     }
 
+    /// Iterate over the block's statements.
+    fn __iter__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        iter_body(py, &self.body)
+    }
+
+    /// Number of statements in the block.
+    fn __len__(&self) -> usize {
+        self.body.len()
+    }
+
+    /// Get a statement by index (negative indices count from the end).
+    fn __getitem__(&self, py: Python, idx: isize) -> PyResult<Py<PyAny>> {
+        body_item(py, &self.body, idx)
+    }
+
+    /// Walk this node's subtree; same as `walk(node, max_depth)`.
+    #[pyo3(signature = (max_depth=None))]
+    pub fn walk(slf: &Bound<'_, Self>, max_depth: Option<usize>) -> WalkIterator {
+        WalkIterator::new_with_limit(slf.clone().into_any().unbind(), max_depth)
+    }
+
+    /// All nodes of `type_name` in this node's subtree (itself included), in walk order.
+    pub fn find_type(slf: &Bound<'_, Self>, type_name: &str) -> Vec<Py<PyAny>> {
+        nodes_of_type(slf.py(), slf.clone().into_any().unbind(), type_name)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "BlockStatement(statements={}, span={}..{})",
@@ -415,6 +1036,18 @@ impl BreakStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Name of the target label (None for a bare `break`)
+    pub fn label_name(&self, py: Python) -> PyResult<Option<String>> {
+        match &self.label {
+            Some(label) => label.bind(py).getattr("name")?.extract(),
+            None => Ok(None),
+        }
+    }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("BreakStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -439,6 +1072,18 @@ impl ContinueStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Name of the target label (None for a bare `continue`)
+    pub fn label_name(&self, py: Python) -> PyResult<Option<String>> {
+        match &self.label {
+            Some(label) => label.bind(py).getattr("name")?.extract(),
+            None => Ok(None),
+        }
+    }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("ContinueStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -465,6 +1110,18 @@ impl LabeledStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Name of the label
+    pub fn label_name(&self, py: Python) -> PyResult<Option<String>> {
+        match &self.label {
+            Some(label) => label.bind(py).getattr("name")?.extract(),
+            None => Ok(None),
+        }
+    }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("LabeledStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -487,6 +1144,11 @@ impl EmptyStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("EmptyStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -513,6 +1175,11 @@ impl WithStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("WithStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -543,6 +1210,11 @@ impl ForStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("ForStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -571,6 +1243,11 @@ impl IfStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("IfStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -595,6 +1272,11 @@ impl ExpressionStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("ExpressionStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -621,6 +1303,11 @@ impl WhileStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("WhileStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -647,6 +1334,11 @@ impl DoWhileStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("DoWhileStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -675,6 +1367,11 @@ impl ForInStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("ForInStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -705,6 +1402,11 @@ impl ForOfStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("ForOfStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -731,7 +1433,22 @@ impl SwitchStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("SwitchStatement(span={}..{})", self.span.start, self.span.end) }
+    /// Test expression of each case in order, None for `default`
+    pub fn case_values(&self, py: Python) -> PyResult<Vec<Option<Py<PyAny>>>> {
+        self.cases.iter()
+            .map(|case| Ok(case.bind(py).extract::<PyRef<'_, SwitchCase>>()?.test.as_ref().map(|t| t.clone_ref(py))))
+            .collect()
+    }
+    /// True if any case is a `default:` clause
+    pub fn has_default(&self, py: Python) -> bool {
+        self.cases.iter().any(|case| case.bind(py).extract::<PyRef<'_, SwitchCase>>().is_ok_and(|case| case.test.is_none()))
+    }
 }
 
 /// SwitchCase node for switch case clauses.
@@ -757,7 +1474,15 @@ impl SwitchCase {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("SwitchCase(span={}..{})", self.span.start, self.span.end) }
+    /// True for the `default:` clause (no test expression)
+    #[getter]
+    pub fn is_default(&self) -> bool { self.test.is_none() }
 }
 
 /// TryStatement node for try-catch-finally statements.
@@ -785,7 +1510,19 @@ impl TryStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("TryStatement(span={}..{})", self.span.start, self.span.end) }
+    /// Name of the catch clause variable (None without a handler, for `catch {}`, or for destructured bindings)
+    pub fn exception_name(&self, py: Python) -> PyResult<Option<String>> {
+        match &self.handler {
+            Some(handler) => handler.bind(py).getattr("param_name")?.extract(),
+            None => Ok(None),
+        }
+    }
 }
 
 /// CatchClause node for catch clauses.
@@ -811,7 +1548,22 @@ impl CatchClause {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("CatchClause(span={}..{})", self.span.start, self.span.end) }
+    /// Name of the caught exception variable (None for `catch {}` and destructured bindings)
+    #[getter]
+    pub fn param_name(&self, py: Python) -> PyResult<Option<String>> {
+        match &self.param {
+            Some(param) if param.bind(py).is_instance_of::<Identifier>() => {
+                param.bind(py).getattr("name")?.extract()
+            }
+            _ => Ok(None),
+        }
+    }
 }
 
 /// ThrowStatement node for throw statements.
@@ -835,6 +1587,11 @@ impl ThrowStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("ThrowStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -859,6 +1616,11 @@ impl ReturnStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("ReturnStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -881,6 +1643,11 @@ impl DebuggerStatement {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
     pub fn get_line_range(&self, _source: &str) -> (usize, usize) { (self.start_line, self.end_line) }
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String { format!("DebuggerStatement(span={}..{})", self.span.start, self.span.end) }
 }
 
@@ -919,6 +1686,14 @@ pub struct ImportDeclaration {
     /// Import specifiers (list of ImportSpecifier, ImportDefaultSpecifier, ImportNamespaceSpecifier)
     #[pyo3(get)]
     pub specifiers: Vec<Py<PyAny>>,
+
+    /// Import attributes from a `with { ... }` clause (None when there is no clause)
+    #[pyo3(get)]
+    pub with_clause: Option<Vec<Py<ImportAttribute>>>,
+
+    /// "type" for `import type ...`, otherwise "value"
+    #[pyo3(get)]
+    pub import_kind: String,
 }
 
 #[pymethods]
@@ -940,12 +1715,101 @@ impl ImportDeclaration {
         (self.start_line, self.end_line)
     }
 
+    fn __rich_repr__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let items = pyo3::types::PyList::empty(py);
+        items.append(("source", &self.source))?;
+        items.append(("span", self.span))?;
+        items.append(("specifiers", self.specifiers.len()))?;
+        items.append(("import_kind", &self.import_kind))?;
+        Ok(items.into_any().unbind())
+    }
+
+    fn __str__(slf: &Bound<'_, Self>) -> String {
+        tree_repr(slf.py(), slf.as_any(), 0)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ImportDeclaration(specifiers={}, span={}..{})",
             self.specifiers.len(), self.span.start, self.span.end
         )
     }
+
+    /// Local binding names of all specifiers, in source order.
+    ///
+    /// Example:
+    ///     import React, { useState as useS } from "react";  // ["React", "useS"]
+    pub fn specifier_names(&self, py: Python) -> PyResult<Vec<String>> {
+        let mut names = Vec::new();
+        for spec in &self.specifiers {
+            let local = spec.bind(py).getattr("local")?;
+            names.push(local.getattr("name")?.extract()?);
+        }
+        Ok(names)
+    }
+}
+
+/// ImportAttribute node for import attributes.
+///
+/// Represents: type: "json" in import data from "./data.json" with { type: "json" }
+#[pyclass]
+pub struct ImportAttribute {
+    /// Source location
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+
+    /// Attribute name (identifier or string key)
+    #[pyo3(get)]
+    pub key: String,
+
+    /// Attribute value (string literal contents)
+    #[pyo3(get)]
+    pub value: String,
+}
+
+#[pymethods]
+impl ImportAttribute {
+    #[getter]
+    fn r#type(&self) -> &'static str {
+        "ImportAttribute"
+    }
+
+    /// Extract source text for this node.
+    pub fn get_text(&self, source: &str) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.min(source.len());
+        source.get(start..end).unwrap_or("").to_string()
+    }
+
+    /// Get line range for this node.
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ImportAttribute(key={:?}, value={:?}, span={}..{})",
+            self.key, self.value, self.span.start, self.span.end
+        )
+    }
 }
 
 /// ImportSpecifier node for named imports.
@@ -975,6 +1839,10 @@ pub struct ImportSpecifier {
     /// Local binding name (Identifier)
     #[pyo3(get)]
     pub local: Py<PyAny>,
+
+    /// "type" for `import { type Foo }`, otherwise "value"
+    #[pyo3(get)]
+    pub import_kind: String,
 }
 
 #[pymethods]
@@ -996,12 +1864,27 @@ impl ImportSpecifier {
         (self.start_line, self.end_line)
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ImportSpecifier(span={}..{})",
             self.span.start, self.span.end
         )
     }
+
+    /// Module-side name being imported ("foo" in `{ foo as bar }`)
+    pub fn imported_name(&self, py: Python) -> Option<String> {
+        self.imported.bind(py).getattr("name").ok()?.extract().ok()
+    }
+
+    /// True for `import { type Foo }` specifiers
+    pub fn is_type_only(&self) -> bool {
+        self.import_kind == "type"
+    }
 }
 
 /// ImportDefaultSpecifier node for default imports.
@@ -1047,6 +1930,11 @@ impl ImportDefaultSpecifier {
         (self.start_line, self.end_line)
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ImportDefaultSpecifier(span={}..{})",
@@ -1098,6 +1986,11 @@ impl ImportNamespaceSpecifier {
         (self.start_line, self.end_line)
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ImportNamespaceSpecifier(span={}..{})",
@@ -1162,6 +2055,33 @@ impl ExportNamedDeclaration {
         (self.start_line, self.end_line)
     }
 
+    /// Names this export introduces, in source order.
+    ///
+    /// Covers the declaration's name (every bound name for variable
+    /// declarations) and the exported name of each specifier.
+    pub fn exported_names(&self, py: Python) -> PyResult<Vec<String>> {
+        let mut names = Vec::new();
+        if let Some(declaration) = &self.declaration {
+            let declaration = declaration.bind(py);
+            if let Ok(declarations) = declaration.getattr("declarations") {
+                for declarator in declarations.extract::<Vec<PyRef<VariableDeclarator>>>()? {
+                    names.extend(declarator.id_names());
+                }
+            } else if let Ok(Some(name)) = declaration.getattr("name").and_then(|n| n.extract::<Option<String>>()) {
+                names.push(name);
+            }
+        }
+        for specifier in &self.specifiers {
+            names.push(specifier.bind(py).getattr("exported")?.getattr("name")?.extract()?);
+        }
+        Ok(names)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ExportNamedDeclaration(specifiers={}, span={}..{})",
@@ -1218,6 +2138,11 @@ impl ExportDefaultDeclaration {
         (self.start_line, self.end_line)
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ExportDefaultDeclaration(span={}..{})",
@@ -1277,6 +2202,11 @@ impl ExportAllDeclaration {
         (self.start_line, self.end_line)
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ExportAllDeclaration(span={}..{})",
@@ -1333,6 +2263,11 @@ impl ExportSpecifier {
         (self.start_line, self.end_line)
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ExportSpecifier(span={}..{})",
@@ -6,7 +6,10 @@
 //! TSInterfaceDeclaration -> ChunkType.INTERFACE
 
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 use crate::Span;
+use crate::core::{body_item, iter_body, node_to_dict, slice_source, tree_repr};
+use crate::traversal::node_span;
 
 // =============================================================================
 // TypeScript Declaration Nodes
@@ -44,9 +47,46 @@ impl TSTypeAliasDeclaration {
         (self.start_line, self.end_line)
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("TSTypeAliasDeclaration(name={:?}, span={}..{})", self.name, self.span.start, self.span.end)
     }
+
+    /// Source text of the aliased type, e.g. "string | number" for `type Id = string | number;`.
+    ///
+    /// Falls back to the text after `type Name<T> =` when the type was not converted.
+    pub fn resolved_type_str(&self, py: Python, source: &str) -> String {
+        if let Some(span) = self.type_annotation.as_ref().and_then(|t| node_span(t.bind(py))) {
+            return slice_source(source, span.start, span.end);
+        }
+        let start = self.type_parameters.as_ref()
+            .and_then(|tp| node_span(tp.bind(py)))
+            .map_or(self.span.start, |tp_span| tp_span.end);
+        let declaration = slice_source(source, start, self.span.end);
+
+        // The first `=` outside the type parameter list (whose defaults may contain `=`)
+        let mut depth = 0usize;
+        let mut prev = ' ';
+        let mut eq = None;
+        for (i, c) in declaration.char_indices() {
+            match c {
+                '<' => depth += 1,
+                '>' if prev != '=' => depth = depth.saturating_sub(1),
+                '=' if depth == 0 => {
+                    eq = Some(i);
+                    break;
+                }
+                _ => {}
+            }
+            prev = c;
+        }
+        eq.map(|i| declaration[i + 1..].trim().trim_end_matches(';').trim_end().to_string())
+            .unwrap_or_default()
+    }
 }
 
 /// TSInterfaceDeclaration node for TypeScript interfaces.
@@ -83,11 +123,81 @@ impl TSInterfaceDeclaration {
         (self.start_line, self.end_line)
     }
 
+    /// Key names of the interface's property signatures, in declaration order (computed keys are skipped)
+    pub fn property_names(&self, py: Python) -> PyResult<Vec<String>> {
+        interface_member_names(py, self.body.as_ref(), "TSPropertySignature")
+    }
+
+    /// Key names of the interface's method signatures, in declaration order (computed keys are skipped)
+    pub fn method_names(&self, py: Python) -> PyResult<Vec<String>> {
+        interface_member_names(py, self.body.as_ref(), "TSMethodSignature")
+    }
+
+    /// Number of signatures in the interface body
+    pub fn signature_count(&self, py: Python) -> PyResult<usize> {
+        Ok(self.signatures(py)?.len())
+    }
+
+    /// Iterate over the body's signatures (`for sig in iface:`)
+    fn __iter__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        iter_body(py, &self.signatures(py)?)
+    }
+
+    fn __len__(&self, py: Python) -> PyResult<usize> {
+        self.signature_count(py)
+    }
+
+    fn __rich_repr__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let items = pyo3::types::PyList::empty(py);
+        items.append(("name", &self.name))?;
+        items.append(("span", self.span))?;
+        items.append(("extends", self.extends.as_ref().map_or(0, Vec::len)))?;
+        Ok(items.into_any().unbind())
+    }
+
+    fn __str__(slf: &Bound<'_, Self>) -> String {
+        tree_repr(slf.py(), slf.as_any(), 0)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("TSInterfaceDeclaration(name={:?}, span={}..{})", self.name, self.span.start, self.span.end)
     }
 }
 
+impl TSInterfaceDeclaration {
+    fn signatures(&self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
+        match &self.body {
+            Some(body) => body.bind(py).getattr("body")?.extract(),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+/// Key names of the members of an interface body with the given node type.
+fn interface_member_names(py: Python, body: Option<&Py<PyAny>>, member_type: &str) -> PyResult<Vec<String>> {
+    let Some(body) = body else {
+        return Ok(Vec::new());
+    };
+    let mut names = Vec::new();
+    for member in body.bind(py).getattr("body")?.extract::<Vec<Bound<'_, PyAny>>>()? {
+        if member.getattr("type")?.extract::<String>()? != member_type {
+            continue;
+        }
+        let key = member.getattr("key")?.extract::<Option<Py<PyAny>>>()?;
+        let computed = member.getattr("computed")?.extract::<bool>()?;
+        // Computed keys have no static name
+        if let Some(name) = signature_key_name(py, key.as_ref(), computed)? {
+            names.push(name);
+        }
+    }
+    Ok(names)
+}
+
 /// TSEnumDeclaration node for TypeScript enums.
 /// Represents: enum Color { Red, Green, Blue }
 #[pyclass]
@@ -119,9 +229,52 @@ impl TSEnumDeclaration {
         (self.start_line, self.end_line)
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("TSEnumDeclaration(name={:?}, span={}..{})", self.name, self.span.start, self.span.end)
     }
+
+    /// Map member names to their values, following TypeScript auto-increment rules.
+    ///
+    /// Members without an initializer take the previous numeric value plus one
+    /// (starting at 0). Values that can't be determined statically (computed
+    /// initializers, or members following one) map to None. `const enum`s are
+    /// handled the same way.
+    ///
+    /// Example:
+    ///     enum E { A, B = 10, C }  // {"A": 0, "B": 10, "C": 11}
+    pub fn value_map(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let map = PyDict::new(py);
+        // Value the next uninitialized member gets; None once it's unknown
+        let mut next: Option<f64> = Some(0.0);
+        for member in &self.members {
+            let Ok(member) = member.bind(py).extract::<PyRef<'_, TSEnumMember>>() else { continue };
+            let Some(id) = &member.id else { continue };
+            let name: String = id.bind(py).getattr("name")?.extract()?;
+
+            if let Some(value) = &member.string_value {
+                map.set_item(name, value)?;
+                next = None;
+                continue;
+            }
+            let value = match (&member.initializer, member.numeric_value) {
+                (_, Some(value)) => Some(value),
+                (Some(_), None) => None,
+                (None, None) => next,
+            };
+            match value {
+                Some(value) if value.fract() == 0.0 && value.abs() < 9.0e15 => map.set_item(name, value as i64)?,
+                Some(value) => map.set_item(name, value)?,
+                None => map.set_item(name, py.None())?,
+            }
+            next = value.map(|value| value + 1.0);
+        }
+        Ok(map.unbind())
+    }
 }
 
 // =============================================================================
@@ -155,9 +308,42 @@ impl TSTypeAnnotation {
         (self.start_line, self.end_line)
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("TSTypeAnnotation(span={}..{})", self.span.start, self.span.end)
     }
+
+    /// Source text of the annotated type, without the leading `:`.
+    pub fn type_str(&self, py: Python, source: &str) -> String {
+        if let Some(span) = self.type_annotation.as_ref().and_then(|t| node_span(t.bind(py))) {
+            return slice_source(source, span.start, span.end);
+        }
+        let text = slice_source(source, self.span.start, self.span.end);
+        text.trim_start().trim_start_matches(':').trim().to_string()
+    }
+
+    /// Primitive type name ("string", "number", "boolean", "bigint", "symbol",
+    /// "null", or "undefined") when the annotation is that keyword; None otherwise.
+    pub fn primitive_kind(&self, py: Python) -> PyResult<Option<String>> {
+        let Some(inner) = &self.type_annotation else {
+            return Ok(None);
+        };
+        let kind = match inner.bind(py).getattr("type")?.extract::<String>()?.as_str() {
+            "TSStringKeyword" => "string",
+            "TSNumberKeyword" => "number",
+            "TSBooleanKeyword" => "boolean",
+            "TSBigIntKeyword" => "bigint",
+            "TSSymbolKeyword" => "symbol",
+            "TSNullKeyword" => "null",
+            "TSUndefinedKeyword" => "undefined",
+            _ => return Ok(None),
+        };
+        Ok(Some(kind.to_string()))
+    }
 }
 
 /// TSTypeReference node for TypeScript type references.
@@ -189,6 +375,11 @@ impl TSTypeReference {
         (self.start_line, self.end_line)
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("TSTypeReference(span={}..{})", self.span.start, self.span.end)
     }
@@ -210,6 +401,15 @@ pub struct TSTypeParameter {
     pub constraint: Option<Py<PyAny>>,
     #[pyo3(get)]
     pub default: Option<Py<PyAny>>,
+    /// `in` variance modifier (TS 4.7+)
+    #[pyo3(get)]
+    pub in_modifier: bool,
+    /// `out` variance modifier (TS 4.7+)
+    #[pyo3(get)]
+    pub out_modifier: bool,
+    /// `const` modifier (TS 5.0+)
+    #[pyo3(get)]
+    pub const_modifier: bool,
 }
 
 #[pymethods]
@@ -217,6 +417,32 @@ impl TSTypeParameter {
     #[getter]
     pub fn r#type(&self) -> &str { "TSTypeParameter" }
 
+    /// Declared variance: "in", "out", "in out", or "invariant" without modifiers
+    #[getter]
+    pub fn variance(&self) -> &str {
+        match (self.in_modifier, self.out_modifier) {
+            (true, true) => "in out",
+            (true, false) => "in",
+            (false, true) => "out",
+            (false, false) => "invariant",
+        }
+    }
+
+    /// True for `out T` (without `in`)
+    pub fn is_covariant(&self) -> bool {
+        self.out_modifier && !self.in_modifier
+    }
+
+    /// True for `in T` (without `out`)
+    pub fn is_contravariant(&self) -> bool {
+        self.in_modifier && !self.out_modifier
+    }
+
+    /// True for `in out T`
+    pub fn is_bivariant(&self) -> bool {
+        self.in_modifier && self.out_modifier
+    }
+
     pub fn get_text(&self, source: &str) -> String {
         source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
     }
@@ -225,6 +451,11 @@ impl TSTypeParameter {
         (self.start_line, self.end_line)
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("TSTypeParameter(name={:?}, span={}..{})", self.name, self.span.start, self.span.end)
     }
@@ -257,6 +488,23 @@ impl TSTypeParameterDeclaration {
         (self.start_line, self.end_line)
     }
 
+    fn __iter__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        iter_body(py, &self.params)
+    }
+
+    fn __len__(&self) -> usize {
+        self.params.len()
+    }
+
+    fn __getitem__(&self, py: Python, idx: isize) -> PyResult<Py<PyAny>> {
+        body_item(py, &self.params, idx)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("TSTypeParameterDeclaration(params={}, span={}..{})", self.params.len(), self.span.start, self.span.end)
     }
@@ -292,11 +540,68 @@ impl TSInterfaceBody {
         (self.start_line, self.end_line)
     }
 
+    fn __iter__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        iter_body(py, &self.body)
+    }
+
+    fn __len__(&self) -> usize {
+        self.body.len()
+    }
+
+    fn __getitem__(&self, py: Python, idx: isize) -> PyResult<Py<PyAny>> {
+        body_item(py, &self.body, idx)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("TSInterfaceBody(members={}, span={}..{})", self.body.len(), self.span.start, self.span.end)
     }
 }
 
+/// TSInterfaceHeritage node for an entry in an interface `extends` clause.
+/// Represents: Bar<string> in interface Foo extends Bar<string>
+#[pyclass]
+pub struct TSInterfaceHeritage {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    /// The extended interface (Identifier or MemberExpression)
+    #[pyo3(get)]
+    pub expression: Py<PyAny>,
+    #[pyo3(get)]
+    pub type_arguments: Option<Py<PyAny>>,
+}
+
+#[pymethods]
+impl TSInterfaceHeritage {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSInterfaceHeritage" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSInterfaceHeritage(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
 /// TSPropertySignature node for TypeScript interface properties.
 /// Represents: readonly name: string; in interface
 #[pyclass]
@@ -309,6 +614,9 @@ pub struct TSPropertySignature {
     pub end_line: usize,
     #[pyo3(get)]
     pub key: Option<Py<PyAny>>,
+    /// True for `[expr]` keys
+    #[pyo3(get)]
+    pub computed: bool,
     #[pyo3(get)]
     pub optional: bool,
     #[pyo3(get)]
@@ -330,11 +638,33 @@ impl TSPropertySignature {
         (self.start_line, self.end_line)
     }
 
+    /// Key as a string: identifier name or string literal value; None for computed keys.
+    pub fn name(&self, py: Python) -> PyResult<Option<String>> {
+        signature_key_name(py, self.key.as_ref(), self.computed)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("TSPropertySignature(span={}..{})", self.span.start, self.span.end)
     }
 }
 
+fn signature_key_name(py: Python, key: Option<&Py<PyAny>>, computed: bool) -> PyResult<Option<String>> {
+    let Some(key) = key.filter(|_| !computed) else {
+        return Ok(None);
+    };
+    let key = key.bind(py);
+    match key.getattr("type")?.extract::<String>()?.as_str() {
+        "Identifier" => key.getattr("name")?.extract(),
+        "Literal" => Ok(key.getattr("value")?.extract::<String>().ok()),
+        _ => Ok(None),
+    }
+}
+
 /// TSMethodSignature node for TypeScript interface methods.
 /// Represents: log(message: string): void; in interface
 #[pyclass]
@@ -347,10 +677,17 @@ pub struct TSMethodSignature {
     pub end_line: usize,
     #[pyo3(get)]
     pub key: Option<Py<PyAny>>,
+    /// True for `[expr]` keys
+    #[pyo3(get)]
+    pub computed: bool,
     #[pyo3(get)]
     pub params: Vec<Py<PyAny>>,
     #[pyo3(get)]
     pub return_type: Option<Py<PyAny>>,
+    #[pyo3(get)]
+    pub type_parameters: Option<Py<PyAny>>,
+    #[pyo3(get)]
+    pub optional: bool,
 }
 
 #[pymethods]
@@ -366,6 +703,16 @@ impl TSMethodSignature {
         (self.start_line, self.end_line)
     }
 
+    /// Key as a string: identifier name or string literal value; None for computed keys.
+    pub fn name(&self, py: Python) -> PyResult<Option<String>> {
+        signature_key_name(py, self.key.as_ref(), self.computed)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("TSMethodSignature(span={}..{})", self.span.start, self.span.end)
     }
@@ -388,6 +735,12 @@ pub struct TSEnumMember {
     pub id: Option<Py<PyAny>>,
     #[pyo3(get)]
     pub initializer: Option<Py<PyAny>>,
+    /// Value of a string literal initializer (`Red = "red"`)
+    #[pyo3(get)]
+    pub string_value: Option<String>,
+    /// Value of a numeric literal initializer (`A = 0`, `B = -1`)
+    #[pyo3(get)]
+    pub numeric_value: Option<f64>,
 }
 
 #[pymethods]
@@ -403,11 +756,103 @@ impl TSEnumMember {
         (self.start_line, self.end_line)
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("TSEnumMember(span={}..{})", self.span.start, self.span.end)
     }
 }
 
+// =============================================================================
+// TypeScript Namespace Nodes
+// =============================================================================
+
+/// TSModuleDeclaration node for namespaces and ambient modules.
+/// Represents: namespace Shapes { ... }, declare module "pkg" { ... }, declare global { ... }
+#[pyclass]
+pub struct TSModuleDeclaration {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    /// Namespace name, or the module specifier for `declare module "pkg"`
+    #[pyo3(get)]
+    pub name: String,
+    /// Keyword used: "namespace", "module", or "global"
+    #[pyo3(get)]
+    pub kind: String,
+    #[pyo3(get)]
+    pub declare: bool,
+    /// TSModuleBlock, a nested TSModuleDeclaration for `namespace A.B {}`, or None
+    #[pyo3(get)]
+    pub body: Option<Py<PyAny>>,
+}
+
+#[pymethods]
+impl TSModuleDeclaration {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSModuleDeclaration" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSModuleDeclaration(name={:?}, span={}..{})", self.name, self.span.start, self.span.end)
+    }
+}
+
+/// TSModuleBlock node for the statements of a namespace or module.
+/// Represents: { export const x = 1; } in namespace N { export const x = 1; }
+#[pyclass]
+pub struct TSModuleBlock {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub body: Vec<Py<PyAny>>,
+}
+
+#[pymethods]
+impl TSModuleBlock {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSModuleBlock" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSModuleBlock(statements={}, span={}..{})", self.body.len(), self.span.start, self.span.end)
+    }
+}
+
 // =============================================================================
 // TypeScript Union/Intersection Types
 // =============================================================================
@@ -439,6 +884,11 @@ impl TSUnionType {
         (self.start_line, self.end_line)
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("TSUnionType(types={}, span={}..{})", self.types.len(), self.span.start, self.span.end)
     }
@@ -471,7 +921,583 @@ impl TSIntersectionType {
         (self.start_line, self.end_line)
     }
 
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
     fn __repr__(&self) -> String {
         format!("TSIntersectionType(types={}, span={}..{})", self.types.len(), self.span.start, self.span.end)
     }
 }
+
+// =============================================================================
+// TypeScript Conditional/Import Types
+// =============================================================================
+
+/// TSConditionalType node for TypeScript conditional types.
+/// Represents: T extends string ? A : B
+#[pyclass]
+pub struct TSConditionalType {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub check_type: Py<PyAny>,
+    #[pyo3(get)]
+    pub extends_type: Py<PyAny>,
+    #[pyo3(get)]
+    pub true_type: Py<PyAny>,
+    #[pyo3(get)]
+    pub false_type: Py<PyAny>,
+}
+
+#[pymethods]
+impl TSConditionalType {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSConditionalType" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSConditionalType(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// TSInferType node for type variables introduced inside conditional types.
+/// Represents: infer U (in T extends Array<infer U> ? U : never)
+#[pyclass]
+pub struct TSInferType {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub type_parameter: Py<PyAny>,
+}
+
+#[pymethods]
+impl TSInferType {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSInferType" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSInferType(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// TSImportType node for TypeScript import types.
+/// Represents: import("./module").Type<T>
+#[pyclass]
+pub struct TSImportType {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub argument: Py<PyAny>,
+    #[pyo3(get)]
+    pub qualifier: Option<Py<PyAny>>,
+    #[pyo3(get)]
+    pub type_arguments: Option<Py<PyAny>>,
+}
+
+#[pymethods]
+impl TSImportType {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSImportType" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSImportType(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// TSLiteralType node for TypeScript literal types.
+/// Represents: "active", 42, true (in type Status = "active")
+#[pyclass]
+pub struct TSLiteralType {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub literal: Py<PyAny>,
+}
+
+#[pymethods]
+impl TSLiteralType {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSLiteralType" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSLiteralType(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+// =============================================================================
+// TypeScript Expression Nodes
+// =============================================================================
+
+/// TSAsExpression node for `as` type assertions.
+/// Represents: value as string
+#[pyclass]
+pub struct TSAsExpression {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub expression: Py<PyAny>,
+    #[pyo3(get)]
+    pub type_annotation: Py<PyAny>,
+}
+
+#[pymethods]
+impl TSAsExpression {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSAsExpression" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSAsExpression(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// TSSatisfiesExpression node for `satisfies` checks.
+/// Represents: config satisfies Config
+#[pyclass]
+pub struct TSSatisfiesExpression {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub expression: Py<PyAny>,
+    #[pyo3(get)]
+    pub type_annotation: Py<PyAny>,
+}
+
+#[pymethods]
+impl TSSatisfiesExpression {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSSatisfiesExpression" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSSatisfiesExpression(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// TSTypeAssertion node for angle-bracket type assertions.
+/// Represents: <string>value
+#[pyclass]
+pub struct TSTypeAssertion {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub expression: Py<PyAny>,
+    #[pyo3(get)]
+    pub type_annotation: Py<PyAny>,
+}
+
+#[pymethods]
+impl TSTypeAssertion {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSTypeAssertion" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSTypeAssertion(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// TSNonNullExpression node for non-null assertions.
+/// Represents: value!
+#[pyclass]
+pub struct TSNonNullExpression {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub expression: Py<PyAny>,
+}
+
+#[pymethods]
+impl TSNonNullExpression {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSNonNullExpression" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSNonNullExpression(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// TSInstantiationExpression node for generic instantiation without a call.
+/// Represents: makeBox<string>
+#[pyclass]
+pub struct TSInstantiationExpression {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub expression: Py<PyAny>,
+    #[pyo3(get)]
+    pub type_arguments: Py<PyAny>,
+}
+
+#[pymethods]
+impl TSInstantiationExpression {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSInstantiationExpression" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSInstantiationExpression(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+// =============================================================================
+// TypeScript Array/Tuple Types
+// =============================================================================
+
+/// TSArrayType node for TypeScript array types.
+/// Represents: string[]
+#[pyclass]
+pub struct TSArrayType {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub element_type: Py<PyAny>,
+}
+
+#[pymethods]
+impl TSArrayType {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSArrayType" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSArrayType(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// TSTupleType node for TypeScript tuple types.
+/// Represents: [string, number?, ...boolean[]]
+#[pyclass]
+pub struct TSTupleType {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub element_types: Vec<Py<PyAny>>,
+}
+
+#[pymethods]
+impl TSTupleType {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSTupleType" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSTupleType(elements={}, span={}..{})", self.element_types.len(), self.span.start, self.span.end)
+    }
+}
+
+/// TSOptionalType node for optional tuple elements.
+/// Represents: number? (in [string, number?])
+#[pyclass]
+pub struct TSOptionalType {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub type_annotation: Py<PyAny>,
+}
+
+#[pymethods]
+impl TSOptionalType {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSOptionalType" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSOptionalType(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+/// TSRestType node for rest tuple elements.
+/// Represents: ...boolean[] (in [string, ...boolean[]])
+#[pyclass]
+pub struct TSRestType {
+    #[pyo3(get)]
+    pub span: Span,
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get)]
+    pub end_line: usize,
+    #[pyo3(get)]
+    pub type_annotation: Py<PyAny>,
+}
+
+#[pymethods]
+impl TSRestType {
+    #[getter]
+    pub fn r#type(&self) -> &str { "TSRestType" }
+
+    pub fn get_text(&self, source: &str) -> String {
+        source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+    }
+
+    pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+        (self.start_line, self.end_line)
+    }
+
+    /// Plain dict of this node's fields.
+    pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+        node_to_dict(slf.as_any())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TSRestType(span={}..{})", self.span.start, self.span.end)
+    }
+}
+
+// =============================================================================
+// TypeScript Keyword Types
+// =============================================================================
+
+/// Generates a span-only pyclass for a TypeScript keyword type.
+/// Keyword types carry no fields beyond their location, but distinct classes
+/// allow isinstance() checks from Python.
+macro_rules! ts_keyword_type {
+    ($name:ident, $doc:literal) => {
+        #[doc = $doc]
+        #[pyclass]
+        pub struct $name {
+            #[pyo3(get)]
+            pub span: Span,
+            #[pyo3(get)]
+            pub start_line: usize,
+            #[pyo3(get)]
+            pub end_line: usize,
+        }
+
+        #[pymethods]
+        impl $name {
+            #[getter]
+            pub fn r#type(&self) -> &str { stringify!($name) }
+
+            pub fn get_text(&self, source: &str) -> String {
+                source[self.span.start.min(source.len())..self.span.end.min(source.len())].to_string()
+            }
+
+            pub fn get_line_range(&self, _source: &str) -> (usize, usize) {
+                (self.start_line, self.end_line)
+            }
+
+            /// Plain dict of this node's fields.
+            pub fn to_dict<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyDict>> {
+                node_to_dict(slf.as_any())
+            }
+
+            fn __repr__(&self) -> String {
+                format!("{}(span={}..{})", stringify!($name), self.span.start, self.span.end)
+            }
+        }
+    };
+}
+
+ts_keyword_type!(TSAnyKeyword, "Represents: any");
+ts_keyword_type!(TSBigIntKeyword, "Represents: bigint");
+ts_keyword_type!(TSBooleanKeyword, "Represents: boolean");
+ts_keyword_type!(TSIntrinsicKeyword, "Represents: intrinsic");
+ts_keyword_type!(TSNeverKeyword, "Represents: never");
+ts_keyword_type!(TSNullKeyword, "Represents: null");
+ts_keyword_type!(TSNumberKeyword, "Represents: number");
+ts_keyword_type!(TSObjectKeyword, "Represents: object");
+ts_keyword_type!(TSStringKeyword, "Represents: string");
+ts_keyword_type!(TSSymbolKeyword, "Represents: symbol");
+ts_keyword_type!(TSUndefinedKeyword, "Represents: undefined");
+ts_keyword_type!(TSUnknownKeyword, "Represents: unknown");
+ts_keyword_type!(TSVoidKeyword, "Represents: void");
+ts_keyword_type!(TSThisType, "Represents: this (in type position)");
@@ -1,15 +1,16 @@
 //! Parser entry point and comment extraction
 
 use oxc_allocator::Allocator as OxcAllocator;
-use oxc_parser::Parser;
+use oxc_parser::{ParseOptions, Parser};
 use oxc_span::SourceType;
 use pyo3::prelude::*;
 use std::cell::RefCell;
 
 use crate::{
-    Allocator, Comment, ParseError, ParseResult, Program, Span,
-    convert_errors, convert_statement,
+    Allocator, Comment, ExportNamedDeclaration, FunctionDeclaration, ParseError, ParseResult, Program, Span,
+    TSModuleBlock, TSModuleDeclaration, convert_errors, convert_statement,
 };
+use crate::traversal::child_nodes;
 
 // =============================================================================
 // Phase 12: Line Number Computation - O(1) Lookup via Thread-Local Storage
@@ -19,7 +20,7 @@ thread_local! {
     /// Thread-local storage for line offset table.
     /// Contains a Vec where index = byte offset, value = line number.
     /// This allows O(1) lookup instead of O(n) scanning.
-    static LINE_OFFSETS: RefCell<Option<Vec<usize>>> = RefCell::new(None);
+    static LINE_OFFSETS: RefCell<Option<Vec<usize>>> = const { RefCell::new(None) };
 }
 
 /// Build a line offset table for O(1) line number lookups.
@@ -35,14 +36,14 @@ thread_local! {
 ///
 /// Returns:
 ///     Vec where vec[byte_offset] = line_number (1-indexed)
-fn build_line_offset_table(source: &str) -> Vec<usize> {
-    let len = source.len();
-    let mut table = Vec::with_capacity(len);
+#[pyfunction]
+pub fn build_line_offset_table(source: &str) -> Vec<usize> {
+    let mut table = Vec::with_capacity(source.len());
     let mut current_line = 1;
 
-    for c in source.chars() {
+    for b in source.bytes() {
         table.push(current_line);
-        if c == '\n' {
+        if b == b'\n' {
             current_line += 1;
         }
     }
@@ -63,6 +64,7 @@ fn build_line_offset_table(source: &str) -> Vec<usize> {
 ///
 /// Returns:
 ///     1-indexed line number
+#[pyfunction]
 pub fn compute_line_number(source: &str, offset: usize) -> usize {
     // Try thread-local lookup first (O(1))
     LINE_OFFSETS.with(|offsets_cell| {
@@ -75,106 +77,495 @@ pub fn compute_line_number(source: &str, offset: usize) -> usize {
         }
 
         // Fallback: O(n) scanning (for compatibility if table not set)
-        let safe_offset = offset.min(source.len());
+        let mut safe_offset = offset.min(source.len());
+        while !source.is_char_boundary(safe_offset) {
+            safe_offset -= 1;
+        }
         source[..safe_offset].chars().filter(|&c| c == '\n').count() + 1
     })
 }
 
+/// 64-bit FNV-1a hash of a source string, as 16 lowercase hex digits.
+///
+/// Deterministic across runs and platforms, so it can be stored to detect
+/// changed files without re-parsing. Not a cryptographic hash.
+///
+/// Example:
+///     >>> oxc_python.hash_source("const x = 1;") == oxc_python.parse("const x = 1;").source_hash
+///     True
+#[pyfunction]
+pub fn hash_source(source: &str) -> String {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let hash = source.bytes().fold(FNV_OFFSET_BASIS, |hash, b| {
+        (hash ^ u64::from(b)).wrapping_mul(FNV_PRIME)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Compute the column of a byte offset.
+///
+/// Columns are 0-indexed and counted in characters from the start of the line.
+pub fn compute_column(source: &str, offset: usize) -> usize {
+    let mut offset = offset.min(source.len());
+    while !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+    source[line_start..offset].chars().count()
+}
+
+/// Compute (line, column) from byte offset.
+///
+/// Args:
+///     source: Source code string
+///     offset: Byte offset into source
+///
+/// Returns:
+///     Tuple of 1-indexed line and 0-indexed column
+///
+/// Example:
+///     >>> oxc_python.compute_line_col("a\nb", 3)
+///     (2, 1)
+#[pyfunction]
+pub fn compute_line_col(source: &str, offset: usize) -> (usize, usize) {
+    (compute_line_number(source, offset), compute_column(source, offset))
+}
+
+/// Precomputed line lookup table for repeated offset queries on one source.
+///
+/// Example:
+///     >>> index = oxc_python.LineIndex(source)
+///     >>> for node, _ in oxc_python.walk(result.program):
+///     ...     print(index.line_col_of(node.span.start))
+#[pyclass]
+pub struct LineIndex {
+    source: String,
+    table: Vec<usize>,
+}
+
+#[pymethods]
+impl LineIndex {
+    #[new]
+    pub fn new(source: String) -> Self {
+        let table = build_line_offset_table(&source);
+        Self { source, table }
+    }
+
+    /// 1-indexed line number of a byte offset (clamped to the source length)
+    pub fn line_of(&self, offset: usize) -> usize {
+        if offset >= self.table.len() {
+            return self.table.last().copied().unwrap_or(1)
+                + usize::from(self.source.ends_with('\n') && offset >= self.source.len());
+        }
+        self.table[offset]
+    }
+
+    /// (line, column) of a byte offset; line is 1-indexed, column 0-indexed
+    pub fn line_col_of(&self, offset: usize) -> (usize, usize) {
+        (self.line_of(offset), compute_column(&self.source, offset))
+    }
+
+    fn __len__(&self) -> usize {
+        self.table.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("LineIndex(bytes={})", self.table.len())
+    }
+}
+
 // =============================================================================
 // Phase 8: parse() Function
 // =============================================================================
 
-/// Extract comments from source code by scanning.
+/// Extract comments recorded by the oxc lexer.
 ///
-/// Since oxc 0.97 doesn't expose comments directly through ParserReturn,
-/// we manually extract them from the source code by scanning for // and /* */ patterns.
-pub fn extract_comments(source: &str, _parser_return: &oxc_parser::ParserReturn) -> Vec<Comment> {
-    let mut comments = Vec::new();
-    let bytes = source.as_bytes();
-    let len = bytes.len();
-    let mut i = 0;
+/// oxc stores every comment on `Program.comments` with byte-accurate spans,
+/// so `//` or `/*` inside strings, template literals, and regular expressions
+/// are never mistaken for comments. The comment text excludes the delimiters.
+///
+/// Must run while the line offset table is populated so line lookups stay O(1).
+/// A hashbang line is not a comment; it is exposed as `Program.hashbang` instead.
+pub fn extract_comments(source: &str, parser_return: &oxc_parser::ParserReturn) -> Vec<Comment> {
+    parser_return.program.comments
+        .iter()
+        .map(|comment| {
+            let content = comment.content_span();
+            let text = source
+                .get(content.start as usize..content.end as usize)
+                .unwrap_or("")
+                .to_string();
+            make_comment(
+                source,
+                text,
+                comment.span.start as usize,
+                comment.span.end as usize,
+                comment.is_block(),
+            )
+        })
+        .collect()
+}
 
-    while i < len {
-        // Check for comment start
-        if i + 1 < len && bytes[i] == b'/' {
-            if bytes[i + 1] == b'/' {
-                // Line comment: //
-                let start = i;
-                let mut end = i + 2;
+/// Fold TypeScript overload signatures into their implementation.
+///
+/// oxc parses `function f(x: string): string; function f(x: any) {}` as
+/// separate FunctionDeclaration statements. Consecutive same-named
+/// declarations without a body are moved into the `overloads` list of the
+/// following implementation. Signatures with no implementation (e.g. in
+/// `.d.ts` files) are left in place.
+///
+/// `export function` overloads are grouped the same way (the `overloads`
+/// list holds the inner FunctionDeclarations), and namespace bodies are
+/// processed recursively.
+fn post_process_overloads(body: &mut Vec<Py<PyAny>>, py: Python) {
+    let mut result: Vec<Py<PyAny>> = Vec::with_capacity(body.len());
+    // (statement, function) pairs; the statement is an export wrapper or the function itself
+    let mut pending: Vec<(Py<PyAny>, Py<PyAny>)> = Vec::new();
+    // Name and export-ness of the pending signatures; both must match to group
+    let mut pending_key: Option<(String, bool)> = None;
+    let flush = |pending: &mut Vec<(Py<PyAny>, Py<PyAny>)>, result: &mut Vec<Py<PyAny>>| {
+        result.extend(pending.drain(..).map(|(stmt, _)| stmt));
+    };
+
+    for node in body.drain(..) {
+        post_process_module_overloads(node.bind(py), py);
+        let Some(func_node) = overload_candidate(node.bind(py), py) else {
+            flush(&mut pending, &mut result);
+            result.push(node);
+            continue;
+        };
+        let exported = !node.bind(py).is_instance_of::<FunctionDeclaration>();
+        let mut func = func_node.bind(py).extract::<PyRefMut<FunctionDeclaration>>()
+            .expect("overload candidates are FunctionDeclarations");
+        let key = func.name.clone().map(|name| (name, exported));
+        if key.is_none() || key != pending_key {
+            flush(&mut pending, &mut result);
+        }
+        if func.body.is_none() {
+            pending_key = key;
+            drop(func);
+            pending.push((node, func_node));
+        } else {
+            func.overloads = pending.drain(..).map(|(_, overload)| overload).collect();
+            drop(func);
+            result.push(node);
+        }
+    }
+    flush(&mut pending, &mut result);
+    *body = result;
+}
+
+/// The FunctionDeclaration of a statement, looking through `export`.
+fn overload_candidate(node: &Bound<'_, PyAny>, py: Python) -> Option<Py<PyAny>> {
+    if node.is_instance_of::<FunctionDeclaration>() {
+        return Some(node.clone().unbind());
+    }
+    let export = node.extract::<PyRef<ExportNamedDeclaration>>().ok()?;
+    let declaration = export.declaration.as_ref()?;
+    declaration.bind(py).is_instance_of::<FunctionDeclaration>().then(|| declaration.clone_ref(py))
+}
+
+/// Group overloads inside a (possibly exported) namespace's blocks.
+fn post_process_module_overloads(node: &Bound<'_, PyAny>, py: Python) {
+    let mut module = match node.extract::<PyRef<ExportNamedDeclaration>>() {
+        Ok(export) => export.declaration.as_ref().map(|d| d.clone_ref(py)),
+        Err(_) => Some(node.clone().unbind()),
+    };
+    // `namespace A.B {}` nests declarations down to the block
+    while let Some(current) = module.take() {
+        let current = current.bind(py);
+        if let Ok(declaration) = current.extract::<PyRef<TSModuleDeclaration>>() {
+            module = declaration.body.as_ref().map(|b| b.clone_ref(py));
+        } else if let Ok(mut block) = current.extract::<PyRefMut<TSModuleBlock>>() {
+            post_process_overloads(&mut block.body, py);
+        }
+    }
+}
 
-                // Find end of line
-                while end < len && bytes[end] != b'\n' && bytes[end] != b'\r' {
-                    end += 1;
+/// Strict mode violations in an otherwise valid tree, for `parse(strict_mode=True)`.
+///
+/// oxc's parser leaves these checks to semantic analysis, so they are done on
+/// the converted tree: `with` statements, and legacy octal (`010`) and
+/// leading-zero decimal (`09`) literals.
+fn strict_mode_errors(py: Python, program: Py<PyAny>, source: &str, source_file: Option<&str>) -> PyResult<Vec<ParseError>> {
+    let mut errors = Vec::new();
+    let mut stack = vec![program];
+
+    while let Some(node) = stack.pop() {
+        let node = node.bind(py);
+        // Push children in reverse so errors come out in source order
+        stack.extend(child_nodes(py, node).into_iter().rev());
+        let Ok(node_type) = node.getattr("type").and_then(|t| t.extract::<String>()) else { continue };
+        let message = match node_type.as_str() {
+            "WithStatement" => "'with' statements are not allowed in strict mode",
+            "Literal" => {
+                let raw: String = node.getattr("raw")?.extract()?;
+                if raw.len() > 1 && raw.starts_with('0') && raw.bytes().all(|b| b.is_ascii_digit()) {
+                    "Octal and leading-zero decimal literals are not allowed in strict mode"
+                } else {
+                    continue;
                 }
+            }
+            _ => continue,
+        };
+        let span: Span = node.getattr("span")?.extract()?;
+        errors.push(ParseError {
+            message: message.to_string(),
+            span,
+            severity: "error".to_string(),
+            code: None,
+            source_file: source_file.map(str::to_string),
+            start_line: compute_line_number(source, span.start),
+        });
+    }
 
-                // Extract and clean text
-                let text = source[start + 2..end].to_string();
+    Ok(errors)
+}
 
-                comments.push(Comment {
-                    text,
-                    span: Span {
-                        start,
-                        end,
-                    },
-                    is_block: false,
-                });
+/// Name of an oxc SourceType as accepted by parse()'s `source_type` argument.
+fn source_type_name(source_type: SourceType) -> &'static str {
+    match (source_type.is_typescript(), source_type.is_jsx()) {
+        (true, true) => "tsx",
+        (true, false) => "typescript",
+        (false, true) => "jsx",
+        (false, false) if source_type.is_script() => "script",
+        (false, false) => "module",
+    }
+}
 
-                i = end;
-                continue;
-            } else if bytes[i + 1] == b'*' {
-                // Block comment: /* */
-                let start = i;
-                let mut end = i + 2;
+/// Guess the source type of code parsed with `source_type="auto"`.
+///
+/// A hashbang line means a CommonJS script. Otherwise the source is scanned
+/// (skipping strings, template literals, regex literals, and comments) for
+/// JSX tags in expression position and for TypeScript-only syntax:
+/// `interface`, `enum`, `declare`, `namespace`, and `abstract class`
+/// declarations, `type X =` aliases, `import type`, `: Type` annotations on
+/// declarators, parameters, return types, and class fields, generic
+/// parameter lists (`function f<T>`, `<T,>() =>`), and `as`/`satisfies`.
+/// Defaults to "module". parse() retries as TypeScript when a source detected
+/// as JavaScript fails to parse, so forms the scan misses still parse.
+pub fn detect_source_type(source: &str) -> SourceType {
+    if source.starts_with("#!") {
+        return SourceType::cjs();
+    }
 
-                // Find end of block comment
-                while end + 1 < len {
-                    if bytes[end] == b'*' && bytes[end + 1] == b'/' {
-                        end += 2;
-                        break;
-                    }
-                    end += 1;
-                }
+    let bytes = source.as_bytes();
+    let len = bytes.len();
+    let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b == b'$';
+    let next_word = |from: usize| {
+        let rest = source[from..].trim_start();
+        &rest[..rest.bytes().take_while(|&c| is_ident(c)).count()]
+    };
+    let mut has_jsx = false;
+    let mut has_ts = false;
+    // Last significant (non-whitespace) byte and the last identifier-like word
+    let mut prev: Option<u8> = None;
+    let mut prev_word = "";
+    // The word before `prev_word`, or "" if punctuation separated them
+    let mut word_before = "";
+    // Open brackets ('(', '[', '{', or 'c' for a class body) with the number
+    // of `?` (and `case`) at that level still waiting for their `:`
+    let mut brackets: Vec<(u8, usize)> = vec![(b'{', 0)];
+    // Depth of the `{ ... }` in `import { a as b }` / `export { a as b }`
+    let mut module_clause: Option<usize> = None;
+    let mut in_import = false;
+    let mut class_pending = false;
+    let mut i = 0;
 
-                // Extract and clean text
-                let text = if end >= start + 4 {
-                    source[start + 2..end - 2].to_string()
-                } else {
-                    String::new()
-                };
-
-                comments.push(Comment {
-                    text,
-                    span: Span {
-                        start,
-                        end,
-                    },
-                    is_block: true,
-                });
-
-                i = end;
+    while i < len && !(has_jsx && has_ts) {
+        let b = bytes[i];
+        match b {
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < len && bytes[i] != b'\n' {
+                    i += 1;
+                }
                 continue;
             }
-        }
-
-        // Skip over strings to avoid false positives (// or /* inside strings)
-        if bytes[i] == b'"' || bytes[i] == b'\'' || bytes[i] == b'`' {
-            let quote = bytes[i];
-            i += 1;
-
-            while i < len {
-                if bytes[i] == quote && (i == 0 || bytes[i - 1] != b'\\') {
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i + 1 < len && !(bytes[i] == b'*' && bytes[i + 1] == b'/') {
+                    i += 1;
+                }
+                i += 2;
+                continue;
+            }
+            b'/' if prev.is_none_or(|p| b"(,=:[!&|?{};+-*%<>~^".contains(&p))
+                || matches!(prev_word, "return" | "typeof" | "case" | "do" | "else" | "in" | "of"
+                    | "new" | "delete" | "void" | "throw" | "yield" | "await" | "instanceof") =>
+            {
+                // Regular expression literal; `/` inside a character class does not end it
+                i += 1;
+                let mut in_class = false;
+                while i < len && bytes[i] != b'\n' && (in_class || bytes[i] != b'/') {
+                    match bytes[i] {
+                        b'\\' => i += 1,
+                        b'[' => in_class = true,
+                        b']' => in_class = false,
+                        _ => {}
+                    }
                     i += 1;
-                    break;
                 }
                 i += 1;
+                prev = Some(b'/');
+                prev_word = "";
+                continue;
             }
-            continue;
+            b'"' | b'\'' | b'`' => {
+                i += 1;
+                while i < len && bytes[i] != b {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+                i += 1;
+                prev = Some(b);
+                prev_word = "";
+                continue;
+            }
+            b';' => in_import = false,
+            b'(' | b'[' => brackets.push((b, 0)),
+            b'{' => {
+                let kind = if std::mem::take(&mut class_pending) { b'c' } else { b'{' };
+                brackets.push((kind, 0));
+                if matches!(prev_word, "import" | "export" | "type") || (prev == Some(b',') && in_import) {
+                    module_clause = Some(brackets.len());
+                }
+            }
+            b')' | b']' | b'}' => {
+                if module_clause == Some(brackets.len()) {
+                    module_clause = None;
+                }
+                if brackets.len() > 1 {
+                    brackets.pop();
+                }
+            }
+            b'?' => {
+                let next = source[i + 1..].trim_start().bytes().next();
+                match next {
+                    // Optional parameter or property: `a?: T`
+                    Some(b':') if prev.is_some_and(is_ident) => has_ts = true,
+                    // `?.` and `??` are not part of a conditional
+                    Some(b'.') | Some(b'?') => {}
+                    _ if prev == Some(b'?') => {}
+                    _ => brackets.last_mut().expect("root bracket").1 += 1,
+                }
+            }
+            b'<' => {
+                let tag_start = bytes.get(i + 1).is_some_and(|&c| c.is_ascii_alphabetic() || c == b'>');
+                let expression_position = prev.is_none_or(|p| b"(=?:,[{};>&|!".contains(&p))
+                    || matches!(prev_word, "return" | "yield" | "default");
+                if prev.is_some_and(is_ident) && matches!(word_before, "function" | "class")
+                    || prev_word == "function"
+                {
+                    // Type parameters: `function f<T>`, `class Box<T>`
+                    has_ts = true;
+                } else if tag_start && expression_position {
+                    // `<T>(x) => x`, `<T,>(x) => x` and `<T extends U>` are generics, not tags
+                    let name_end = (i + 1..len).find(|&j| !is_ident(bytes[j])).unwrap_or(len);
+                    let generic = match bytes.get(name_end) {
+                        Some(b'>') => bytes.get(name_end + 1) == Some(&b'('),
+                        Some(b',') => true,
+                        _ => next_word(name_end) == "extends",
+                    };
+                    if generic {
+                        has_ts = true;
+                    } else {
+                        has_jsx = true;
+                    }
+                }
+            }
+            b':' => {
+                let (kind, pending) = brackets.last_mut().expect("root bracket");
+                if *pending > 0 {
+                    // The `:` of a conditional or a `case` label
+                    *pending -= 1;
+                } else {
+                    let after_name = prev.is_some_and(is_ident);
+                    // `const x: T`, `(a: T)`, `({ a }: T)`, `f(): T`, and class fields `x: T` / `x!: T`
+                    let annotated = (after_name && matches!(word_before, "const" | "let" | "var"))
+                        || (*kind == b'(' && (after_name || matches!(prev, Some(b'}') | Some(b']'))))
+                        || prev == Some(b')')
+                        || *kind == b'c';
+                    has_ts |= annotated;
+                }
+            }
+            _ if is_ident(b) => {
+                let start = i;
+                while i < len && is_ident(bytes[i]) {
+                    i += 1;
+                }
+                let word = &source[start..i];
+                let at_statement_start = prev.is_none_or(|p| b";{}".contains(&p))
+                    || matches!(prev_word, "export" | "declare");
+                let next = source[i..].trim_start();
+                let next_is_name = next.bytes().next().is_some_and(|c| is_ident(c) && !c.is_ascii_digit());
+                if at_statement_start && next_is_name {
+                    match word {
+                        "interface" | "enum" | "declare" | "namespace" => has_ts = true,
+                        "abstract" if next.starts_with("class") => has_ts = true,
+                        "type" => {
+                            let after_name = next.trim_start_matches(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$').trim_start();
+                            has_ts |= after_name.starts_with('=') || after_name.starts_with('<');
+                        }
+                        _ => {}
+                    }
+                }
+                match word {
+                    "import" if at_statement_start => in_import = true,
+                    "from" => in_import = false,
+                    "class" => class_pending = true,
+                    "case" => brackets.last_mut().expect("root bracket").1 += 1,
+                    "default" if next.starts_with(':') => brackets.last_mut().expect("root bracket").1 += 1,
+                    // `import type { A }`, `export type * from`, `import { type A }`
+                    "type" if matches!(prev_word, "import" | "export") || module_clause.is_some() => {
+                        let type_only = next.starts_with('{') || next.starts_with('*')
+                            || (next_is_name && !matches!(next_word(i), "from" | "as"));
+                        has_ts |= type_only;
+                    }
+                    // `x as T` / `x satisfies T`, but not `import { a as b }` or `* as ns`
+                    "as" | "satisfies" => {
+                        let after_operand = prev.is_some_and(|p| is_ident(p) || b")]}".contains(&p))
+                            && !matches!(prev_word, "const" | "let" | "var" | "function" | "class" | "import" | "export");
+                        let type_follows = next.bytes().next().is_some_and(|c| is_ident(c) || b"{[('\"".contains(&c));
+                        let module_syntax = word == "as" && (module_clause.is_some() || prev == Some(b'*'));
+                        has_ts |= after_operand && type_follows && !module_syntax;
+                    }
+                    _ => {}
+                }
+                prev = Some(bytes[i - 1]);
+                word_before = prev_word;
+                prev_word = word;
+                continue;
+            }
+            _ => {}
+        }
+        if !b.is_ascii_whitespace() {
+            prev = Some(b);
+            prev_word = "";
+            word_before = "";
         }
-
         i += 1;
     }
 
-    comments
+    match (has_ts, has_jsx) {
+        (true, true) => SourceType::tsx(),
+        (true, false) => SourceType::ts(),
+        (false, true) => SourceType::jsx(),
+        (false, false) => SourceType::mjs(),
+    }
+}
+
+/// Build a Comment with line and column positions for `start..end`.
+fn make_comment(source: &str, text: String, start: usize, end: usize, is_block: bool) -> Comment {
+    Comment {
+        text,
+        span: Span { start, end },
+        is_block,
+        start_line: compute_line_number(source, start),
+        end_line: compute_line_number(source, end),
+        start_col: compute_column(source, start),
+        end_col: compute_column(source, end),
+    }
 }
 
 /// Parse JavaScript/TypeScript source code into an AST.
@@ -185,7 +576,16 @@ pub fn extract_comments(source: &str, _parser_return: &oxc_parser::ParserReturn)
 /// Args:
 ///     source: JavaScript/TypeScript source code to parse
 ///     allocator: Optional allocator for memory reuse (performance optimization)
-///     source_type: Optional source type ("module" or "script", defaults to "module")
+///     source_type: Optional source type ("auto", "module", "script", "jsx", "ts"/"typescript",
+///         or "tsx"). Defaults to "auto", which uses the filename extension when one
+///         is given and otherwise detects JSX and TypeScript syntax in the source
+///         (see detect_source_type)
+///     preserve_parens: Keep ParenthesizedExpression nodes for explicitly
+///         parenthesized expressions (default False drops them)
+///     filename: Optional filename recorded on the result and its errors. With
+///         source_type "auto", the file extension (.ts, .tsx, .jsx, ...) picks it.
+///     strict_mode: Report strict mode violations (`with` statements, legacy
+///         octal literals) as errors even without a "use strict" directive
 ///
 /// Returns:
 ///     ParseResult containing program AST, errors list, and is_valid flag
@@ -205,8 +605,16 @@ pub fn extract_comments(source: &str, _parser_return: &oxc_parser::ParserReturn)
 ///     ...     process(result)
 ///     ...     allocator.reset()
 #[pyfunction]
-#[pyo3(signature = (source, *, allocator=None, source_type=None))]
-pub fn parse(py: Python, source: &str, allocator: Option<&Allocator>, source_type: Option<&str>) -> PyResult<ParseResult> {
+#[pyo3(signature = (source, *, allocator=None, source_type=None, filename=None, preserve_parens=false, strict_mode=false))]
+pub fn parse(
+    py: Python,
+    source: &str,
+    allocator: Option<&Allocator>,
+    source_type: Option<&str>,
+    filename: Option<String>,
+    preserve_parens: bool,
+    strict_mode: bool,
+) -> PyResult<ParseResult> {
     // Step 1: Get or create allocator
     // If allocator is provided, use it; otherwise create a temporary one
     let owned_allocator;
@@ -239,13 +647,21 @@ pub fn parse(py: Python, source: &str, allocator: Option<&Allocator>, source_typ
 
     // Step 2: Create parser with appropriate source type
     // Parse source_type string and construct oxc SourceType with TypeScript support
-    let oxc_source_type = match source_type {
+    let mut detected = false;
+    let mut oxc_source_type = match source_type {
         Some("module") => SourceType::mjs(),
         Some("script") => SourceType::cjs(),
         Some("jsx") => SourceType::jsx(),
         Some("tsx") => SourceType::tsx(),
         Some("typescript") | Some("ts") => SourceType::ts(),
-        None => SourceType::mjs(),  // Default: JS module
+        // Default: infer from the filename extension, else detect from the source
+        Some("auto") | None => filename
+            .as_deref()
+            .and_then(|name| SourceType::from_path(name).ok())
+            .unwrap_or_else(|| {
+                detected = true;
+                detect_source_type(source)
+            }),
         Some(invalid) => {
             // Clean up thread-local storage before returning error
             LINE_OFFSETS.with(|offsets_cell| {
@@ -254,16 +670,28 @@ pub fn parse(py: Python, source: &str, allocator: Option<&Allocator>, source_typ
 
             // Reject invalid source_type values
             return Err(pyo3::exceptions::PyValueError::new_err(format!(
-                "Invalid source_type: '{}'. Must be 'tsx', 'jsx', 'module', 'script', 'ts', or 'typescript'",
+                "Invalid source_type: '{}'. Must be 'auto', 'tsx', 'jsx', 'module', 'script', 'ts', or 'typescript'",
                 invalid
             )));
         }
     };
 
-    let parser = Parser::new(alloc_ref, source, oxc_source_type);
+    let options = ParseOptions { preserve_parens, ..ParseOptions::default() };
+    let parser = Parser::new(alloc_ref, source, oxc_source_type).with_options(options);
 
     // Step 3: Parse the source
-    let parse_result = parser.parse();
+    let mut parse_result = parser.parse();
+
+    // Detection only recognizes common TypeScript forms; if detected JavaScript
+    // fails to parse, use TypeScript instead when that parses cleanly
+    if detected && !oxc_source_type.is_typescript() && !parse_result.errors.is_empty() {
+        let typescript = if oxc_source_type.is_jsx() { SourceType::tsx() } else { SourceType::ts() };
+        let retry = Parser::new(alloc_ref, source, typescript).with_options(options).parse();
+        if retry.errors.is_empty() {
+            parse_result = retry;
+            oxc_source_type = typescript;
+        }
+    }
 
     // Step 4: Convert oxc result to Python ParseResult
     // Convert statements from oxc result to Python nodes
@@ -275,6 +703,7 @@ pub fn parse(py: Python, source: &str, allocator: Option<&Allocator>, source_typ
         // JSXElement and JSXFragment cases. The walk() iterator traverses into
         // expression trees to find JSX nodes nested in arrow functions, conditionals, etc.
     }
+    post_process_overloads(&mut body, py);
 
     // Create Program node with converted body
     let program_span = Span::from(parse_result.program.span);
@@ -285,14 +714,19 @@ pub fn parse(py: Python, source: &str, allocator: Option<&Allocator>, source_typ
     let end_line = compute_line_number(source, parse_result.program.span.end as usize);
     program_node.start_line = start_line;
     program_node.end_line = end_line;
+    program_node.hashbang = parse_result.program.hashbang.as_ref().map(|h| h.value.to_string());
 
     let program = Py::new(py, program_node)?;
 
-    // Phase 18: Extract comments from parse result (before moving errors)
+    // Phase 18: Extract comments from parse result (before moving errors, and
+    // before the line offset table is torn down below)
     let comments = extract_comments(source, &parse_result);
 
     // Phase 19: Convert oxc errors to ParseError objects
-    let errors = convert_errors(parse_result.errors);
+    let mut errors = convert_errors(parse_result.errors, source, filename.as_deref());
+    if strict_mode {
+        errors.extend(strict_mode_errors(py, program.clone_ref(py).into_any(), source, filename.as_deref())?);
+    }
 
     // Get panicked flag before parse_result is consumed
     let panicked = parse_result.panicked;
@@ -307,5 +741,94 @@ pub fn parse(py: Python, source: &str, allocator: Option<&Allocator>, source_typ
         errors,
         comments,
         panicked,
+        source_file: filename,
+        source_type: source_type_name(oxc_source_type).to_string(),
+        strict_mode,
+        source_hash: hash_source(source),
+        has_jsx_cache: None,
+        has_typescript_cache: None,
+        import_paths_cache: None,
+        export_names_cache: None,
+        required_modules_cache: None,
     })
 }
+
+/// Parse many sources in order, reporting progress after each one.
+///
+/// Conversion to Python nodes needs the GIL, so sources are parsed one after
+/// another on the calling thread and `callback(completed, total)` is invoked
+/// between them. An exception raised by the callback stops the batch.
+///
+/// Args:
+///     sources: Source strings to parse
+///     callback: Called as callback(completed, total) after each parse
+///     source_type: Optional source type applied to every source (see parse())
+///
+/// Returns:
+///     List of ParseResult, one per source, in input order
+///
+/// Example:
+///     >>> def on_progress(done, total):
+///     ...     print(f"{done}/{total}")
+///     >>> results = oxc_python.parse_many_with_progress(sources, on_progress)
+#[pyfunction]
+#[pyo3(signature = (sources, callback, source_type=None))]
+pub fn parse_many_with_progress(
+    py: Python,
+    sources: Vec<String>,
+    callback: Bound<'_, PyAny>,
+    source_type: Option<&str>,
+) -> PyResult<Vec<ParseResult>> {
+    let total = sources.len();
+    let mut results = Vec::with_capacity(total);
+    for (index, source) in sources.iter().enumerate() {
+        results.push(parse(py, source, None, source_type, None, false, false)?);
+        callback.call1((index + 1, total))?;
+    }
+    Ok(results)
+}
+
+/// Prefix wrapped around the input of parse_typescript_type().
+const TYPE_ALIAS_PREFIX: &str = "type __T__ = ";
+
+/// Parse a standalone TypeScript type expression.
+///
+/// The type is parsed as the right-hand side of `type __T__ = <type_str>;`, so
+/// node spans are offsets into that wrapped source (subtract 13 to map them
+/// back onto `type_str`).
+///
+/// Args:
+///     type_str: TypeScript type text, e.g. "Record<string, number[]>"
+///
+/// Returns:
+///     The type node (TSUnionType, TSTypeReference, TSTypeLiteral, ...)
+///
+/// Raises:
+///     ValueError: If the text is not a single valid type
+///
+/// Example:
+///     >>> oxc_python.parse_typescript_type("string | number").type
+///     'TSUnionType'
+#[pyfunction]
+pub fn parse_typescript_type(py: Python, type_str: &str) -> PyResult<Py<PyAny>> {
+    let wrapped = format!("{}{};", TYPE_ALIAS_PREFIX, type_str);
+    let result = parse(py, &wrapped, None, Some("typescript"), None, false, false)?;
+
+    if let Some(error) = result.errors.first() {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Invalid TypeScript type '{}': {}",
+            type_str, error.message
+        )));
+    }
+
+    let program = result.program.expect("parse() always produces a program");
+    let body = program.bind(py).getattr("body")?.extract::<Vec<Py<PyAny>>>()?;
+    // Anything after the type (e.g. "string; let x") would add statements
+    let [alias] = body.as_slice() else {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Invalid TypeScript type '{}': expected a single type expression",
+            type_str
+        )));
+    };
+    Ok(alias.bind(py).getattr("type_annotation")?.unbind())
+}
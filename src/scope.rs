@@ -0,0 +1,337 @@
+//! Scope analysis utilities
+
+use pyo3::prelude::*;
+use crate::Span;
+use crate::traversal::{child_nodes, node_span as span_of};
+
+// =============================================================================
+// Scope Tree
+// =============================================================================
+
+/// A lexical scope and the names declared directly in it.
+///
+/// Scopes form a tree rooted at the module scope. `var` declarations are
+/// hoisted to the nearest function (or module) scope; `let`, `const`, class
+/// and function declarations bind in the scope where they appear. Destructuring
+/// binds every name in the pattern, and a named function or class expression
+/// binds its name only in its own scope.
+///
+/// Example:
+///     scope = collect_scopes(result.program)
+///     print(scope.kind, scope.bindings)
+///     for child in scope.children:
+///         print(child.kind, child.bindings)
+#[pyclass]
+pub struct Scope {
+    /// Scope kind ("module", "function", "block", or "class")
+    #[pyo3(get)]
+    pub kind: String,
+
+    /// Names declared in this scope, in source order
+    #[pyo3(get)]
+    pub bindings: Vec<String>,
+
+    /// Nested scopes
+    #[pyo3(get)]
+    pub children: Vec<Py<Scope>>,
+
+    /// Source location of the scope-creating node
+    #[pyo3(get)]
+    pub span: Span,
+}
+
+#[pymethods]
+impl Scope {
+    fn __repr__(&self) -> String {
+        format!(
+            "Scope(kind='{}', bindings={:?}, children={}, span={}..{})",
+            self.kind, self.bindings, self.children.len(), self.span.start, self.span.end
+        )
+    }
+}
+
+/// Scope under construction; converted to `Scope` once the subtree is done.
+struct ScopeBuilder {
+    kind: &'static str,
+    bindings: Vec<String>,
+    children: Vec<ScopeBuilder>,
+    span: Span,
+}
+
+impl ScopeBuilder {
+    fn new(kind: &'static str, span: Span) -> Self {
+        Self { kind, bindings: Vec::new(), children: Vec::new(), span }
+    }
+
+    fn into_py(self, py: Python) -> PyResult<Py<Scope>> {
+        let children = self.children
+            .into_iter()
+            .map(|child| child.into_py(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        Py::new(py, Scope {
+            kind: self.kind.to_string(),
+            bindings: self.bindings,
+            children,
+            span: self.span,
+        })
+    }
+}
+
+fn node_type(node: &Bound<'_, PyAny>) -> String {
+    node.getattr("type")
+        .and_then(|t| t.extract::<String>())
+        .unwrap_or_default()
+}
+
+fn node_span(node: &Bound<'_, PyAny>) -> Span {
+    span_of(node).unwrap_or(Span { start: 0, end: 0 })
+}
+
+/// Read a string `name` attribute, or the `.name` of an Identifier-valued attribute.
+fn binding_name(node: &Bound<'_, PyAny>, attr: &str) -> Option<String> {
+    let value = node.getattr(attr).ok()?;
+    if value.is_none() {
+        return None;
+    }
+    if let Ok(name) = value.extract::<String>() {
+        return Some(name);
+    }
+    value.getattr("name").ok()?.extract::<String>().ok()
+}
+
+/// Every name bound by a declarator, parameter, or binding pattern, in source order.
+///
+/// Declarators and parameters report their own flattened names (`id_names()`);
+/// pattern nodes (e.g. destructured catch parameters) are flattened here.
+fn bound_names(py: Python, node: &Bound<'_, PyAny>) -> Vec<String> {
+    if let Ok(names) = node.call_method0("id_names").and_then(|n| n.extract::<Vec<String>>()) {
+        return names;
+    }
+    let attr_names = |attr: &str| -> Vec<String> {
+        node.getattr(attr)
+            .ok()
+            .filter(|value| !value.is_none())
+            .map(|value| bound_names(py, &value))
+            .unwrap_or_default()
+    };
+    match node_type(node).as_str() {
+        "Identifier" => binding_name(node, "name").into_iter().collect(),
+        "ObjectPattern" | "ArrayPattern" => {
+            let list = if node_type(node) == "ObjectPattern" { "properties" } else { "elements" };
+            let mut names: Vec<String> = node.getattr(list)
+                .and_then(|items| items.extract::<Vec<Option<Py<PyAny>>>>())
+                .unwrap_or_default()
+                .into_iter()
+                .flatten()
+                .flat_map(|item| bound_names(py, item.bind(py)))
+                .collect();
+            names.extend(attr_names("rest"));
+            names
+        }
+        "AssignmentPattern" => attr_names("left"),
+        "RestElement" => attr_names("argument"),
+        _ => Vec::new(),
+    }
+}
+
+/// Nodes whose children are statements, where a FunctionDeclaration or
+/// ClassDeclaration child is a declaration rather than a named expression.
+fn holds_statements(node: &Bound<'_, PyAny>) -> bool {
+    matches!(
+        node_type(node).as_str(),
+        "Program" | "BlockStatement" | "SwitchCase" | "TSModuleBlock" | "LabeledStatement"
+            | "ExportNamedDeclaration" | "ExportDefaultDeclaration"
+    )
+}
+
+struct ScopeCollector {
+    stack: Vec<ScopeBuilder>,
+}
+
+impl ScopeCollector {
+    fn declare(&mut self, name: String) {
+        if let Some(scope) = self.stack.last_mut() {
+            scope.bindings.push(name);
+        }
+    }
+
+    /// Declare in the nearest function or module scope (`var` hoisting).
+    fn declare_hoisted(&mut self, name: String) {
+        if let Some(scope) = self.stack.iter_mut().rev().find(|s| s.kind == "function" || s.kind == "module") {
+            scope.bindings.push(name);
+        }
+    }
+
+    fn push(&mut self, kind: &'static str, span: Span) {
+        self.stack.push(ScopeBuilder::new(kind, span));
+    }
+
+    fn pop(&mut self) {
+        if self.stack.len() > 1 {
+            let scope = self.stack.pop().expect("scope stack is non-empty");
+            if let Some(parent) = self.stack.last_mut() {
+                parent.children.push(scope);
+            }
+        }
+    }
+
+    fn visit_children(&mut self, py: Python, node: &Bound<'_, PyAny>) {
+        let declarations = holds_statements(node);
+        for child in child_nodes(py, node) {
+            self.visit(py, child.bind(py), declarations);
+        }
+    }
+
+    /// Visit a function-like node: params and the top-level body statements
+    /// share one function scope. A named function expression's own name
+    /// (`own_name`) is bound there too.
+    fn visit_function(&mut self, py: Python, node: &Bound<'_, PyAny>, body_attr: &str, own_name: Option<String>) {
+        self.push("function", node_span(node));
+        if let Some(name) = own_name {
+            self.declare(name);
+        }
+        if let Ok(params) = node.getattr("params").and_then(|p| p.extract::<Vec<Py<PyAny>>>()) {
+            for param in params {
+                for name in bound_names(py, param.bind(py)) {
+                    self.declare(name);
+                }
+            }
+        }
+        if let Ok(body) = node.getattr(body_attr) {
+            if !body.is_none() {
+                if node_type(&body) == "BlockStatement" {
+                    self.visit_children(py, &body);
+                } else {
+                    self.visit(py, &body, false);
+                }
+            }
+        }
+        self.pop();
+    }
+
+    /// Visit a node; `declaration` is true when it sits in a statement list,
+    /// so a function or class there binds its name in the enclosing scope.
+    fn visit(&mut self, py: Python, node: &Bound<'_, PyAny>, declaration: bool) {
+        match node_type(node).as_str() {
+            "FunctionDeclaration" => {
+                let name = binding_name(node, "name");
+                if declaration {
+                    if let Some(name) = name {
+                        self.declare(name);
+                    }
+                    self.visit_function(py, node, "body", None);
+                } else {
+                    self.visit_function(py, node, "body", name);
+                }
+            }
+            "ArrowFunctionExpression" => self.visit_function(py, node, "body", None),
+            "MethodDefinition" | "TSAbstractMethodDefinition" => {
+                self.visit_function(py, node, "function_body", None)
+            }
+            "ClassDeclaration" => {
+                let name = binding_name(node, "name");
+                if declaration {
+                    if let Some(name) = name.clone() {
+                        self.declare(name);
+                    }
+                }
+                self.push("class", node_span(node));
+                if let Some(name) = name.filter(|_| !declaration) {
+                    self.declare(name);
+                }
+                self.visit_children(py, node);
+                self.pop();
+            }
+            "BlockStatement" => {
+                self.push("block", node_span(node));
+                self.visit_children(py, node);
+                self.pop();
+            }
+            // `let` / `const` in a loop head are scoped to the loop
+            "ForStatement" | "ForInStatement" | "ForOfStatement" => {
+                let head = node.getattr("init").or_else(|_| node.getattr("left")).ok();
+                let lexical = head.is_some_and(|head| {
+                    node_type(&head) == "VariableDeclaration"
+                        && head.getattr("kind").and_then(|k| k.extract::<String>()).is_ok_and(|k| k != "var")
+                });
+                if lexical {
+                    self.push("block", node_span(node));
+                    self.visit_children(py, node);
+                    self.pop();
+                } else {
+                    self.visit_children(py, node);
+                }
+            }
+            "VariableDeclaration" => {
+                let hoisted = node.getattr("kind")
+                    .and_then(|k| k.extract::<String>())
+                    .map(|k| k == "var")
+                    .unwrap_or(false);
+                if let Ok(declarations) = node.getattr("declarations").and_then(|d| d.extract::<Vec<Py<PyAny>>>()) {
+                    for declarator in &declarations {
+                        for name in bound_names(py, declarator.bind(py)) {
+                            if hoisted {
+                                self.declare_hoisted(name);
+                            } else {
+                                self.declare(name);
+                            }
+                        }
+                    }
+                }
+                self.visit_children(py, node);
+            }
+            // The caught exception binds in the clause, alongside its body's statements
+            "CatchClause" => {
+                self.push("block", node_span(node));
+                if let Ok(param) = node.getattr("param") {
+                    for name in bound_names(py, &param) {
+                        self.declare(name);
+                    }
+                }
+                if let Some(body) = node.getattr("body").ok().filter(|body| !body.is_none()) {
+                    self.visit_children(py, &body);
+                }
+                self.pop();
+            }
+            "ImportDeclaration" => {
+                if let Ok(specifiers) = node.getattr("specifiers").and_then(|s| s.extract::<Vec<Py<PyAny>>>()) {
+                    for spec in &specifiers {
+                        if let Some(name) = binding_name(spec.bind(py), "local") {
+                            self.declare(name);
+                        }
+                    }
+                }
+            }
+            _ => self.visit_children(py, node),
+        }
+    }
+}
+
+/// Build the scope tree for a program.
+///
+/// Recognizes Program (module), FunctionDeclaration, ArrowFunctionExpression
+/// and class methods (function), BlockStatement, CatchClause, and `for` loops
+/// with a `let`/`const` head (block), and ClassDeclaration (class) as
+/// scope-creating nodes.
+///
+/// Args:
+///     program: Root Program node
+///
+/// Returns:
+///     The module Scope, with nested scopes in `children`
+///
+/// Example:
+///     >>> result = oxc_python.parse("function f(a) { if (a) { let b = 1; } }")
+///     >>> scope = oxc_python.collect_scopes(result.program)
+///     >>> scope.children[0].bindings
+///     ['a']
+#[pyfunction]
+pub fn collect_scopes(py: Python, program: Py<PyAny>) -> PyResult<Py<Scope>> {
+    let program = program.bind(py);
+    let mut collector = ScopeCollector {
+        stack: vec![ScopeBuilder::new("module", node_span(program))],
+    };
+    collector.visit_children(py, program);
+    let root = collector.stack.pop().expect("module scope is always present");
+    root.into_py(py)
+}
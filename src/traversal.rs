@@ -1,7 +1,9 @@
 //! AST traversal utilities
 
 use pyo3::prelude::*;
-use std::collections::VecDeque;
+use pyo3::types::PyDict;
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::Span;
 
 // =============================================================================
 // Phase 10: Walk Iterator with Depth Tracking
@@ -24,11 +26,119 @@ use std::collections::VecDeque;
 ///         print(f"{'  ' * depth}{node.type}")
 #[pyclass]
 pub struct WalkIterator {
-    /// Queue of (node, depth) to visit (using VecDeque for efficient front operations)
-    queue: VecDeque<(Py<PyAny>, usize)>,
+    /// Stack of (node, depth, parent) still to visit; the top is visited next
+    stack: Vec<(Py<PyAny>, usize, Option<Py<PyAny>>)>,
+
+    /// Deepest level to yield (None = unlimited)
+    max_depth: Option<usize>,
+
+    /// Node types to yield (None = all); the full tree is still traversed
+    types: Option<HashSet<String>>,
 }
 
 impl WalkIterator {
+    /// Create new iterator starting at program node with depth 0
+    pub fn new(program: Py<PyAny>) -> Self {
+        Self::new_with_limit(program, None)
+    }
+
+    /// Create new iterator that stops descending below `max_depth`
+    pub fn new_with_limit(program: Py<PyAny>, max_depth: Option<usize>) -> Self {
+        Self { stack: vec![(program, 0, None)], max_depth, types: None }
+    }
+
+    /// Create new iterator that only yields nodes whose `type` is in `types`
+    pub fn new_with_types(program: Py<PyAny>, types: Vec<String>) -> Self {
+        let mut iter = Self::new(program);
+        iter.types = Some(types.into_iter().collect());
+        iter
+    }
+
+    fn matches(&self, node: &Bound<'_, PyAny>) -> bool {
+        let Some(types) = &self.types else {
+            return true;
+        };
+        node.getattr("type")
+            .and_then(|t| t.extract::<String>())
+            .is_ok_and(|t| types.contains(&t))
+    }
+
+    /// Advance to the next (node, depth, parent) entry; the root has no parent.
+    fn next_entry(&mut self, py: Python) -> Option<(Py<PyAny>, usize, Option<Py<PyAny>>)> {
+        // Pop next node from the stack, skipping nodes filtered out by type
+        while let Some((node, depth, parent)) = self.stack.pop() {
+            // Push children unless they would exceed the depth limit, in
+            // reverse so the first child is visited next (pre-order)
+            if self.max_depth.is_none_or(|max| depth < max) {
+                let children = child_nodes(py, node.bind(py));
+                for child in children.into_iter().rev() {
+                    self.stack.push((child, depth + 1, Some(node.clone_ref(py))));
+                }
+            }
+
+            // Return current node with its depth and parent
+            if self.matches(node.bind(py)) {
+                return Some((node, depth, parent));
+            }
+        }
+
+        // Stack is empty, iteration complete
+        None
+    }
+}
+
+#[pymethods]
+impl WalkIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<(Py<PyAny>, usize)>> {
+        Ok(self.next_entry(py).map(|(node, depth, _parent)| (node, depth)))
+    }
+}
+
+/// Iterator that yields (node, depth, parent) triples.
+///
+/// Visits nodes in the same order as `walk()`. `parent` is the node whose
+/// attribute or list contained `node`, or None for the root.
+///
+/// Example:
+///     for node, depth, parent in walk_with_context(program):
+///         if node.type == "MemberExpression" and parent.type == "CallExpression":
+///             print("method call")
+#[pyclass]
+pub struct ContextWalkIterator {
+    inner: WalkIterator,
+}
+
+#[pymethods]
+impl ContextWalkIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> Option<(Py<PyAny>, usize, Option<Py<PyAny>>)> {
+        self.inner.next_entry(py)
+    }
+}
+
+/// Iterator that yields (node, depth) tuples in breadth-first (level) order.
+///
+/// Every node at depth N is yielded before any node at depth N+1, which makes
+/// it suitable for finding the shallowest occurrence of a node type.
+///
+/// Example:
+///     for node, depth in walk_breadth_first(program):
+///         if node.type == "CallExpression":
+///             break  # shallowest call
+#[pyclass]
+pub struct BFSWalkIterator {
+    /// FIFO queue of (node, depth) still to visit
+    queue: VecDeque<(Py<PyAny>, usize)>,
+}
+
+impl BFSWalkIterator {
     /// Create new iterator starting at program node with depth 0
     pub fn new(program: Py<PyAny>) -> Self {
         let mut queue = VecDeque::new();
@@ -38,142 +148,139 @@ impl WalkIterator {
 }
 
 #[pymethods]
-impl WalkIterator {
+impl BFSWalkIterator {
     fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
         slf
     }
 
     fn __next__(&mut self, py: Python) -> PyResult<Option<(Py<PyAny>, usize)>> {
-        // Pop next node from front of queue
-        if let Some((node, depth)) = self.queue.pop_front() {
-            // Get children of this node and add them to the queue
-            let node_ref = node.bind(py);
-
-            // Collect children to add (avoid borrow conflicts)
-            let mut children_to_add: Vec<(Py<PyAny>, usize)> = Vec::new();
-
-            // Single node attributes to traverse
-            // Note: 'body' is handled specially below since it can be a single node or list
-            let node_attrs = [
-                "init", "declaration", "function_body", "class_body", "value", "key",
-                "super_class", "consequent", "alternate", "test", "update", "discriminant",
-                "block", "handler", "finalizer", "param", "left", "right", "expression",
-                "callee", "object", "property", "argument", "quasi", "tag", "source",
-                "local", "imported", "exported", "type_annotation", "type_parameters",
-                "extends", "opening_element", "closing_element", "return_type", "id",
-                "constraint", "default", "initializer",
-            ];
-
-            for attr_name in node_attrs {
-                if let Ok(attr) = node_ref.getattr(attr_name) {
-                    if !attr.is_none() {
-                        // Only traverse if this is an AST node (has 'type' attribute)
-                        // This filters out primitive values like strings and numbers
-                        if let Ok(child) = attr.extract::<Py<PyAny>>() {
-                            let child_bound = child.bind(py);
-                            if child_bound.hasattr("type").unwrap_or(false) {
-                                children_to_add.push((child, depth + 1));
-                            }
-                        }
-                    }
-                }
-            }
+        let Some((node, depth)) = self.queue.pop_front() else {
+            return Ok(None);
+        };
 
-            // List attributes to traverse
-            let list_attrs = [
-                "statements", "declarations", "params", "methods", "decorators",
-                "cases", "arguments", "properties", "elements", "quasis", "expressions",
-                "specifiers", "members", "implements", "children", "attributes",
-            ];
-
-            for attr_name in list_attrs {
-                if let Ok(attr) = node_ref.getattr(attr_name) {
-                    if let Ok(list) = attr.extract::<Vec<Py<PyAny>>>() {
-                        for child in list {
-                            // Only traverse if this is an AST node (has 'type' attribute)
-                            let child_bound = child.bind(py);
-                            if child_bound.hasattr("type").unwrap_or(false) {
-                                children_to_add.push((child, depth + 1));
-                            }
-                        }
-                    }
-                }
-            }
+        // Children go to the back, behind everything already queued at this depth
+        for child in child_nodes(py, node.bind(py)) {
+            self.queue.push_back((child, depth + 1));
+        }
 
-            // 'body' is special - can be a single node (FunctionDeclaration.body = BlockStatement)
-            // or a list (Program.body = list[Statement], BlockStatement.body = list[Statement])
-            if let Ok(body) = node_ref.getattr("body") {
-                // Try as list first
-                if let Ok(list) = body.extract::<Vec<Py<PyAny>>>() {
-                    for child in list {
-                        let child_bound = child.bind(py);
-                        if child_bound.hasattr("type").unwrap_or(false) {
-                            children_to_add.push((child, depth + 1));
-                        }
-                    }
-                } else if !body.is_none() {
-                    // If not a list, try as single node
-                    if let Ok(child) = body.extract::<Py<PyAny>>() {
-                        let child_bound = child.bind(py);
-                        if child_bound.hasattr("type").unwrap_or(false) {
-                            children_to_add.push((child, depth + 1));
-                        }
-                    }
-                }
-            }
+        Ok(Some((node, depth)))
+    }
+}
 
-            // 'extends' can be both single node and list (TSInterfaceDeclaration)
-            if let Ok(extends) = node_ref.getattr("extends") {
-                if let Ok(list) = extends.extract::<Vec<Py<PyAny>>>() {
-                    for child in list {
-                        let child_bound = child.bind(py);
-                        if child_bound.hasattr("type").unwrap_or(false) {
-                            children_to_add.push((child, depth + 1));
-                        }
-                    }
-                }
-            }
+/// Collect the direct child AST nodes of a node, in source order.
+///
+/// Children are discovered by probing well-known attribute names; only values
+/// that have a `type` attribute (i.e. AST nodes) are returned. They are then
+/// sorted by span start, since attribute order doesn't follow the source
+/// (`ArrayPattern.rest` is probed before `elements`).
+pub(crate) fn child_nodes(py: Python, node_ref: &Bound<'_, PyAny>) -> Vec<Py<PyAny>> {
+    let mut children: Vec<Py<PyAny>> = Vec::new();
 
-            // 'consequent' can be both single node (IfStatement) and list (SwitchCase)
-            if let Ok(consequent) = node_ref.getattr("consequent") {
-                if let Ok(list) = consequent.extract::<Vec<Py<PyAny>>>() {
-                    for child in list {
-                        let child_bound = child.bind(py);
-                        if child_bound.hasattr("type").unwrap_or(false) {
-                            children_to_add.push((child, depth + 1));
-                        }
+    // Single node attributes to traverse
+    // Note: 'body' is handled specially below since it can be a single node or list
+    let node_attrs = [
+        "init", "declaration", "function_body", "class_body", "value", "key",
+        "super_class", "consequent", "alternate", "test", "update", "discriminant",
+        "block", "handler", "finalizer", "param", "left", "right", "expression",
+        "callee", "object", "property", "argument", "quasi", "tag", "source",
+        "local", "imported", "exported", "type_annotation", "type_parameters",
+        "extends", "opening_element", "closing_element", "return_type", "id",
+        "constraint", "default", "initializer", "check_type", "extends_type",
+        "true_type", "false_type", "type_parameter", "qualifier", "type_arguments",
+        "literal", "element_type", "rest", "options",
+    ];
+
+    for attr_name in node_attrs {
+        if let Ok(attr) = node_ref.getattr(attr_name) {
+            if !attr.is_none() {
+                // Only traverse if this is an AST node (has 'type' attribute)
+                // This filters out primitive values like strings and numbers
+                if let Ok(child) = attr.extract::<Py<PyAny>>() {
+                    let child_bound = child.bind(py);
+                    if child_bound.hasattr("type").unwrap_or(false) {
+                        children.push(child);
                     }
                 }
             }
+        }
+    }
+
+    // List attributes to traverse
+    let list_attrs = [
+        "statements", "declarations", "params", "decorators",
+        "cases", "arguments", "properties", "elements", "quasis", "expressions",
+        "specifiers", "members", "implements", "children", "attributes",
+        "element_types", "overloads", "types",
+    ];
 
-            // Check if node has 'name' attribute and traverse it (for JSX nodes only)
-            if let Ok(name) = node_ref.getattr("name") {
-                if !name.is_none() {
-                    if let Ok(name_node) = name.extract::<Py<PyAny>>() {
-                        let name_bound = name_node.bind(py);
-                        if let Ok(name_type) = name_bound.getattr("type") {
-                            if let Ok(type_str) = name_type.extract::<String>() {
-                                if type_str == "JSXIdentifier" || type_str == "JSXMemberExpression" {
-                                    children_to_add.push((name_node, depth + 1));
-                                }
-                            }
+    for attr_name in list_attrs {
+        if let Ok(attr) = node_ref.getattr(attr_name) {
+            children.extend(list_children(py, &attr));
+        }
+    }
+
+    // 'body' is special - can be a single node (FunctionDeclaration.body = BlockStatement)
+    // or a list (Program.body = list[Statement], BlockStatement.body = list[Statement])
+    if let Ok(body) = node_ref.getattr("body") {
+        if is_node(&body) {
+            children.push(body.unbind());
+        } else {
+            children.extend(list_children(py, &body));
+        }
+    }
+
+    // 'extends' can be both single node and list (TSInterfaceDeclaration)
+    // 'consequent' can be both single node (IfStatement) and list (SwitchCase)
+    // Single nodes were already collected via node_attrs above
+    for attr_name in ["extends", "consequent"] {
+        if let Ok(attr) = node_ref.getattr(attr_name) {
+            children.extend(list_children(py, &attr));
+        }
+    }
+
+    // Check if node has 'name' attribute and traverse it (for JSX nodes only)
+    if let Ok(name) = node_ref.getattr("name") {
+        if !name.is_none() {
+            if let Ok(name_node) = name.extract::<Py<PyAny>>() {
+                let name_bound = name_node.bind(py);
+                if let Ok(name_type) = name_bound.getattr("type") {
+                    if let Ok(type_str) = name_type.extract::<String>() {
+                        if type_str == "JSXIdentifier" || type_str == "JSXMemberExpression" {
+                            children.push(name_node);
                         }
                     }
                 }
             }
+        }
+    }
 
-            // Now add all collected children to the queue
-            for child in children_to_add {
-                self.queue.push_back(child);
-            }
+    // Stable sort, so children sharing a start keep attribute order
+    children.sort_by_key(|child| node_span(child.bind(py)).map_or(usize::MAX, |span| span.start));
+    children
+}
 
-            // Return current node with its depth
-            Ok(Some((node, depth)))
-        } else {
-            // Queue is empty, iteration complete
-            Ok(None)
-        }
+/// True if `value` is an AST node (has a `type` attribute).
+fn is_node(value: &Bound<'_, PyAny>) -> bool {
+    value.hasattr("type").unwrap_or(false)
+}
+
+/// AST nodes in a list-valued attribute.
+///
+/// Returns nothing for single nodes: container nodes such as BlockStatement
+/// support the sequence protocol but must not be flattened into their items.
+fn list_children(py: Python, value: &Bound<'_, PyAny>) -> Vec<Py<PyAny>> {
+    if value.is_none() || is_node(value) {
+        return Vec::new();
     }
+    value
+        .extract::<Vec<Py<PyAny>>>()
+        .map(|list| list.into_iter().filter(|child| is_node(child.bind(py))).collect())
+        .unwrap_or_default()
+}
+
+/// Read a node's `span` attribute, if it has one.
+pub(crate) fn node_span(node: &Bound<'_, PyAny>) -> Option<Span> {
+    node.getattr("span").ok()?.extract::<Span>().ok()
 }
 
 /// Walk AST in depth-first, pre-order traversal.
@@ -186,6 +293,7 @@ impl WalkIterator {
 ///
 /// Args:
 ///     program: Root Program node to start traversal
+///     max_depth: Optional depth limit; nodes deeper than this are not visited
 ///
 /// Returns:
 ///     Iterator yielding (node, depth) tuples
@@ -197,6 +305,877 @@ impl WalkIterator {
 ///     Program
 ///       FunctionDeclaration
 #[pyfunction]
-pub fn walk(program: Py<PyAny>) -> PyResult<WalkIterator> {
-    Ok(WalkIterator::new(program))
+#[pyo3(signature = (program, max_depth=None))]
+pub fn walk(program: Py<PyAny>, max_depth: Option<usize>) -> PyResult<WalkIterator> {
+    Ok(WalkIterator::new_with_limit(program, max_depth))
+}
+
+/// Walk AST with parent context.
+///
+/// Like `walk()`, but yields (node, depth, parent) triples so callers can
+/// check the enclosing node while matching patterns.
+///
+/// Args:
+///     program: Root Program node to start traversal
+///
+/// Returns:
+///     ContextWalkIterator yielding (node, depth, parent) tuples
+///
+/// Example:
+///     >>> result = oxc_python.parse("a.b()")
+///     >>> for node, depth, parent in oxc_python.walk_with_context(result.program):
+///     ...     print(node.type, parent.type if parent else None)
+///     Program None
+///     ExpressionStatement Program
+///     CallExpression ExpressionStatement
+#[pyfunction]
+pub fn walk_with_context(program: Py<PyAny>) -> PyResult<ContextWalkIterator> {
+    Ok(ContextWalkIterator { inner: WalkIterator::new(program) })
+}
+
+/// Walk AST in breadth-first (level) order.
+///
+/// Yields (node, depth) tuples; all nodes at one depth are yielded before
+/// any node at the next depth.
+///
+/// Args:
+///     program: Root Program node to start traversal
+///
+/// Returns:
+///     Iterator yielding (node, depth) tuples
+///
+/// Example:
+///     >>> depths = [d for _, d in oxc_python.walk_breadth_first(result.program)]
+///     >>> depths == sorted(depths)
+///     True
+#[pyfunction]
+pub fn walk_breadth_first(program: Py<PyAny>) -> PyResult<BFSWalkIterator> {
+    Ok(BFSWalkIterator::new(program))
+}
+
+/// Walk only the Program and its top-level statements.
+///
+/// Equivalent to `walk(program, max_depth=1)`.
+///
+/// Example:
+///     >>> for node, depth in oxc_python.walk_top_level(result.program):
+///     ...     print(depth, node.type)
+///     0 Program
+///     1 FunctionDeclaration
+#[pyfunction]
+pub fn walk_top_level(program: Py<PyAny>) -> PyResult<WalkIterator> {
+    Ok(WalkIterator::new_with_limit(program, Some(1)))
+}
+
+/// Walk AST yielding only nodes of the given types.
+///
+/// The whole tree is still traversed so nested matches are found, but the
+/// type filter runs in Rust and non-matching nodes never reach Python.
+///
+/// Args:
+///     program: Root Program node to start traversal
+///     types: Node type names to yield (e.g. ["FunctionDeclaration"])
+///
+/// Returns:
+///     Iterator yielding (node, depth) tuples for matching nodes
+///
+/// Example:
+///     >>> for node, depth in oxc_python.walk_types(result.program, ["CallExpression"]):
+///     ...     print(node.span)
+#[pyfunction]
+pub fn walk_types(program: Py<PyAny>, types: Vec<String>) -> PyResult<WalkIterator> {
+    Ok(WalkIterator::new_with_types(program, types))
+}
+
+/// Every node of `type_name` under `root` (including `root`), in walk order.
+pub(crate) fn nodes_of_type(py: Python, root: Py<PyAny>, type_name: &str) -> Vec<Py<PyAny>> {
+    let mut iter = WalkIterator::new_with_types(root, vec![type_name.to_string()]);
+    let mut nodes = Vec::new();
+    while let Some((node, _, _)) = iter.next_entry(py) {
+        nodes.push(node);
+    }
+    nodes
+}
+
+/// Find the innermost AST node whose span contains a byte offset.
+///
+/// Spans are half-open, so a node contains `offset` when
+/// `span.start <= offset < span.end`. Useful for hover/completion style lookups.
+///
+/// Args:
+///     program: Root Program node to search
+///     offset: Byte offset into the source
+///
+/// Returns:
+///     The deepest node containing the offset, or None if no node does
+///
+/// Example:
+///     >>> result = oxc_python.parse("const foo = bar + 42;")
+///     >>> oxc_python.node_at_offset(result.program, 12).name
+///     'bar'
+#[pyfunction]
+pub fn node_at_offset(py: Python, program: Py<PyAny>, offset: usize) -> PyResult<Option<Py<PyAny>>> {
+    let mut walker = WalkIterator::new(program);
+    let mut innermost: Option<(Py<PyAny>, usize)> = None;
+
+    while let Some((node, depth)) = walker.__next__(py)? {
+        let Some(span) = node_span(node.bind(py)) else { continue };
+        if span.start <= offset && offset < span.end
+            && innermost.as_ref().is_none_or(|(_, best)| depth >= *best)
+        {
+            innermost = Some((node, depth));
+        }
+    }
+
+    Ok(innermost.map(|(node, _)| node))
+}
+
+/// Collect all AST nodes whose spans overlap a byte range.
+///
+/// A node overlaps `[start, end)` when `span.start < end && span.end > start`.
+/// Subtrees of non-overlapping nodes are skipped. Useful for range formatting
+/// and range extraction.
+///
+/// Args:
+///     program: Root Program node to search
+///     start: Start byte offset of the range (inclusive)
+///     end: End byte offset of the range (exclusive)
+///
+/// Returns:
+///     Overlapping nodes in pre-order (parents before children)
+///
+/// Example:
+///     >>> result = oxc_python.parse("const a = 1;\nfoo(a);")
+///     >>> [n.type for n in oxc_python.nodes_in_range(result.program, 13, 20)][:2]
+///     ['Program', 'ExpressionStatement']
+#[pyfunction]
+pub fn nodes_in_range(py: Python, program: Py<PyAny>, start: usize, end: usize) -> PyResult<Vec<Py<PyAny>>> {
+    let mut found = Vec::new();
+    let mut stack = vec![program];
+
+    while let Some(node) = stack.pop() {
+        let node_ref = node.bind(py);
+        let overlaps = node_span(node_ref).is_none_or(|span| span.start < end && span.end > start);
+        if !overlaps {
+            continue;
+        }
+
+        // Push children in reverse so they pop in source order
+        let children = child_nodes(py, node_ref);
+        stack.extend(children.into_iter().rev());
+        if node_span(node_ref).is_some() {
+            found.push(node);
+        }
+    }
+
+    Ok(found)
+}
+
+/// Find the chain of nodes from the root down to the node starting at a byte offset.
+///
+/// Descends through the children whose spans contain `target_start`. When
+/// several nested nodes start at the offset (e.g. an ExpressionStatement and
+/// its CallExpression), the path ends at the innermost one.
+///
+/// Args:
+///     program: Root Program node to search
+///     target_start: Byte offset where the target node starts
+///
+/// Returns:
+///     Nodes from the root (first) to the target (last), or an empty list if
+///     no node starts at `target_start`
+///
+/// Example:
+///     >>> result = oxc_python.parse("function f() { return a + 1; }")
+///     >>> [n.type for n in oxc_python.node_path_to(result.program, 26)]
+///     ['Program', 'FunctionDeclaration', 'BlockStatement', 'ReturnStatement', 'BinaryExpression', 'Literal']
+#[pyfunction]
+pub fn node_path_to(py: Python, program: Py<PyAny>, target_start: usize) -> PyResult<Vec<Py<PyAny>>> {
+    let contains = |node: &Bound<'_, PyAny>| {
+        node_span(node).is_some_and(|span| {
+            span.start == target_start || (span.start < target_start && target_start < span.end)
+        })
+    };
+
+    let mut path = vec![program];
+    // Length of `path` when it last ended at a node starting at the target
+    let mut matched_len = 0;
+
+    loop {
+        let current = path.last().expect("path starts with the root").bind(py);
+        if node_span(current).is_some_and(|span| span.start == target_start) {
+            matched_len = path.len();
+        }
+        let next = child_nodes(py, current)
+            .into_iter()
+            .find(|child| contains(child.bind(py)));
+        match next {
+            Some(child) => path.push(child),
+            None => break,
+        }
+    }
+
+    path.truncate(matched_len);
+    Ok(path)
+}
+
+/// Walk the AST calling `enter(node, depth)` before and `exit(node, depth)` after
+/// each node's children.
+///
+/// Nodes are entered in depth-first pre-order, the same order `walk()` yields
+/// them. If `enter` returns a falsy value other than None (e.g. `False`), the
+/// node's children are skipped; `exit` is still called for that node, so every
+/// `enter` is paired with an `exit`.
+///
+/// Args:
+///     program: Root Program node to start traversal
+///     enter: Optional callable invoked as `enter(node, depth)` on the way down
+///     exit: Optional callable invoked as `exit(node, depth)` on the way up
+///
+/// Example:
+///     >>> def enter(node, depth):
+///     ...     if node.type == "FunctionDeclaration":
+///     ...         print(node.name)
+///     ...         return False  # don't descend into nested functions
+///     >>> oxc_python.visit(result.program, enter=enter)
+#[pyfunction]
+#[pyo3(signature = (program, enter=None, exit=None))]
+pub fn visit(py: Python, program: Py<PyAny>, enter: Option<Py<PyAny>>, exit: Option<Py<PyAny>>) -> PyResult<()> {
+    // (node, depth, children_pushed): a node is revisited for `exit` once its children are done
+    let mut stack: Vec<(Py<PyAny>, usize, bool)> = vec![(program, 0, false)];
+
+    while let Some((node, depth, children_pushed)) = stack.pop() {
+        if children_pushed {
+            if let Some(exit) = &exit {
+                exit.call1(py, (node, depth))?;
+            }
+            continue;
+        }
+
+        let descend = match &enter {
+            Some(enter) => {
+                let result = enter.bind(py).call1((node.clone_ref(py), depth))?;
+                result.is_none() || result.is_truthy()?
+            }
+            None => true,
+        };
+
+        let children = if descend { child_nodes(py, node.bind(py)) } else { Vec::new() };
+        stack.push((node, depth, true));
+        // Push children in reverse so they pop in source order
+        stack.extend(children.into_iter().rev().map(|child| (child, depth + 1, false)));
+    }
+
+    Ok(())
+}
+
+/// Tally node types over the whole tree in Rust.
+fn count_node_types(py: Python, program: Py<PyAny>) -> PyResult<HashMap<String, usize>> {
+    let mut walker = WalkIterator::new(program);
+    let mut counts: HashMap<String, usize> = HashMap::new();
+
+    while let Some((node, _depth)) = walker.__next__(py)? {
+        if let Ok(node_type) = node.bind(py).getattr("type").and_then(|t| t.extract::<String>()) {
+            *counts.entry(node_type).or_insert(0) += 1;
+        }
+    }
+
+    Ok(counts)
+}
+
+/// Count every node in the tree, including the root.
+///
+/// Args:
+///     program: Root Program node to count
+///
+/// Returns:
+///     Total number of nodes yielded by `walk(program)`
+///
+/// Example:
+///     >>> result = oxc_python.parse("foo(1);")
+///     >>> oxc_python.node_count(result.program)
+///     5
+#[pyfunction]
+pub fn node_count(py: Python, program: Py<PyAny>) -> PyResult<usize> {
+    Ok(count_node_types(py, program)?.values().sum())
+}
+
+/// Count nodes by type.
+///
+/// Counts are accumulated in Rust and converted to a dict once, so this is
+/// much cheaper than tallying `walk()` output from Python.
+///
+/// Args:
+///     program: Root Program node to count
+///
+/// Returns:
+///     Dict mapping node type name to number of occurrences
+///
+/// Example:
+///     >>> result = oxc_python.parse("foo(1);")
+///     >>> oxc_python.node_type_histogram(result.program)["Identifier"]
+///     1
+#[pyfunction]
+pub fn node_type_histogram(py: Python, program: Py<PyAny>) -> PyResult<Py<PyDict>> {
+    let dict = PyDict::new(py);
+    for (node_type, count) in count_node_types(py, program)? {
+        dict.set_item(node_type, count)?;
+    }
+    Ok(dict.unbind())
+}
+
+/// Cyclomatic complexity of a function body: 1 plus one per decision point.
+///
+/// Decision points are branches and loops, non-default `case` clauses,
+/// `catch` clauses, ternaries, and `&&`/`||` operators. Nested functions,
+/// arrow functions, and classes in the body have their own complexity and
+/// are not descended into.
+pub(crate) fn cyclomatic_complexity(py: Python, body: Option<&Py<PyAny>>) -> PyResult<usize> {
+    let Some(body) = body else {
+        return Ok(1);
+    };
+    let mut stack = vec![body.clone_ref(py)];
+    let mut complexity = 1;
+
+    while let Some(node) = stack.pop() {
+        let node = node.bind(py);
+        let node_type = node.getattr("type")
+            .and_then(|t| t.extract::<String>())
+            .unwrap_or_default();
+        if matches!(
+            node_type.as_str(),
+            "FunctionDeclaration" | "ArrowFunctionExpression" | "MethodDefinition" | "ClassDeclaration"
+        ) {
+            continue;
+        }
+        stack.extend(child_nodes(py, node));
+        let is_decision = match node_type.as_str() {
+            "IfStatement" | "WhileStatement" | "DoWhileStatement" | "ForStatement"
+            | "ForInStatement" | "ForOfStatement" | "ConditionalExpression" | "CatchClause" => true,
+            "SwitchCase" => !node.getattr("is_default")?.extract::<bool>()?,
+            "LogicalExpression" => node.getattr("operator")
+                .and_then(|op| op.extract::<String>())
+                .is_ok_and(|op| op == "&&" || op == "||"),
+            _ => false,
+        };
+        if is_decision {
+            complexity += 1;
+        }
+    }
+
+    Ok(complexity)
+}
+
+// =============================================================================
+// Function Signature Extraction
+// =============================================================================
+
+/// Structured summary of a function's signature.
+///
+/// Produced by `extract_function_signatures()` for FunctionDeclaration,
+/// ArrowFunctionExpression, and MethodDefinition nodes.
+#[pyclass]
+pub struct FunctionSignature {
+    /// Function or method name (None for anonymous/arrow functions)
+    #[pyo3(get)]
+    pub name: Option<String>,
+
+    /// Parameter names ("...name" for rest parameters, None for destructured parameters)
+    #[pyo3(get)]
+    pub params: Vec<Option<String>>,
+
+    /// Type annotation text per parameter (None when unannotated or no source given)
+    #[pyo3(get)]
+    pub param_types: Vec<Option<String>>,
+
+    /// Return type annotation text (None when unannotated or no source given)
+    #[pyo3(get)]
+    pub return_type: Option<String>,
+
+    #[pyo3(get)]
+    pub is_async: bool,
+
+    #[pyo3(get)]
+    pub is_generator: bool,
+
+    #[pyo3(get)]
+    pub is_arrow: bool,
+
+    /// Source location of the function node
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// End line number (1-indexed)
+    #[pyo3(get)]
+    pub end_line: usize,
+}
+
+#[pymethods]
+impl FunctionSignature {
+    fn __repr__(&self) -> String {
+        format!(
+            "FunctionSignature(name={:?}, params={:?}, return_type={:?}, lines={}..{})",
+            self.name, self.params, self.return_type, self.start_line, self.end_line
+        )
+    }
+}
+
+/// Source text of a type annotation node, without the leading `:`.
+///
+/// Accepts a TSTypeAnnotation wrapper or a bare type node.
+fn annotation_text(annotation: &Bound<'_, PyAny>, source: Option<&str>) -> Option<String> {
+    let source = source?;
+    if annotation.is_none() {
+        return None;
+    }
+    let inner = annotation.getattr("type_annotation").ok()
+        .filter(|inner| !inner.is_none())
+        .unwrap_or_else(|| annotation.clone());
+    let span = node_span(&inner)?;
+    source.get(span.start..span.end).map(|text| text.trim_start_matches(':').trim().to_string())
+}
+
+fn bool_attr(node: &Bound<'_, PyAny>, attr: &str) -> bool {
+    node.getattr(attr).and_then(|v| v.extract::<bool>()).unwrap_or(false)
+}
+
+/// Line range for a node: its own `start_line`/`end_line`, else computed from source.
+fn line_range(node: &Bound<'_, PyAny>, span: Span, source: Option<&str>) -> (usize, usize) {
+    let lines = node.getattr("start_line").and_then(|s| s.extract::<usize>())
+        .and_then(|start| Ok((start, node.getattr("end_line")?.extract::<usize>()?)));
+    match (lines, source) {
+        (Ok(lines), _) => lines,
+        (Err(_), Some(source)) => (
+            crate::compute_line_number(source, span.start),
+            crate::compute_line_number(source, span.end),
+        ),
+        (Err(_), None) => (0, 0),
+    }
+}
+
+fn function_signature(
+    py: Python,
+    node: &Bound<'_, PyAny>,
+    kind: &str,
+    source: Option<&str>,
+) -> PyResult<Py<FunctionSignature>> {
+    let span = node_span(node).unwrap_or(Span { start: 0, end: 0 });
+    let name = node.getattr("name").ok()
+        .and_then(|n| n.extract::<Option<String>>().ok())
+        .flatten();
+
+    let mut params = Vec::new();
+    let mut param_types = Vec::new();
+    for param in node.getattr("params")?.extract::<Vec<Py<PyAny>>>()? {
+        let mut param = param.bind(py).clone();
+        // `...args` is a RestElement wrapping the parameter
+        let is_rest = param.getattr("type").and_then(|t| t.extract::<String>()).is_ok_and(|t| t == "RestElement");
+        if is_rest {
+            param = param.getattr("argument")?;
+        }
+        let param_name = param.getattr("name").ok()
+            .and_then(|n| n.extract::<Option<String>>().ok())
+            .flatten();
+        params.push(if is_rest { param_name.map(|name| format!("...{}", name)) } else { param_name });
+        param_types.push(
+            param.getattr("type_annotation").ok()
+                .and_then(|ta| annotation_text(&ta, source)),
+        );
+    }
+
+    let return_type = node.getattr("return_type").ok()
+        .and_then(|rt| annotation_text(&rt, source));
+    let (start_line, end_line) = line_range(node, span, source);
+
+    Py::new(py, FunctionSignature {
+        name,
+        params,
+        param_types,
+        return_type,
+        is_async: bool_attr(node, "is_async"),
+        is_generator: bool_attr(node, "is_generator"),
+        is_arrow: kind == "ArrowFunctionExpression",
+        span,
+        start_line,
+        end_line,
+    })
+}
+
+/// Collect signatures for all functions, arrow functions, and methods.
+///
+/// Type annotations and line numbers for nodes without them are read from
+/// `source`; when it is omitted, `param_types` and `return_type` are None.
+///
+/// Args:
+///     program: Root Program node to search
+///     source: Optional original source code, used for type annotation text
+///
+/// Returns:
+///     FunctionSignature records in traversal order
+///
+/// Example:
+///     >>> source = "async function load(id: string): Promise<User> {}"
+///     >>> result = oxc_python.parse(source, source_type="ts")
+///     >>> sig = oxc_python.extract_function_signatures(result.program, source)[0]
+///     >>> sig.name, sig.params, sig.param_types, sig.return_type
+///     ('load', ['id'], ['string'], 'Promise<User>')
+#[pyfunction]
+#[pyo3(signature = (program, source=None))]
+pub fn extract_function_signatures(
+    py: Python,
+    program: Py<PyAny>,
+    source: Option<&str>,
+) -> PyResult<Vec<Py<FunctionSignature>>> {
+    let mut walker = WalkIterator::new(program);
+    let mut signatures = Vec::new();
+
+    while let Some((node, _depth)) = walker.__next__(py)? {
+        let node = node.bind(py);
+        let kind = node.getattr("type")
+            .and_then(|t| t.extract::<String>())
+            .unwrap_or_default();
+        if matches!(kind.as_str(), "FunctionDeclaration" | "ArrowFunctionExpression" | "MethodDefinition") {
+            signatures.push(function_signature(py, node, &kind, source)?);
+        }
+    }
+
+    Ok(signatures)
+}
+
+// =============================================================================
+// Class Hierarchy Extraction
+// =============================================================================
+
+/// Summary of a class's inheritance and members.
+///
+/// Produced by `extract_class_info()` for class declarations and class expressions.
+#[pyclass]
+pub struct ClassInfo {
+    /// Class name (None for anonymous class expressions)
+    #[pyo3(get)]
+    pub name: Option<String>,
+
+    /// Superclass name (None if no extends)
+    #[pyo3(get)]
+    pub superclass: Option<String>,
+
+    /// Interface names from a TypeScript `implements` clause
+    #[pyo3(get)]
+    pub implements: Vec<String>,
+
+    /// Method names, in source order
+    #[pyo3(get)]
+    pub methods: Vec<String>,
+
+    /// Source location of the class node
+    #[pyo3(get)]
+    pub span: Span,
+}
+
+#[pymethods]
+impl ClassInfo {
+    fn __repr__(&self) -> String {
+        format!(
+            "ClassInfo(name={:?}, superclass={:?}, implements={:?}, methods={:?})",
+            self.name, self.superclass, self.implements, self.methods
+        )
+    }
+}
+
+fn class_info(py: Python, node: &Bound<'_, PyAny>) -> PyResult<ClassInfo> {
+    let name = node.getattr("name")?.extract::<Option<String>>()?;
+    let superclass = node.getattr("superclass")?.extract::<Option<String>>()?;
+    let implements = node.getattr("implements")?.extract::<Vec<String>>()?;
+
+    let mut methods = Vec::new();
+    let body = node.getattr("body")?;
+    if !body.is_none() {
+        for member in body.getattr("body")?.extract::<Vec<Py<PyAny>>>()? {
+            let member = member.bind(py);
+            let is_method = member.getattr("type")
+                .and_then(|t| t.extract::<String>())
+                .is_ok_and(|t| t == "MethodDefinition" || t == "TSAbstractMethodDefinition");
+            if let (true, Some(method_name)) = (is_method, string_attr(member, "name")) {
+                methods.push(method_name);
+            }
+        }
+    }
+
+    Ok(ClassInfo {
+        name,
+        superclass,
+        implements,
+        methods,
+        span: node_span(node).unwrap_or(Span { start: 0, end: 0 }),
+    })
+}
+
+/// Walk the tree and summarize every class, in traversal order.
+fn collect_classes(py: Python, program: Py<PyAny>) -> PyResult<Vec<ClassInfo>> {
+    let mut walker = WalkIterator::new(program);
+    let mut classes = Vec::new();
+
+    while let Some((node, _depth)) = walker.__next__(py)? {
+        let node = node.bind(py);
+        let is_class = node.getattr("type")
+            .and_then(|t| t.extract::<String>())
+            .is_ok_and(|t| t == "ClassDeclaration");
+        if is_class {
+            classes.push(class_info(py, node)?);
+        }
+    }
+
+    Ok(classes)
+}
+
+/// Map each named class to its superclass name.
+///
+/// Class expressions are converted to ClassDeclaration nodes, so both forms
+/// are included. Anonymous classes are skipped.
+///
+/// Args:
+///     program: Root Program node to search
+///
+/// Returns:
+///     Dict of class name to superclass name (None when the class has no extends)
+///
+/// Example:
+///     >>> result = oxc_python.parse("class A {} class B extends A {}")
+///     >>> oxc_python.extract_class_hierarchy(result.program)
+///     {'A': None, 'B': 'A'}
+#[pyfunction]
+pub fn extract_class_hierarchy(py: Python, program: Py<PyAny>) -> PyResult<Py<PyDict>> {
+    let dict = PyDict::new(py);
+    for class in collect_classes(py, program)? {
+        if let Some(name) = class.name {
+            dict.set_item(name, class.superclass)?;
+        }
+    }
+    Ok(dict.unbind())
+}
+
+/// Collect inheritance and method information for all classes.
+///
+/// Args:
+///     program: Root Program node to search
+///
+/// Returns:
+///     ClassInfo records in traversal order
+///
+/// Example:
+///     >>> source = "class C extends B implements I { run() {} }"
+///     >>> result = oxc_python.parse(source, source_type="ts")
+///     >>> info = oxc_python.extract_class_info(result.program)[0]
+///     >>> info.name, info.superclass, info.implements, info.methods
+///     ('C', 'B', ['I'], ['run'])
+#[pyfunction]
+pub fn extract_class_info(py: Python, program: Py<PyAny>) -> PyResult<Vec<Py<ClassInfo>>> {
+    collect_classes(py, program)?
+        .into_iter()
+        .map(|class| Py::new(py, class))
+        .collect()
+}
+
+// =============================================================================
+// TypeScript Name Extraction
+// =============================================================================
+
+/// Walk the tree and collect unique names from nodes of one type, in walk order.
+fn collect_unique_names(
+    py: Python,
+    program: Py<PyAny>,
+    node_type: &str,
+    name_of: impl Fn(&Bound<'_, PyAny>) -> Option<String>,
+) -> PyResult<Vec<String>> {
+    let mut walker = WalkIterator::new(program);
+    let mut names: Vec<String> = Vec::new();
+
+    while let Some((node, _depth)) = walker.__next__(py)? {
+        let node = node.bind(py);
+        let is_match = node.getattr("type")
+            .and_then(|t| t.extract::<String>())
+            .is_ok_and(|t| t == node_type);
+        if !is_match {
+            continue;
+        }
+        if let Some(name) = name_of(node) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+
+    Ok(names)
+}
+
+fn string_attr(node: &Bound<'_, PyAny>, attr: &str) -> Option<String> {
+    node.getattr(attr).ok()?.extract::<String>().ok()
+}
+
+/// Collect the unique type names referenced in TypeScript type annotations.
+///
+/// Qualified references (`Foo.Bar`) are returned as a single dotted name.
+///
+/// Args:
+///     program: Root Program node to search
+///
+/// Returns:
+///     Unique referenced type names, in traversal order
+///
+/// Example:
+///     >>> result = oxc_python.parse("let a: User; let b: Map<string, User>;", source_type="ts")
+///     >>> oxc_python.extract_ts_type_names(result.program)
+///     ['User', 'Map']
+#[pyfunction]
+pub fn extract_ts_type_names(py: Python, program: Py<PyAny>) -> PyResult<Vec<String>> {
+    collect_unique_names(py, program, "TSTypeReference", |node| {
+        let type_name = node.getattr("type_name").ok()?;
+        string_attr(&type_name, "name")
+    })
+}
+
+/// Collect the names of all declared TypeScript interfaces.
+///
+/// Args:
+///     program: Root Program node to search
+///
+/// Returns:
+///     Unique interface names, in traversal order
+#[pyfunction]
+pub fn extract_ts_interface_names(py: Python, program: Py<PyAny>) -> PyResult<Vec<String>> {
+    collect_unique_names(py, program, "TSInterfaceDeclaration", |node| string_attr(node, "name"))
+}
+
+/// Collect the names of all declared TypeScript type aliases.
+///
+/// Args:
+///     program: Root Program node to search
+///
+/// Returns:
+///     Unique type alias names, in traversal order
+#[pyfunction]
+pub fn extract_ts_type_alias_names(py: Python, program: Py<PyAny>) -> PyResult<Vec<String>> {
+    collect_unique_names(py, program, "TSTypeAliasDeclaration", |node| string_attr(node, "name"))
+}
+
+// =============================================================================
+// Dependency Collection
+// =============================================================================
+
+/// A module dependency found in the source.
+///
+/// Produced by `collect_dependencies()`.
+#[pyclass]
+pub struct Dependency {
+    /// Module path as written ("react", "./utils")
+    #[pyo3(get)]
+    pub specifier: String,
+
+    /// "static" (import declaration), "dynamic" (import()), "reexport"
+    /// (`export ... from`), or "require"
+    #[pyo3(get)]
+    pub kind: String,
+
+    /// Source location of the import declaration or call
+    #[pyo3(get)]
+    pub span: Span,
+
+    /// Start line number (1-indexed)
+    #[pyo3(get)]
+    pub start_line: usize,
+
+    /// True for `import type` declarations
+    #[pyo3(get)]
+    pub type_only: bool,
+}
+
+#[pymethods]
+impl Dependency {
+    fn __repr__(&self) -> String {
+        format!(
+            "Dependency(specifier='{}', kind='{}', line={})",
+            self.specifier, self.kind, self.start_line
+        )
+    }
+}
+
+/// String value of a Literal node, if it is a string literal.
+fn string_literal_value(node: &Bound<'_, PyAny>) -> Option<String> {
+    if node.is_none() {
+        return None;
+    }
+    node.getattr("value").ok()?.extract::<String>().ok()
+}
+
+/// Classify a node as a dependency: (specifier, kind, type_only).
+fn dependency_of(py: Python, node: &Bound<'_, PyAny>) -> Option<(String, &'static str, bool)> {
+    let node_type = node.getattr("type").ok()?.extract::<String>().ok()?;
+    match node_type.as_str() {
+        "ImportDeclaration" => {
+            let specifier = string_literal_value(&node.getattr("source").ok()?)?;
+            let type_only = string_attr(node, "import_kind").is_some_and(|kind| kind == "type");
+            Some((specifier, "static", type_only))
+        }
+        "ImportExpression" => {
+            let specifier = string_literal_value(&node.getattr("source").ok()?)?;
+            Some((specifier, "dynamic", false))
+        }
+        "ExportNamedDeclaration" | "ExportAllDeclaration" => {
+            let specifier = string_literal_value(&node.getattr("source").ok()?)?;
+            Some((specifier, "reexport", false))
+        }
+        "CallExpression" => {
+            let callee = node.getattr("callee").ok()?;
+            let is_require = callee.getattr("type").ok()?.extract::<String>().ok()? == "Identifier"
+                && string_attr(&callee, "name").is_some_and(|name| name == "require");
+            if !is_require {
+                return None;
+            }
+            let arguments = node.getattr("arguments").ok()?.extract::<Vec<Py<PyAny>>>().ok()?;
+            let specifier = string_literal_value(arguments.first()?.bind(py))?;
+            Some((specifier, "require", false))
+        }
+        _ => None,
+    }
+}
+
+/// Collect module dependencies: static imports, dynamic `import()`,
+/// re-exports (`export ... from`), and `require()`.
+///
+/// Only string-literal specifiers are reported; computed ones such as
+/// `import(name)` are skipped.
+///
+/// Args:
+///     program: Root Program node to search
+///     source: Optional original source code, used for line numbers of
+///         expression nodes that don't carry their own
+///
+/// Returns:
+///     Dependency records in source order
+///
+/// Example:
+///     >>> result = oxc_python.parse("import a from 'a'; const b = require('b');")
+///     >>> [(d.specifier, d.kind) for d in oxc_python.collect_dependencies(result.program)]
+///     [('a', 'static'), ('b', 'require')]
+#[pyfunction]
+#[pyo3(signature = (program, source=None))]
+pub fn collect_dependencies(py: Python, program: Py<PyAny>, source: Option<&str>) -> PyResult<Vec<Py<Dependency>>> {
+    let mut walker = WalkIterator::new(program);
+    let mut found = Vec::new();
+
+    while let Some((node, _depth)) = walker.__next__(py)? {
+        let node = node.bind(py);
+        if let Some((specifier, kind, type_only)) = dependency_of(py, node) {
+            let span = node_span(node).unwrap_or(Span { start: 0, end: 0 });
+            let (start_line, _) = line_range(node, span, source);
+            found.push(Dependency { specifier, kind: kind.to_string(), span, start_line, type_only });
+        }
+    }
+
+    // walk() is pre-order over source-ordered children, so `found` is in source order
+    found.into_iter().map(|dep| Py::new(py, dep)).collect()
 }
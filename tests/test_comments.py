@@ -517,3 +517,55 @@ async function initialize() {
     # Verify JSDoc comments
     jsdoc_comments = [c for c in result.comments if "@" in c.text]
     assert len(jsdoc_comments) >= 2
+
+
+def test_comment_line_and_column_positions():
+    """Test comments carry 1-indexed lines and 0-indexed columns."""
+    import oxc_python
+
+    source = "const a = 1;\nconst b = 2; // trailing\n  /* block\n     comment */\n"
+    result = oxc_python.parse(source)
+
+    trailing, block = result.comments
+    assert trailing.start_line == 2
+    assert trailing.end_line == 2
+    assert trailing.start_col == 13
+    assert trailing.end_col == 24
+
+    assert block.start_line == 3
+    assert block.end_line == 4
+    assert block.start_col == 2
+    assert block.end_col == 15
+
+
+def test_hashbang_not_extracted_as_comment():
+    """Test a shebang line is exposed as Program.hashbang, not as a comment."""
+    import oxc_python
+
+    source = "#!/usr/bin/env node --opt=//x\nconst x = 1; // real"
+    result = oxc_python.parse(source)
+
+    assert result.program.hashbang == "/usr/bin/env node --opt=//x"
+    assert [c.text for c in result.comments] == [" real"]
+
+    assert oxc_python.parse("const x = 1;").program.hashbang is None
+
+
+def test_comment_delimiters_in_literals_ignored():
+    """Test // and /* inside strings, templates, and regexes are not comments."""
+    import oxc_python
+
+    source = (
+        'const s = "not // a comment";\n'
+        "const t = `a //* b ${1 /* real */} c`;\n"
+        "const r = /hello\\/*/;\n"
+        "const q = 'it\\'s // still a string'; // trailing\n"
+    )
+    result = oxc_python.parse(source)
+
+    assert result.is_valid
+    assert [(c.text, c.is_block) for c in result.comments] == [(" real ", True), (" trailing", False)]
+
+    real = result.comments[0]
+    assert source[real.span.start:real.span.end] == "/* real */"
+    assert real.start_line == 2
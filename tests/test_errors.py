@@ -130,6 +130,51 @@ def test_error_severity_levels():
     assert any(e.severity == "error" for e in result.errors)
 
 
+def test_parse_error_span_points_at_error():
+    """RED: Verify error span comes from the diagnostic label, not 0..0."""
+    from oxc_python import parse
+
+    source = "const = 5;"
+    result = parse(source, source_type="module")
+
+    assert len(result.errors) > 0
+    error = result.errors[0]
+    assert error.span.start == 6
+    assert error.span.end == 7
+    assert source[error.span.start : error.span.end] == "="
+
+
+def test_parse_error_code():
+    """RED: Verify diagnostic codes are exposed when oxc provides one."""
+    from oxc_python import parse
+
+    source = "class A extends B extends C {}"
+    result = parse(source, source_type="ts")
+
+    codes = [e.code for e in result.errors]
+    assert "TS(1172)" in codes
+
+    # Plain syntax errors carry no code
+    result = parse("const = 5;", source_type="module")
+    assert result.errors[0].code is None
+
+
+def test_parse_error_to_dict():
+    """RED: Verify ParseError.to_dict() includes location, severity and code."""
+    from oxc_python import parse
+
+    result = parse("const = 5;", source_type="module")
+    data = result.errors[0].to_dict()
+
+    assert data == {
+        "message": "Unexpected token",
+        "start": 6,
+        "end": 7,
+        "severity": "error",
+        "code": None,
+    }
+
+
 # ChunkHound Validation
 def test_chunkhound_error_fallback_pattern():
     """
@@ -249,3 +294,50 @@ def test_panicked_flag():
     assert len(result.errors) > 0
     # Most syntax errors should not cause panic
     # (panicked is for internal parser failures)
+
+
+def test_filename_propagates_to_errors():
+    """Test that the filename passed to parse() is recorded on errors."""
+    from oxc_python import parse
+
+    result = parse("const =", filename="src/foo.ts")
+
+    assert result.source_file == "src/foo.ts"
+    assert len(result.errors) > 0
+    assert result.errors[0].source_file == "src/foo.ts"
+
+    # Without a filename, source_file is None
+    result = parse("const =")
+    assert result.source_file is None
+    assert result.errors[0].source_file is None
+
+
+def test_filename_extension_selects_source_type():
+    """Test that a .ts filename enables TypeScript when source_type is omitted."""
+    from oxc_python import parse
+
+    result = parse("const x: number = 1;", filename="src/foo.ts")
+    assert result.is_valid
+
+    # Explicit source_type still wins
+    result = parse("const x: number = 1;", filename="src/foo.ts", source_type="module")
+    assert not result.is_valid
+
+
+def test_strict_mode_reports_strict_only_errors():
+    """Test that parse(strict_mode=True) reports code only invalid in strict mode."""
+    from oxc_python import parse
+
+    result = parse("with (obj) {}", source_type="script", strict_mode=True)
+    assert result.strict_mode is True
+    assert not result.is_valid
+    assert "with" in result.errors[0].message
+    assert result.errors[0].severity == "error"
+
+    result = parse("with (obj) {}", source_type="script")
+    assert result.strict_mode is False
+    assert result.is_valid
+
+    result = parse("var a = 010;\nvar b = 0.5 + 0 + 0o10;", source_type="script", strict_mode=True)
+    assert len(result.errors) == 1
+    assert result.errors[0].start_line == 1
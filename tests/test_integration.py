@@ -317,6 +317,32 @@ def test_blocker_3_allocator_reuse():
     # Should complete without errors
 
 
+def test_allocator_context_manager():
+    """Validate Allocator as a `with` context manager that resets on exit."""
+    import oxc_python
+
+    allocator = oxc_python.Allocator()
+
+    with allocator as entered:
+        assert entered is allocator
+        result = oxc_python.parse("const a = 1;", allocator=entered)
+        assert result.is_valid
+
+    # Reusable after the block, including as a context manager again
+    assert oxc_python.parse("const b = 2;", allocator=allocator).is_valid
+    with allocator:
+        assert oxc_python.parse("const c = 3;", allocator=allocator).is_valid
+
+    # Exceptions inside the block are not suppressed
+    try:
+        with oxc_python.Allocator():
+            raise ValueError("boom")
+    except ValueError as e:
+        assert str(e) == "boom"
+    else:
+        raise AssertionError("ValueError was suppressed")
+
+
 # ==============================================================================
 # BLOCKER-4: Node.get_text()
 # ==============================================================================
@@ -102,3 +102,45 @@ const y = 2; // Line 5"""
 
     # Comments should be extracted
     assert len(result.comments) > 0
+
+
+# ==============================================================================
+# Standalone Line Helpers
+# ==============================================================================
+
+
+def test_compute_line_number_function():
+    """compute_line_number is callable from Python."""
+    assert oxc_python.compute_line_number("a\nb\nc", 2) == 2
+    assert oxc_python.compute_line_number("a\nb\nc", 0) == 1
+
+
+def test_compute_line_number_inside_multibyte_char():
+    """Offsets inside a multi-byte character resolve to that character's line."""
+    assert oxc_python.compute_line_number("é", 1) == 1
+    assert oxc_python.compute_line_number("a\né\nb", 3) == 2
+    assert oxc_python.compute_line_col("a\né\nb", 3) == (2, 0)
+
+
+def test_compute_line_col_function():
+    """compute_line_col returns a 1-indexed line and 0-indexed column."""
+    assert oxc_python.compute_line_col("a\nb", 3) == (2, 1)
+    assert oxc_python.compute_line_col("abc", 2) == (1, 2)
+
+
+def test_build_line_offset_table_indexed_by_byte():
+    """The table has one entry per byte, including multi-byte characters."""
+    assert oxc_python.build_line_offset_table("a\nb") == [1, 1, 2]
+    assert oxc_python.build_line_offset_table("é\nx") == [1, 1, 1, 2]
+
+
+def test_line_index_matches_standalone_functions():
+    """LineIndex answers the same queries from a cached table."""
+    source = "const a = 1;\n  const b = 2;\n"
+    index = oxc_python.LineIndex(source)
+
+    assert len(index) == len(source)
+    for offset in range(len(source) + 1):
+        assert index.line_of(offset) == oxc_python.compute_line_number(source, offset)
+        assert index.line_col_of(offset) == oxc_python.compute_line_col(source, offset)
+    assert index.line_col_of(15) == (2, 2)
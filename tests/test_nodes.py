@@ -83,6 +83,72 @@ def test_span_repr():
     assert "Span" in repr_str
 
 
+def test_span_contains_offset():
+    """Test `offset in span` uses half-open [start, end) semantics."""
+    from oxc_python import Span
+
+    span = Span(0, 5)
+
+    assert 0 in span
+    assert 4 in span
+    assert 5 not in span
+    assert 0 not in Span(0, 0)
+    assert -1 not in span
+
+
+def test_span_operators():
+    """Test Span union, intersection, truthiness, and unpacking."""
+    from oxc_python import Span
+
+    assert Span(0, 5) + Span(3, 10) == Span(0, 10)
+    assert Span(8, 10) + Span(0, 2) == Span(0, 10)
+
+    assert (Span(0, 5) & Span(3, 10)) == Span(3, 5)
+    assert (Span(0, 5) & Span(5, 10)) is None
+
+    assert Span(0, 5)
+    assert not Span(3, 3)
+
+    start, end = Span(2, 7)
+    assert (start, end) == (2, 7)
+
+
+def test_span_tuple_protocol():
+    """Test Span indexes and unpacks like a (start, end) tuple."""
+    from oxc_python import Span, parse
+
+    span = parse("let a = 1;").program[0].span
+    assert list(span) == [span.start, span.end]
+    a, b = span
+    assert (a, b) == (span.start, span.end)
+
+    assert len(span) == 2
+    assert len(Span(3, 3)) == 2
+    assert span[0] == span.start
+    assert span[1] == span.end
+    assert span[-1] == span.end
+    assert span[-2] == span.start
+    with pytest.raises(IndexError):
+        span[2]
+    assert tuple(Span(4, 9)) == (4, 9)
+
+
+def test_span_line_slice_and_caret_annotation():
+    """Test Span.line_slice() and caret_annotation() show the span's source line."""
+    from oxc_python import Span
+
+    source = "hello\nworld\n"
+    assert Span(7, 10).line_slice(source) == "world"
+    assert Span(7, 10).caret_annotation(source) == "world\n ^^^"
+
+    assert Span(0, 2).line_slice(source) == "hello"
+    # Spans past the line end are cut off; empty spans get one caret
+    assert Span(3, 9).caret_annotation(source) == "hello\n   ^^"
+    assert Span(6, 6).caret_annotation(source) == "world\n^"
+    assert Span(2, 4).line_slice("ab\r\ncd") == "ab"
+    assert Span(1, 3).caret_annotation("\tx = 1") == "\tx = 1\n\t^^"
+
+
 # ChunkHound Validation
 def test_chunkhound_span_for_get_text():
     """
@@ -1118,3 +1184,157 @@ export default main;
         assert chunk["start_line"] >= 1
         assert chunk["end_line"] >= 1
         assert chunk["end_line"] >= chunk["start_line"]
+
+
+def test_program_sequence_protocol():
+    """Test that Program supports iteration, len(), and indexing over its body."""
+    import oxc_python
+
+    result = oxc_python.parse("const a = 1;\nlet b = 2;\nfoo();")
+    program = result.program
+
+    assert list(program) == program.body
+    assert len(program) == 3
+    assert program[0].type == "VariableDeclaration"
+    assert program[-1].type == "ExpressionStatement"
+    assert program[-1] == program.body[2]
+
+    with pytest.raises(IndexError):
+        program[3]
+    with pytest.raises(IndexError):
+        program[-4]
+
+
+def test_container_nodes_sequence_protocol():
+    """Test that BlockStatement, ClassBody, and TSInterfaceBody behave like sequences."""
+    import oxc_python
+
+    block = oxc_python.parse("function f() { a(); b(); }").program[0].body
+    assert block.type == "BlockStatement"
+    assert len(block) == 2
+    assert list(block) == block.body
+    assert block[-1] == block.body[1]
+
+    class_body = oxc_python.parse("class A { x = 1; m() {} }").program[0].body
+    assert len(class_body) == 2
+    assert class_body[0].type == "ClassProperty"
+    assert class_body[-1].type == "MethodDefinition"
+
+    interface_body = oxc_python.parse(
+        "interface I { a: string; b: number; }", source_type="ts"
+    ).program[0].body
+    assert len(interface_body) == 2
+    assert list(interface_body) == interface_body.body
+
+
+def test_type_parameters_sequence_protocol():
+    """Test that TSTypeParameterDeclaration iterates over its params."""
+    import oxc_python
+
+    func = oxc_python.parse(
+        "function f<T, U extends string>(a: T, b: U) {}", source_type="ts"
+    ).program[0]
+    type_params = func.type_parameters
+
+    assert len(type_params) == 2
+    assert [tp.name for tp in type_params] == ["T", "U"]
+    assert type_params[-1].name == "U"
+    assert type_params[0] == type_params.params[0]
+
+
+def test_function_body_and_signature_source():
+    """Test body_source()/signature_source() split a function at its body."""
+    import oxc_python
+
+    src = "function foo(x: number): string { return x.toString(); }"
+    func = oxc_python.parse(src, source_type="ts").program[0]
+
+    assert func.body_source(src) == "{ return x.toString(); }"
+    assert func.signature_source(src) == "function foo(x: number): string "
+
+
+def test_arrow_and_method_body_and_signature_source():
+    """Test body_source()/signature_source() on arrows and class methods."""
+    import oxc_python
+
+    src = "const add = (a, b) => a + b;\nclass A { run(n) { return n; } }"
+    program = oxc_python.parse(src).program
+
+    arrow = program[0].declarations[0].init
+    assert arrow.body_source(src) == "a + b"
+    assert arrow.signature_source(src) == "(a, b) => "
+
+    method = program[1].body.body[0]
+    assert method.body_source(src) == "{ return n; }"
+    assert method.signature_source(src) == "run(n) "
+
+
+def test_function_str_shows_tree():
+    """Test str() of a FunctionDeclaration is an indented tree with name and params."""
+    import oxc_python
+
+    func = oxc_python.parse("function foo(a, b) { return a; }").program[0]
+    lines = str(func).splitlines()
+
+    assert lines[0] == "FunctionDeclaration name='foo' params=2 span=0..32"
+    assert lines[1] == "  FormalParameter name='a' span=13..14"
+    assert "      Identifier name='a' span=28..29" in lines
+
+
+def test_rich_repr_items():
+    """Test __rich_repr__ yields (name, value) pairs for rich."""
+    import oxc_python
+
+    program = oxc_python.parse(
+        "import { x } from 'm';\ninterface I extends J {}", source_type="ts"
+    ).program
+    items = dict(program[0].__rich_repr__())
+    assert items["specifiers"] == 1
+    assert items["import_kind"] == "value"
+
+    items = dict(program[1].__rich_repr__())
+    assert items["name"] == "I"
+    assert items["extends"] == 1
+
+
+def test_node_to_repr_indents_by_depth():
+    """Test Node.to_repr() indents the first line by depth."""
+    import oxc_python
+
+    node = oxc_python.Node("Custom", oxc_python.Span(0, 3))
+    assert node.to_repr() == "Custom span=0..3"
+    assert node.to_repr(depth=2) == "    Custom span=0..3"
+
+
+def test_node_dict_introspection():
+    """Test Node.__dict__ and to_dict() expose type, span, and lines."""
+    import oxc_python
+
+    node = oxc_python.Node("Custom", oxc_python.Span(0, 3))
+    expected = {"type": "Custom", "span": {"start": 0, "end": 3}, "start_line": 1, "end_line": 1}
+    assert node.__dict__ == expected
+    assert node.to_dict() == expected
+
+
+def test_typed_node_to_dict_includes_fields():
+    """Test to_dict() on typed nodes includes every field."""
+    import oxc_python
+
+    source = "function add(a, b) { return a + b; }\nclass A extends B {}"
+    result = oxc_python.parse(source)
+
+    for node, _ in oxc_python.walk(result.program):
+        data = node.to_dict()
+        assert data["type"] == node.type
+        assert data["span"] == {"start": node.span.start, "end": node.span.end}
+        assert "start_line" in data and "end_line" in data
+
+    func = result.program.body[0].to_dict()
+    assert func["name"] == "add"
+    assert len(func["params"]) == 2
+    assert func["is_async"] is False
+    assert "get_text" not in func
+
+    cls = result.program.body[1].to_dict()
+    assert cls["superclass"] == "B"
+    assert cls["start_line"] == 2
@@ -138,6 +138,82 @@ class TestFunctionDeclarationStructure:
         else:
             pytest.fail("No FunctionDeclaration found")
 
+    def test_function_param_helpers(self):
+        """RED: param_names(), param_count() and has_rest_param() summarize params."""
+        import oxc_python
+
+        func = oxc_python.parse("function f(a, b, ...rest) {}").program.body[0]
+        assert func.param_count() == 3
+        assert func.param_names() == ["a", "b", None]
+        assert func.has_rest_param() is True
+        assert func.params[-1].type == "RestElement"
+        assert func.params[-1].argument.name == "rest"
+
+        source = "const g = (x, { a, b }, z) => x;\nclass C { m(p) {} }"
+        result = oxc_python.parse(source)
+        arrow = result.program.body[0].declarations[0].init
+        method = result.program.body[1].body.body[0]
+
+        assert arrow.param_names() == ["x", None, "z"]
+        assert arrow.has_rest_param() is False
+        assert method.param_names() == ["p"]
+        assert method.param_count() == 1
+
+    def test_param_names_with_defaults(self):
+        """RED: param_names() names defaulted params and leaves patterns and rest as None."""
+        import oxc_python
+
+        func = oxc_python.parse("function f(a, {b}, c = 1, ...rest) {}").program.body[0]
+        assert func.param_names() == ["a", None, "c", None]
+
+        arrow = oxc_python.parse("const g = (x = 0, [y] = []) => x;").program.body[0].declarations[0].init
+        assert arrow.param_names() == ["x", None]
+
+    def test_function_complexity(self):
+        """RED: complexity() counts decision points on top of a base of 1."""
+        import oxc_python
+
+        def complexity(source):
+            return oxc_python.parse(source).program.body[0].complexity()
+
+        assert complexity("function f() { return 1; }") == 1
+        assert complexity("function f(a) { if (a) { return 1; } }") == 2
+        source = """
+function classify(items) {
+    for (const item of items) {
+        if (item > 10) {
+            return "big";
+        } else if (item > 5 && item < 8) {
+            return "medium";
+        } else {
+            return item ? "small" : "zero";
+        }
+    }
+    switch (items.length) {
+        case 0: return "empty";
+        default: return "done";
+    }
+}
+"""
+        # for-of, if, else-if, &&, ternary, one non-default case
+        assert complexity(source) == 7
+
+    def test_function_complexity_excludes_nested_functions(self):
+        """RED: branches inside nested functions, arrows, and classes don't count."""
+        import oxc_python
+
+        source = """
+function f() {
+    function g() { if (a) {} }
+    const h = () => { if (b) {} };
+    class K { m() { if (c) {} } }
+    return d ? 1 : 2;
+}
+"""
+        f = oxc_python.parse(source).program.body[0]
+        assert f.complexity() == 2
+        assert f.body.body[0].complexity() == 2
+
 
 class TestClassDeclarationStructure:
     """Tests for ClassDeclaration node structure."""
@@ -206,6 +282,80 @@ class TestClassDeclarationStructure:
         else:
             pytest.fail("No ClassDeclaration found")
 
+    def test_class_member_helpers(self):
+        """RED: method_count(), property_count() and constructor() summarize members."""
+        import oxc_python
+
+        source = """
+class Account {
+    balance = 0;
+    owner;
+    constructor(owner) { this.owner = owner; }
+    deposit(amount) { this.balance += amount; }
+    get total() { return this.balance; }
+}
+"""
+        cls = oxc_python.parse(source).program.body[0]
+
+        assert cls.method_count() == 3
+        assert cls.property_count() == 2
+        ctor = cls.constructor()
+        assert ctor.name == "constructor"
+        assert ctor.kind == "constructor"
+        methods = [e for e in cls.body.body if e.type == "MethodDefinition"]
+        assert [m.kind for m in methods] == ["constructor", "method", "get"]
+
+        empty = oxc_python.parse("class Empty {}").program.body[0]
+        assert empty.method_count() == 0
+        assert empty.constructor() is None
+
+    def test_class_body_elements(self):
+        """RED: ClassBody.body holds every element; methods is the method subset."""
+        import oxc_python
+
+        source = "class Counter { count = 0; increment() { this.count++; } static max = 10; }"
+        class_body = oxc_python.parse(source).program.body[0].body
+
+        assert [element.type for element in class_body.body] == [
+            "ClassProperty",
+            "MethodDefinition",
+            "ClassProperty",
+        ]
+        with pytest.warns(DeprecationWarning, match="ClassBody.methods"):
+            methods = class_body.methods
+        assert [m.name for m in methods] == ["increment"]
+        assert methods[0] is class_body.body[1]
+
+    def test_class_body_static_blocks_and_accessors(self):
+        """RED: static blocks, accessors, and index signatures stay in ClassBody.body."""
+        import oxc_python
+
+        source = """
+class Registry {
+    [key: string]: unknown;
+    static accessor size: number = 0;
+    static { Registry.size = 1; }
+    load() {}
+}
+"""
+        cls = oxc_python.parse(source, source_type="typescript").program.body[0]
+
+        assert [element.type for element in cls.body.body] == [
+            "TSIndexSignature",
+            "AccessorProperty",
+            "StaticBlock",
+            "MethodDefinition",
+        ]
+        accessor = cls.body.body[1]
+        assert accessor.name == "size"
+        assert accessor.is_static
+        assert accessor.value.value == 0
+        assert accessor.type_annotation is not None
+        static_block = cls.body.body[2]
+        assert [stmt.type for stmt in static_block.body] == ["ExpressionStatement"]
+        assert cls.method_count() == 1
+        assert cls.property_count() == 1
+
 
 class TestVariableDeclarationStructure:
     """Tests for VariableDeclaration node structure."""
@@ -300,6 +450,42 @@ class TestVariableDeclarationStructure:
         else:
             pytest.fail("No VariableDeclaration found")
 
+    def test_variable_kind_flags(self):
+        """RED: Exactly one kind flag is True per VariableDeclaration."""
+        import oxc_python
+
+        source = "const x = 1; let y = 2; var z = 3; using r = getResource(); async function f() { await using s = open(); }"
+        result = oxc_python.parse(source, source_type="module")
+
+        flags = []
+        for node, _ in oxc_python.walk(result.program):
+            if node.type == "VariableDeclaration":
+                flags.append((
+                    node.kind,
+                    [node.is_const(), node.is_let(), node.is_var(), node.is_using(), node.is_await_using()],
+                ))
+
+        assert flags == [
+            ("const", [True, False, False, False, False]),
+            ("let", [False, True, False, False, False]),
+            ("var", [False, False, True, False, False]),
+            ("using", [False, False, False, True, False]),
+            ("await using", [False, False, False, False, True]),
+        ]
+
+    def test_first_declarator_name_and_single_name(self):
+        """RED: Declarator names are read safely for single and multiple bindings."""
+        import oxc_python
+
+        single, multiple, pattern = oxc_python.parse("const x = 1; const a = 1, b = 2; const [c, d] = [];").program.body
+
+        assert single.first_declarator_name() == "x"
+        assert single.single_name() == "x"
+        assert multiple.first_declarator_name() == "a"
+        assert multiple.single_name() is None
+        assert pattern.first_declarator_name() is None
+        assert pattern.single_name() is None
+
 
 class TestChunkHoundIntegration:
     """Tests for ChunkHound compatibility."""
@@ -771,6 +957,23 @@ class TestForStatement:
         else:
             pytest.fail("No ForStatement found")
 
+    def test_for_statement_expression_clauses_are_typed(self):
+        """RED: Expression init, test and update must be real expression nodes."""
+        import oxc_python
+
+        source = "for (i = 0; i < 10; i++) {}"
+        result = oxc_python.parse(source, source_type="module")
+
+        for_node = result.program.body[0]
+        assert for_node.init.type == "AssignmentExpression"
+        assert for_node.init.get_text(source) == "i = 0"
+        assert for_node.test.type == "BinaryExpression"
+        assert for_node.update.type == "UpdateExpression"
+
+        walked = [node.type for node, _ in oxc_python.walk(result.program)]
+        assert "AssignmentExpression" in walked
+        assert "UpdateExpression" in walked
+
     def test_for_statement_get_text(self):
         """RED: ForStatement must support get_text()."""
         import oxc_python
@@ -834,6 +1037,18 @@ class TestForInStatement:
         assert for_in_node.right is not None
         assert for_in_node.body is not None
 
+    def test_for_in_statement_assignment_target_left(self):
+        """RED: A non-declaration left side must be converted as an expression."""
+        import oxc_python
+
+        result = oxc_python.parse("for (key in obj) {} for (this.item of items) {}")
+
+        for_in, for_of = result.program.body
+        assert for_in.left.type == "Identifier"
+        assert for_in.left.name == "key"
+        assert for_of.left.type == "MemberExpression"
+        assert for_of.left.property.name == "item"
+
     def test_for_in_statement_get_text(self):
         """RED: ForInStatement must support get_text()."""
         import oxc_python
@@ -1148,6 +1363,22 @@ class TestSwitchStatement:
         else:
             pytest.fail("No SwitchStatement found")
 
+    def test_switch_statement_case_values(self):
+        """RED: case_values() and has_default() summarize the cases."""
+        import oxc_python
+
+        source = 'switch(x) { case 1: break; case "a": break; default: break; }'
+        switch = oxc_python.parse(source).program.body[0]
+
+        values = switch.case_values()
+        assert [v.value if v is not None else None for v in values] == [1, "a", None]
+        assert values[0].type == "Literal"
+        assert switch.has_default() is True
+        assert [case.is_default for case in switch.cases] == [False, False, True]
+
+        no_default = oxc_python.parse("switch(x) { case 1: break; }").program.body[0]
+        assert no_default.has_default() is False
+
 
 class TestSwitchCase:
     """Tests for SwitchCase node structure."""
@@ -1323,6 +1554,50 @@ class TestTryStatement:
         else:
             pytest.fail("No TryStatement found")
 
+    def test_try_statement_exception_name(self):
+        """RED: exception_name() reads the catch variable, None when absent."""
+        import oxc_python
+
+        cases = {
+            "try {} catch(e) {}": "e",
+            "try {} catch {}": None,
+            "try {} finally {}": None,
+        }
+        for source, expected in cases.items():
+            try_stmt = oxc_python.parse(source).program.body[0]
+            assert try_stmt.exception_name() == expected, source
+            if try_stmt.handler is not None:
+                assert try_stmt.handler.param_name == expected
+
+    def test_destructured_catch_binding_has_no_name(self):
+        """RED: catch ({message}) keeps an ObjectPattern param and reports no name."""
+        import oxc_python
+
+        source = "try {} catch ({message}) {}"
+        try_stmt = oxc_python.parse(source).program.body[0]
+
+        assert try_stmt.exception_name() is None
+        assert try_stmt.handler.param_name is None
+        assert try_stmt.handler.param.type == "ObjectPattern"
+        assert try_stmt.handler.param.get_text(source) == "{message}"
+
+    def test_destructured_catch_binding_is_typed_pattern(self):
+        """RED: destructured catch params are pattern nodes that walk() can descend into."""
+        import oxc_python
+
+        source = "try {} catch ({message, cause: [first = 0, ...others]}) {}"
+        param = oxc_python.parse(source).program.body[0].handler.param
+
+        assert isinstance(param, oxc_python.ObjectPattern)
+        assert param.keys == ["message", "cause"]
+        array = param.properties[1]
+        assert isinstance(array, oxc_python.ArrayPattern)
+        assert array.elements[0].type == "AssignmentPattern"
+        assert array.rest.argument.name == "others"
+
+        names = [node.name for node, _ in oxc_python.walk(param) if node.type == "Identifier"]
+        assert names == ["message", "first", "others"]
+
 
 class TestCatchClause:
     """Tests for CatchClause node structure."""
@@ -1563,6 +1838,22 @@ class TestBreakStatement:
         else:
             pytest.fail("No BreakStatement found")
 
+    def test_label_name_helpers(self):
+        """RED: label_name() returns the label on labeled, break, and continue statements."""
+        import oxc_python
+
+        source = "outer: for (;;) { if (a) break outer; if (b) continue outer; break; }"
+        result = oxc_python.parse(source, source_type="module")
+
+        names = {}
+        for node, _ in oxc_python.walk(result.program):
+            if node.type in ("LabeledStatement", "BreakStatement", "ContinueStatement"):
+                names.setdefault(node.type, []).append(node.label_name())
+
+        assert names["LabeledStatement"] == ["outer"]
+        assert set(names["BreakStatement"]) == {"outer", None}
+        assert names["ContinueStatement"] == ["outer"]
+
 
 class TestContinueStatement:
     """Tests for ContinueStatement node structure."""
@@ -1829,6 +2120,20 @@ class TestWithStatement:
         else:
             pytest.fail("No WithStatement found")
 
+    def test_with_statement_object_is_converted_expression(self):
+        """RED: WithStatement.object must be the real expression node."""
+        import oxc_python
+
+        source = "with (obj.nested.chain) {}"
+        result = oxc_python.parse(source, source_type="script")
+
+        with_node = result.program.body[0]
+        assert with_node.object.type == "MemberExpression"
+        assert with_node.object.get_text(source) == "obj.nested.chain"
+
+        walked = [node.type for node, _ in oxc_python.walk(result.program)]
+        assert walked.count("MemberExpression") == 2
+
     def test_with_statement_get_line_range(self):
         """RED: WithStatement must support get_line_range()."""
         import oxc_python
@@ -1906,6 +2211,32 @@ class TestVariableDeclarator:
         else:
             pytest.fail("No VariableDeclarator found")
 
+    def test_variable_declarator_id_name(self):
+        """RED: id_name() returns the identifier name, None for patterns."""
+        import oxc_python
+
+        program = oxc_python.parse("const x = 1; const [a, b] = f();").program
+        simple = program[0].declarations[0]
+        pattern = program[1].declarations[0]
+
+        assert simple.id_name() == "x"
+        assert simple.id_names() == ["x"]
+        assert pattern.id is None
+        assert pattern.id_name() is None
+        assert pattern.id_names() == ["a", "b"]
+
+    def test_variable_declarator_id_names_flattens_patterns(self):
+        """RED: id_names() collects names from nested, renamed, default and rest bindings."""
+        import oxc_python
+
+        program = oxc_python.parse(
+            "const { x: y } = obj;\n"
+            "let [first, { inner = 1, ...others }, , ...tail] = list;"
+        ).program
+
+        assert program[0].declarations[0].id_names() == ["y"]
+        assert program[1].declarations[0].id_names() == ["first", "inner", "others", "tail"]
+
 
 """
 Phase 14: Expression Node Types - Implementation Tests
@@ -2074,6 +2405,8 @@ class TestArrowFunctionExpression:
             body=None,
             is_async=False,
             is_generator=False,
+            start_line=1,
+            end_line=1,
         )
 
         assert hasattr(arrow_node, "get_line_range")
@@ -2083,6 +2416,21 @@ class TestArrowFunctionExpression:
         assert start_line >= 1
         assert end_line >= start_line
 
+    def test_arrow_function_is_concise_and_lines(self):
+        """Parsed arrows report body style and their line range."""
+        import oxc_python
+
+        source = "const f = (x) => x * 2;\nconst g = (x) => {\n  return x;\n};"
+        program = oxc_python.parse(source).program
+        concise = program[0].declarations[0].init
+        block = program[1].declarations[0].init
+
+        assert concise.is_concise is True
+        assert (concise.start_line, concise.end_line) == (1, 1)
+        assert block.is_concise is False
+        assert (block.start_line, block.end_line) == (2, 4)
+        assert block.get_line_range(source) == (2, 4)
+
 
 class TestCallExpression:
     """Tests for CallExpression node structure."""
@@ -2135,6 +2483,45 @@ class TestCallExpression:
         assert hasattr(call_node, "span")
         assert call_node.span == span
 
+    def test_call_expression_callee_name(self):
+        """RED: callee_name() flattens identifiers, this and member chains."""
+        import oxc_python
+
+        source = 'require("foo"); console.log(); this.method(); a.b.c(); obj[key](); f()();'
+        program = oxc_python.parse(source).program
+        names = [stmt.expression.callee_name() for stmt in program]
+
+        assert names == ["require", "console.log", "this.method", "a.b.c", None, None]
+
+
+class TestNewExpression:
+    """Tests for NewExpression (new Foo(a, b))."""
+
+    def test_new_expression_callee_and_arguments(self):
+        """new Foo(a, ...rest) exposes the callee and arguments."""
+        import oxc_python
+
+        new_expr = oxc_python.parse("new Foo(a, ...rest);").program.body[0].expression
+
+        assert isinstance(new_expr, oxc_python.NewExpression)
+        assert new_expr.callee.name == "Foo"
+        assert [arg.type for arg in new_expr.arguments] == ["Identifier", "SpreadElement"]
+        assert new_expr.arguments[1].argument.name == "rest"
+        assert new_expr.type_arguments is None
+
+    def test_new_expression_type_arguments(self):
+        """new Map<string, number>() keeps its generic type arguments."""
+        import oxc_python
+
+        result = oxc_python.parse("const m = new Map<string, number>();", source_type="ts")
+        new_expr = result.program.body[0].declarations[0].init
+
+        assert new_expr.arguments == []
+        assert [p.type for p in new_expr.type_arguments.params] == ["TSStringKeyword", "TSNumberKeyword"]
+        walked = [node.type for node, _ in oxc_python.walk(new_expr)]
+        assert walked.count("TSStringKeyword") == 1
+        assert walked.count("TSNumberKeyword") == 1
+
 
 class TestMemberExpression:
     """Tests for MemberExpression node structure."""
@@ -2178,6 +2565,15 @@ class TestMemberExpression:
         assert member_node.type == "MemberExpression"
         assert member_node.computed is True
 
+    def test_member_expression_property_name(self):
+        """RED: property_name() resolves static and string-literal keys."""
+        import oxc_python
+
+        program = oxc_python.parse('obj.foo; obj["bar"]; obj[variable]; obj[0];').program
+        names = [stmt.expression.property_name() for stmt in program]
+
+        assert names == ["foo", "bar", None, None]
+
 
 class TestBinaryExpression:
     """Tests for BinaryExpression node structure."""
@@ -2221,6 +2617,54 @@ class TestBinaryExpression:
             assert binary_node.type == "BinaryExpression"
             assert binary_node.operator == op
 
+    def test_binary_expression_operator_categories(self):
+        """Each parsed operator falls into exactly one category."""
+        import oxc_python
+
+        program = oxc_python.parse("a + b; x === y; m & n; a instanceof B;").program
+        exprs = [stmt.expression for stmt in program]
+
+        assert [e.operator for e in exprs] == ["+", "===", "&", "instanceof"]
+        categories = [(e.is_arithmetic(), e.is_comparison(), e.is_bitwise()) for e in exprs]
+        assert categories == [
+            (True, False, False),
+            (False, True, False),
+            (False, False, True),
+            (False, True, False),
+        ]
+
+
+class TestLogicalExpression:
+    """Tests for LogicalExpression (&&, ||, ??)."""
+
+    def test_logical_operators_are_not_binary_expressions(self):
+        """&&, ||, and ?? become LogicalExpression; + stays BinaryExpression."""
+        import oxc_python
+
+        result = oxc_python.parse("a && b; c || d; e ?? f; g + h;")
+        exprs = [stmt.expression for stmt in result.program.body]
+
+        assert [e.type for e in exprs] == [
+            "LogicalExpression",
+            "LogicalExpression",
+            "LogicalExpression",
+            "BinaryExpression",
+        ]
+        assert [e.operator for e in exprs[:3]] == ["&&", "||", "??"]
+        assert isinstance(exprs[0], oxc_python.LogicalExpression)
+        assert (exprs[0].left.name, exprs[0].right.name) == ("a", "b")
+
+    def test_jsx_short_circuit_is_walkable(self):
+        """The JSX branch of `cond && <el/>` is reachable via walk()."""
+        import oxc_python
+
+        result = oxc_python.parse("const el = isOpen && <Modal />;", source_type="jsx")
+        logical = result.program.body[0].declarations[0].init
+
+        assert logical.type == "LogicalExpression"
+        assert logical.right.type == "JSXElement"
+        assert "JSXElement" in [node.type for node, _ in oxc_python.walk(result.program)]
+
 
 class TestUnaryExpression:
     """Tests for UnaryExpression node structure."""
@@ -2261,6 +2705,87 @@ class TestUnaryExpression:
             assert unary_node.type == "UnaryExpression"
             assert unary_node.operator == op
 
+    def test_unary_expression_operator_predicates(self):
+        """Each operator predicate is True only on its own operator."""
+        import oxc_python
+
+        source = "!x; typeof y; void 0; delete obj.prop; -n; ~bits;"
+        result = oxc_python.parse(source)
+        nodes = [stmt.expression for stmt in result.program.body]
+        assert [n.type for n in nodes] == ["UnaryExpression"] * 6
+
+        predicates = [
+            "is_negation",
+            "is_typeof",
+            "is_void",
+            "is_delete",
+            "is_arithmetic_negation",
+            "is_bitwise_not",
+        ]
+        for i, node in enumerate(nodes):
+            assert [getattr(node, name)() for name in predicates] == [j == i for j in range(6)]
+
+        assert nodes[0].argument.name == "x"
+        assert nodes[3].argument.type == "MemberExpression"
+
+
+class TestUpdateExpression:
+    """Tests for UpdateExpression (i++, --j)."""
+
+    def test_update_expression_in_for_loop(self):
+        """The loop update i++ exposes its operator and Identifier operand."""
+        import oxc_python
+
+        result = oxc_python.parse("for (let i = 0; i < n; i++) {}")
+        updates = [node for node, _ in oxc_python.walk(result.program) if node.type == "UpdateExpression"]
+
+        assert len(updates) == 1
+        update = updates[0]
+        assert isinstance(update, oxc_python.UpdateExpression)
+        assert update.operator == "++"
+        assert update.prefix is False
+        assert update.argument.type == "Identifier"
+        assert update.argument.name == "i"
+        assert any(node is update.argument for node, _ in oxc_python.walk(update))
+
+    def test_prefix_decrement_on_member(self):
+        """--obj.count is a prefix decrement of a MemberExpression."""
+        import oxc_python
+
+        update = oxc_python.parse("--obj.count;").program.body[0].expression
+
+        assert update.operator == "--"
+        assert update.prefix is True
+        assert update.argument.type == "MemberExpression"
+
+
+class TestAwaitYieldExpression:
+    """Tests for AwaitExpression and YieldExpression."""
+
+    def test_await_expression_argument(self):
+        """await fetch(url) exposes the awaited call."""
+        import oxc_python
+
+        result = oxc_python.parse("async function load(url) { const res = await fetch(url); }")
+        awaits = [node for node, _ in oxc_python.walk(result.program) if node.type == "AwaitExpression"]
+
+        assert len(awaits) == 1
+        assert isinstance(awaits[0], oxc_python.AwaitExpression)
+        assert awaits[0].argument.type == "CallExpression"
+        assert awaits[0].argument.callee.name == "fetch"
+
+    def test_yield_expression_delegate(self):
+        """yield, yield value, and yield* differ in argument and delegate."""
+        import oxc_python
+
+        result = oxc_python.parse("async function* gen() { yield; yield await next(); yield* other(); }")
+        yields = [node for node, _ in oxc_python.walk(result.program) if node.type == "YieldExpression"]
+
+        assert [y.delegate for y in yields] == [False, False, True]
+        assert yields[0].argument is None
+        assert yields[1].argument.type == "AwaitExpression"
+        assert yields[2].argument.callee.name == "other"
+
 
 class TestConditionalExpression:
     """Tests for ConditionalExpression node structure."""
@@ -2286,6 +2811,60 @@ class TestConditionalExpression:
         assert hasattr(cond_node, "alternate")
 
 
+class TestSequenceExpression:
+    """Tests for SequenceExpression (comma operator)."""
+
+    def test_sequence_expression_expressions(self):
+        """a = 1, b = 2, f() keeps every expression in order."""
+        import oxc_python
+
+        seq = oxc_python.parse("a = 1, b = 2, f();").program.body[0].expression
+
+        assert isinstance(seq, oxc_python.SequenceExpression)
+        assert [e.type for e in seq.expressions] == [
+            "AssignmentExpression",
+            "AssignmentExpression",
+            "CallExpression",
+        ]
+        walked = [node.type for node, _ in oxc_python.walk(seq)]
+        assert walked.count("Identifier") == 3
+
+    def test_sequence_in_for_update(self):
+        """The i++, j-- update of a for loop is a SequenceExpression."""
+        import oxc_python
+
+        loop = oxc_python.parse("for (i = 0, j = n; i < j; i++, j--) {}").program.body[0]
+
+        assert loop.init.type == "SequenceExpression"
+        assert [u.operator for u in loop.update.expressions] == ["++", "--"]
+
+
+class TestThisAndSuper:
+    """Tests for ThisExpression and Super nodes."""
+
+    def test_this_expression(self):
+        """`this` is a ThisExpression node."""
+        import oxc_python
+
+        result = oxc_python.parse("this.count")
+        member = result.program.body[0].expression
+
+        assert isinstance(member.object, oxc_python.ThisExpression)
+        assert member.object.type == "ThisExpression"
+        assert (member.object.span.start, member.object.span.end) == (0, 4)
+
+    def test_super_call_and_member(self):
+        """super(...) and super.m() both reference a Super node."""
+        import oxc_python
+
+        source = "class A extends B { constructor() { super(1); super.m(); } }"
+        supers = oxc_python.parse(source).program.find_type("Super")
+
+        assert len(supers) == 2
+        assert all(isinstance(n, oxc_python.Super) for n in supers)
+        assert supers[0].get_text(source) == "super"
+
+
 class TestObjectExpression:
     """Tests for ObjectExpression node structure."""
 
@@ -2317,6 +2896,28 @@ class TestObjectExpression:
         )
 
         assert len(obj_node.properties) == 2
+        assert obj_node.keys == [None, None]
+
+    def test_object_expression_property_map(self):
+        """property_map(), has_key() and get_value() look up static keys."""
+        import oxc_python
+
+        source = 'const opts = { timeout: 5000, retries: 3, host: "localhost", [k]: 1, ...rest, "a-b": 2, retries: 4 };'
+        result = oxc_python.parse(source)
+        opts_node = result.program.body[0].declarations[0].init
+
+        assert opts_node.keys == ["timeout", "retries", "host", None, None, "a-b", "retries"]
+
+        props = opts_node.property_map()
+        assert set(props) == {"timeout", "retries", "host", "a-b"}
+        assert props["timeout"].raw == "5000"
+        assert props["host"].value == "localhost"
+        assert props["retries"].value == 4
+
+        assert opts_node.has_key("host") is True
+        assert opts_node.has_key("k") is False
+        assert opts_node.get_value("retries").value == 4
+        assert opts_node.get_value("missing") is None
 
 
 class TestArrayExpression:
@@ -2352,6 +2953,76 @@ class TestArrayExpression:
         assert len(arr_node.elements) == 3
 
 
+class TestSpreadElement:
+    """Tests for SpreadElement in call arguments, arrays, and objects."""
+
+    def test_spread_call_argument(self):
+        """f(...args) keeps the spread wrapper around the argument."""
+        import oxc_python
+
+        result = oxc_python.parse("f(a, ...args)")
+        call = result.program.body[0].expression
+
+        assert call.arguments[0].type == "Identifier"
+        spread = call.arguments[1]
+        assert spread.type == "SpreadElement"
+        assert spread.argument.name == "args"
+        assert (spread.span.start, spread.span.end) == (5, 12)
+
+    def test_spread_array_element(self):
+        """[...arr, 1] yields a SpreadElement followed by the literal."""
+        import oxc_python
+
+        result = oxc_python.parse("[...arr, 1]")
+        array = result.program.body[0].expression
+
+        assert [e.type for e in array.elements] == ["SpreadElement", "Literal"]
+        assert array.elements[0].argument.name == "arr"
+
+    def test_spread_object_property(self):
+        """({...base, a: 1}) keeps the spread wrapper, with no static key."""
+        import oxc_python
+
+        result = oxc_python.parse("({...base, a: 1})")
+        obj = result.program.body[0].expression
+
+        assert [p.type for p in obj.properties] == ["SpreadElement", "Literal"]
+        assert obj.properties[0].argument.name == "base"
+        assert obj.keys == [None, "a"]
+
+
+class TestTemplateLiteral:
+    """Tests for TemplateLiteral and its TemplateElement quasis."""
+
+    def test_template_literal_quasis_and_expressions(self):
+        """Quasis hold the string parts; expressions hold the ${...} parts."""
+        import oxc_python
+
+        source = "const s = `hello ${name}, you are ${age} years old`;"
+        template = oxc_python.parse(source).program.body[0].declarations[0].init
+
+        assert isinstance(template, oxc_python.TemplateLiteral)
+        assert [q.raw for q in template.quasis] == ["hello ", ", you are ", " years old"]
+        assert [q.tail for q in template.quasis] == [False, False, True]
+        assert all(q.type == "TemplateElement" for q in template.quasis)
+        assert [e.name for e in template.expressions] == ["name", "age"]
+
+        walked = [node.type for node, _ in oxc_python.walk(template)]
+        assert walked.count("TemplateElement") == 3
+        assert walked.count("Identifier") == 2
+
+    def test_template_element_cooked_and_raw(self):
+        """cooked processes escapes while raw keeps the source text."""
+        import oxc_python
+
+        template = oxc_python.parse(r"`a\tb`").program.body[0].expression
+
+        (quasi,) = template.quasis
+        assert quasi.raw == r"a\tb"
+        assert quasi.cooked == "a\tb"
+        assert template.expressions == []
+
+
 class TestIdentifier:
     """Tests for Identifier node structure."""
 
@@ -2388,6 +3059,45 @@ class TestIdentifier:
             assert ident_node.type == "Identifier"
             assert ident_node.name == name
 
+    def test_expression_line_numbers(self):
+        """Expression nodes carry the lines they appear on."""
+        import oxc_python
+
+        source = "const a = 1;\n\nfoo(\n  bar.baz,\n  [x, { y: \"s\" }],\n  c ? d : -e + f\n);"
+        result = oxc_python.parse(source)
+
+        lines = {}
+        for node, _ in oxc_python.walk(result.program):
+            lines.setdefault((node.type, node.get_text(source)), (node.start_line, node.end_line))
+
+        assert lines[("Literal", "1")] == (1, 1)
+        assert lines[("CallExpression", source[source.index("foo"):-1])] == (3, 7)
+        assert lines[("Identifier", "foo")] == (3, 3)
+        assert lines[("MemberExpression", "bar.baz")] == (4, 4)
+        assert lines[("ArrayExpression", '[x, { y: "s" }]')] == (5, 5)
+        assert lines[("ObjectExpression", '{ y: "s" }')] == (5, 5)
+        assert lines[("ConditionalExpression", "c ? d : -e + f")] == (6, 6)
+        assert lines[("BinaryExpression", "-e + f")] == (6, 6)
+        assert lines[("UnaryExpression", "-e")] == (6, 6)
+
+    def test_constructed_identifier_lines(self):
+        """Nodes built from Python take lines as arguments, defaulting to 0."""
+        import oxc_python
+
+        ident = oxc_python.Identifier(span=oxc_python.Span(50, 53), name="foo")
+        assert (ident.start_line, ident.end_line) == (0, 0)
+
+        ident = oxc_python.Identifier(span=oxc_python.Span(50, 53), name="foo", start_line=3, end_line=4)
+        assert ident.get_line_range("") == (3, 4)
+
+    def test_constructed_nodes_ignore_previous_parse(self):
+        """Constructor line numbers do not depend on an earlier parse()."""
+        import oxc_python
+
+        oxc_python.parse("a;\nb;\nc;\nd;")
+        expr = oxc_python.BinaryExpression(span=oxc_python.Span(6, 7), left=None, operator="+", right=None)
+        assert (expr.start_line, expr.end_line) == (0, 0)
+
 
 class TestLiteral:
     """Tests for Literal node structure."""
@@ -2457,6 +3167,124 @@ class TestLiteral:
         assert lit_node.value is None
         assert lit_node.raw == "null"
 
+    def test_literal_kind_inferred(self):
+        """Constructed literals infer kind from the Python value."""
+        import oxc_python
+
+        span = oxc_python.Span(0, 1)
+        assert oxc_python.Literal(span=span, value=1, raw="1").kind == "number"
+        assert oxc_python.Literal(span=span, value=True, raw="true").kind == "boolean"
+        assert oxc_python.Literal(span=span, value=None, raw="null").kind == "null"
+        assert oxc_python.Literal(span=span, value="a", raw="'a'").kind == "string"
+
+    def test_literal_kind_and_truthiness(self):
+        """Parsed literals report their kind and JavaScript truthiness."""
+        import oxc_python
+
+        source = "[0, 1, NaN, '', 'x', true, false, null, /a/, 0n, 5n]"
+        result = oxc_python.parse(source)
+        elements = result.program.body[0].expression.elements
+        literals = [e for e in elements if e.type != "Identifier"]
+
+        assert [lit.kind for lit in literals] == [
+            "number", "number", "string", "string", "boolean",
+            "boolean", "null", "regex", "bigint", "bigint",
+        ]
+        assert [lit.is_truthy() for lit in literals] == [
+            False, True, False, True, True, False, False, True, False, True,
+        ]
+        assert literals[0].is_number()
+        assert literals[2].is_string()
+        assert literals[6].is_null()
+        assert not literals[8].is_number()
+
+
+class TestRegExpLiteral:
+    """Tests for RegExpLiteral node structure."""
+
+    def test_regexp_literal_pattern_and_flags(self):
+        """RED: Regex literal initializer should be a RegExpLiteral."""
+        import oxc_python
+
+        source = "const re = /^hello/gi;"
+        result = oxc_python.parse(source)
+
+        declarator = result.program.body[0].declarations[0]
+        regex = declarator.init
+
+        assert regex.type == "RegExpLiteral"
+        assert regex.pattern == "^hello"
+        assert regex.flags == "gi"
+        assert regex.raw == "/^hello/gi"
+        assert regex.value is None
+        assert regex.get_text(source) == "/^hello/gi"
+
+    def test_regexp_literal_is_literal_subclass(self):
+        """RED: RegExpLiteral should be usable wherever a Literal is expected."""
+        import oxc_python
+
+        result = oxc_python.parse("const re = /a+/;")
+        regex = result.program.body[0].declarations[0].init
+
+        assert isinstance(regex, oxc_python.RegExpLiteral)
+        assert isinstance(regex, oxc_python.Literal)
+        assert regex.flags == ""
+
+
+class TestBigIntLiteral:
+    """Tests for BigIntLiteral and numeric Literal conversion."""
+
+    def test_bigint_literal_value(self):
+        """RED: BigInt literal should keep its exact digits as a string."""
+        import oxc_python
+
+        source = "const n = 9007199254740993n"
+        result = oxc_python.parse(source)
+        bigint = result.program.body[0].declarations[0].init
+
+        assert bigint.type == "BigIntLiteral"
+        assert isinstance(bigint, oxc_python.Literal)
+        assert bigint.value == "9007199254740993"
+        assert bigint.raw == "9007199254740993n"
+
+    def test_bigint_literal_hex_value_is_decimal(self):
+        """RED: Non-decimal BigInt literals report a base-10 value."""
+        import oxc_python
+
+        result = oxc_python.parse("const n = 0xFFn;")
+        bigint = result.program.body[0].declarations[0].init
+
+        assert bigint.value == "255"
+        assert bigint.raw == "0xFFn"
+
+    def test_numeric_literal_int_and_float(self):
+        """RED: Numeric literals become Literal nodes with int or float values."""
+        import oxc_python
+
+        result = oxc_python.parse("const a = 42; const b = 3.5;")
+        a = result.program.body[0].declarations[0].init
+        b = result.program.body[1].declarations[0].init
+
+        assert a.type == "Literal"
+        assert a.value == 42
+        assert isinstance(a.value, int)
+        assert b.value == 3.5
+        assert isinstance(b.value, float)
+
+    def test_string_boolean_null_literals(self):
+        """RED: String, boolean and null literals become Literal nodes."""
+        import oxc_python
+
+        result = oxc_python.parse("f('hi', true, null);")
+        args = result.program.body[0].expression.arguments
+
+        assert [arg.type for arg in args] == ["Literal", "Literal", "Literal"]
+        assert args[0].value == "hi"
+        assert args[0].raw == "'hi'"
+        assert args[1].value is True
+        assert args[2].value is None
+        assert args[2].raw == "null"
+
 
 class TestChunkHoundExpressionIntegration:
     """Tests for ChunkHound expression compatibility - CRITICAL."""
@@ -2785,6 +3613,8 @@ class TestExpressionTypes:
         expressions = [
             oxc_python.ArrowFunctionExpression(
                 span=span,
+                start_line=1,
+                end_line=1,
                 params=[],
                 body=None,
                 is_async=False,
@@ -2792,46 +3622,64 @@ class TestExpressionTypes:
             ),
             oxc_python.CallExpression(
                 span=span,
+                start_line=1,
+                end_line=1,
                 callee=None,
                 arguments=[],
             ),
             oxc_python.MemberExpression(
                 span=span,
+                start_line=1,
+                end_line=1,
                 object=None,
                 property=None,
                 computed=False,
             ),
             oxc_python.BinaryExpression(
                 span=span,
+                start_line=1,
+                end_line=1,
                 left=None,
                 operator="+",
                 right=None,
             ),
             oxc_python.UnaryExpression(
                 span=span,
+                start_line=1,
+                end_line=1,
                 operator="!",
                 argument=None,
             ),
             oxc_python.ConditionalExpression(
                 span=span,
+                start_line=1,
+                end_line=1,
                 test=None,
                 consequent=None,
                 alternate=None,
             ),
             oxc_python.ObjectExpression(
                 span=span,
+                start_line=1,
+                end_line=1,
                 properties=[],
             ),
             oxc_python.ArrayExpression(
                 span=span,
+                start_line=1,
+                end_line=1,
                 elements=[],
             ),
             oxc_python.Identifier(
                 span=span,
+                start_line=1,
+                end_line=1,
                 name="x",
             ),
             oxc_python.Literal(
                 span=span,
+                start_line=1,
+                end_line=1,
                 value=42,
                 raw="42",
             ),
@@ -2922,6 +3770,38 @@ def test_import_specifier_properties():
     pytest.fail("ImportSpecifier not found")
 
 
+def test_import_declaration_specifier_names():
+    """RED: Test ImportDeclaration.specifier_names() lists local bindings."""
+    import oxc_python
+
+    cases = {
+        'import { a, b as c } from "m";': ["a", "c"],
+        'import React from "react";': ["React"],
+        'import * as utils from "u";': ["utils"],
+        'import D, { e } from "m";': ["D", "e"],
+        'import "side-effect";': [],
+    }
+    for source, expected in cases.items():
+        decl = oxc_python.parse(source).program.body[0]
+        assert decl.specifier_names() == expected, source
+
+
+def test_import_specifier_imported_name_and_type_only():
+    """RED: Test ImportSpecifier.imported_name() and is_type_only()."""
+    import oxc_python
+
+    source = 'import { foo as bar, type Baz } from "m";'
+    decl = oxc_python.parse(source, source_type="ts").program.body[0]
+    renamed, type_spec = decl.specifiers
+
+    assert renamed.imported_name() == "foo"
+    assert renamed.local.name == "bar"
+    assert renamed.is_type_only() is False
+    assert type_spec.imported_name() == "Baz"
+    assert type_spec.is_type_only() is True
+    assert type_spec.import_kind == "type"
+
+
 def test_import_default_specifier():
     """RED: Test ImportDefaultSpecifier for default imports."""
     import oxc_python
@@ -3299,6 +4179,35 @@ def test_side_effect_imports():
             assert len(imp.specifiers) == 0, "Side-effect imports should have no specifiers"
 
 
+def test_import_attributes():
+    """RED: Test import attributes from a with clause."""
+    import oxc_python
+
+    source = 'import data from "./data.json" with { type: "json" };'
+    result = oxc_python.parse(source, source_type="module")
+
+    assert result.is_valid
+
+    import_decl = result.program.body[0]
+    assert import_decl.type == "ImportDeclaration"
+    assert len(import_decl.with_clause) == 1
+
+    attr = import_decl.with_clause[0]
+    assert attr.type == "ImportAttribute"
+    assert attr.key == "type"
+    assert attr.value == "json"
+    assert attr.get_text(source) == 'type: "json"'
+
+
+def test_import_without_attributes():
+    """RED: Test with_clause is None when there is no with clause."""
+    import oxc_python
+
+    result = oxc_python.parse("import foo from 'module';", source_type="module")
+
+    assert result.program.body[0].with_clause is None
+
+
 def test_re_exports():
     """RED: Test export...from re-export syntax."""
     import oxc_python
@@ -3351,6 +4260,47 @@ def test_export_declaration_with_inline_declaration():
     assert len(exports_with_decl) >= 3, "Should find inline export declarations"
 
 
+def test_export_named_declaration_exported_names():
+    """RED: Test exported_names() for declarations and specifiers."""
+    import oxc_python
+
+    source = """
+    export const x = 1, y = 2;
+    export function f() {}
+    export class C {}
+    export { a, b as c };
+    export const { p, q: r } = obj;
+    """
+    program = oxc_python.parse(source).program
+    exports = [node for node in program if node.type == "ExportNamedDeclaration"]
+
+    assert [e.exported_names() for e in exports] == [
+        ["x", "y"],
+        ["f"],
+        ["C"],
+        ["a", "c"],
+        ["p", "r"],
+    ]
+
+
+def test_export_named_declaration_exported_names_typescript():
+    """RED: Test exported_names() for enums, interfaces, and type aliases."""
+    import oxc_python
+
+    source = """
+    export enum E { A }
+    export const enum F { B }
+    export interface I {}
+    export type T = string;
+    """
+    result = oxc_python.parse(source, source_type="ts")
+    exports = [node for node in result.program if node.type == "ExportNamedDeclaration"]
+
+    assert exports[0].declaration.type == "TSEnumDeclaration"
+    assert [e.exported_names() for e in exports] == [["E"], ["F"], ["I"], ["T"]]
+    assert result.export_names() == ["E", "F", "I", "T"]
+
+
 def test_import_export_get_text():
     """RED: Test get_text() on import/export nodes."""
     import oxc_python
@@ -3393,3 +4343,165 @@ export const bar = 1;
         elif node.type == "ExportNamedDeclaration":
             start, end = node.get_line_range(source)
             assert start == 4, f"Export should start at line 4, got {start}"
+
+
+def test_preserve_parens_keeps_parenthesized_expression():
+    """RED: Test parse(preserve_parens=True) emits ParenthesizedExpression nodes."""
+    import oxc_python
+
+    source = "const y = (a + b) * 2;"
+
+    result = oxc_python.parse(source, preserve_parens=True)
+    types = [node.type for node, _depth in oxc_python.walk(result.program)]
+    assert "ParenthesizedExpression" in types
+
+    paren = next(
+        node for node, _depth in oxc_python.walk(result.program)
+        if node.type == "ParenthesizedExpression"
+    )
+    assert paren.expression.type == "BinaryExpression"
+    assert paren.get_text(source) == "(a + b)"
+
+    # Default drops the parentheses
+    result = oxc_python.parse(source)
+    types = [node.type for node, _depth in oxc_python.walk(result.program)]
+    assert "ParenthesizedExpression" not in types
+    assert "BinaryExpression" in types
+
+
+class TestAssignmentExpression:
+    """Tests for AssignmentExpression and destructuring assignment targets."""
+
+    def _assignment(self, source):
+        import oxc_python
+
+        result = oxc_python.parse(source)
+        assert result.is_valid
+        return result.program.body[0].expression
+
+    def test_simple_assignment(self):
+        """RED: Identifier targets and operators are exposed."""
+        assign = self._assignment("count += 2;")
+
+        assert assign.type == "AssignmentExpression"
+        assert assign.operator == "+="
+        assert assign.left.type == "Identifier"
+        assert assign.left.name == "count"
+        assert assign.right.value == 2
+
+    def test_member_assignment(self):
+        """RED: Member expression targets become MemberExpression nodes."""
+        assign = self._assignment("obj.x = 1;")
+
+        assert assign.left.type == "MemberExpression"
+        assert assign.left.property.name == "x"
+
+    def test_array_destructuring_assignment(self):
+        """RED: `[a, b] = f()` has an ArrayPattern on the left."""
+        assign = self._assignment("[a, , b = 1, ...rest] = f();")
+
+        assert assign.operator == "="
+        assert assign.left.type == "ArrayPattern"
+        a, hole, b = assign.left.elements
+        assert a.name == "a"
+        assert hole is None
+        assert b.type == "AssignmentPattern"
+        assert b.left.name == "b"
+        assert b.right.value == 1
+        assert assign.left.rest.type == "RestElement"
+        assert assign.left.rest.argument.name == "rest"
+        assert assign.right.type == "CallExpression"
+
+    def test_object_destructuring_assignment(self):
+        """RED: `({ x, y: z } = obj)` has an ObjectPattern on the left."""
+        assign = self._assignment("({ x, y: z, ...others } = obj);")
+
+        assert assign.left.type == "ObjectPattern"
+        assert [p.name for p in assign.left.properties] == ["x", "z"]
+        assert assign.left.rest.argument.name == "others"
+
+    def test_object_destructuring_keys(self):
+        """RED: ObjectPattern.keys pairs each target with its key name."""
+        assign = self._assignment("({ x, y: z, [k]: w, v = 1 } = obj);")
+
+        assert assign.left.keys == ["x", "y", None, "v"]
+        assert len(assign.left.keys) == len(assign.left.properties)
+
+    def test_destructuring_targets_in_walk(self):
+        """RED: walk() reaches identifiers inside destructuring targets."""
+        import oxc_python
+
+        result = oxc_python.parse("[a, { b }] = pairs;")
+        names = [
+            node.name for node, _ in oxc_python.walk(result.program)
+            if node.type == "Identifier"
+        ]
+        assert {"a", "b", "pairs"} <= set(names)
+
+    def test_logical_assignment_with_jsx_value(self):
+        """RED: walk() descends into JSX on the right of a logical assignment."""
+        import oxc_python
+
+        result = oxc_python.parse("cache.el &&= <div>{label}</div>;", source_type="jsx")
+        assign = result.program.body[0].expression
+
+        assert isinstance(assign, oxc_python.AssignmentExpression)
+        assert assign.operator == "&&="
+        assert assign.left.type == "MemberExpression"
+        walked = [node.type for node, _ in oxc_python.walk(assign)]
+        assert "JSXElement" in walked
+        assert "JSXExpressionContainer" in walked
+
+
+class TestDecorators:
+    """Tests for class and method decorators."""
+
+    def test_class_decorator(self):
+        """RED: Class decorators are exposed as Decorator nodes."""
+        import oxc_python
+
+        source = "@Injectable() class Service {}"
+        result = oxc_python.parse(source, source_type="ts")
+        assert result.is_valid
+
+        cls = result.program.body[0]
+        assert cls.type == "ClassDeclaration"
+        assert len(cls.decorators) == 1
+        decorator = cls.decorators[0]
+        assert decorator.type == "Decorator"
+        assert decorator.expression.type == "CallExpression"
+        assert decorator.get_text(source) == "@Injectable()"
+
+    def test_method_decorators(self):
+        """RED: Method decorators keep source order."""
+        import oxc_python
+
+        source = """
+class Controller {
+    @Get("/users")
+    @Auth
+    list() {}
+
+    plain() {}
+}
+"""
+        result = oxc_python.parse(source, source_type="ts")
+        list_method, plain_method = result.program.body[0].body.body
+
+        assert [d.expression.type for d in list_method.decorators] == [
+            "CallExpression",
+            "Identifier",
+        ]
+        assert list_method.decorators[1].expression.name == "Auth"
+        assert plain_method.decorators == []
+
+    def test_decorators_in_walk(self):
+        """RED: walk() visits decorators and their expressions."""
+        import oxc_python
+
+        result = oxc_python.parse("@sealed class A { @log m() {} }", source_type="ts")
+        decorators = [
+            node for node, _ in oxc_python.walk(result.program)
+            if node.type == "Decorator"
+        ]
+        assert len(decorators) == 2
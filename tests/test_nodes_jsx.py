@@ -370,6 +370,25 @@ class TestJSXExpressionContainer:
         container = expr_containers[0]
         assert hasattr(container, "expression"), "JSXExpressionContainer must have expression"
 
+    def test_empty_expression_container(self):
+        """RED: Test {} containers are flagged empty and hold an EmptyExpression."""
+        from oxc_python import parse
+
+        result = parse("<div>{ }</div>", source_type="jsx")
+        element = result.program.body[0].expression
+        container = element.children[0]
+
+        assert container.type == "JSXExpressionContainer"
+        assert container.is_empty is True
+        assert container.expression.type == "EmptyExpression"
+
+        result = parse("<div>{x}</div>", source_type="jsx")
+        container = result.program.body[0].expression.children[0]
+
+        assert container.is_empty is False
+        assert container.expression.type == "Identifier"
+        assert container.expression.name == "x"
+
 
 class TestJSXConditionalRendering:
     """Tests for JSX conditional rendering patterns."""
@@ -1344,3 +1363,36 @@ class TestJSXUtilityMethods:
         assert "li" in element_names
         assert element_names.count("span") == 2, "Should find 2 span elements"
         assert "footer" in element_names
+
+    def test_name_str_and_is_intrinsic(self):
+        """RED: name_str() and is_intrinsic() resolve element names."""
+        from oxc_python import parse, walk
+
+        source = "const x = <div><MyComp /><UI.Button.Primary></UI.Button.Primary></div>;"
+        result = parse(source, source_type="jsx")
+
+        openings = [node for node, _ in walk(result.program) if node.type == "JSXOpeningElement"]
+        by_name = {o.name_str(): o for o in openings}
+
+        assert set(by_name) == {"div", "MyComp", "UI.Button.Primary"}
+        assert by_name["div"].is_intrinsic() is True
+        assert by_name["MyComp"].is_intrinsic() is False
+        assert by_name["UI.Button.Primary"].is_intrinsic() is False
+
+        closings = [node for node, _ in walk(result.program) if node.type == "JSXClosingElement"]
+        assert sorted(c.name_str() for c in closings) == ["UI.Button.Primary", "div"]
+
+    def test_element_component_name_and_is_component(self):
+        """RED: JSXElement.component_name() and is_component() read the opening tag."""
+        from oxc_python import parse, walk
+
+        source = "const x = <div><MyButton /><React.Fragment></React.Fragment></div>;"
+        result = parse(source, source_type="jsx")
+
+        elements = [node for node, _ in walk(result.program) if node.type == "JSXElement"]
+        by_name = {e.component_name(): e for e in elements}
+
+        assert set(by_name) == {"div", "MyButton", "React.Fragment"}
+        assert by_name["div"].is_component() is False
+        assert by_name["MyButton"].is_component() is True
+        assert by_name["React.Fragment"].is_component() is True
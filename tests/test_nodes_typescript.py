@@ -6,6 +6,8 @@ validating TypeScript-specific AST nodes including type annotations, interfaces,
 type aliases, enums, and TypeScript-only syntax.
 """
 
+import pytest
+
 from oxc_python import parse, walk
 
 """
@@ -762,6 +764,20 @@ interface User {
         assert "port" in text, f"get_text() should include 'port', got: {text}"
 
 
+    def test_ts_property_signature_name(self):
+        """RED: name() returns identifier and string keys, None for computed keys"""
+        source = 'interface I { name: string; "key": number; [sym]: boolean; }'
+        result = parse(source, source_type="ts")
+
+        assert result.is_valid
+
+        sigs = find_all_nodes(result.program, "TSPropertySignature")
+
+        assert [sig.name() for sig in sigs] == ["name", "key", None]
+        assert [sig.computed for sig in sigs] == [False, False, True]
+        assert sigs[1].key.type == "Literal"
+        assert sigs[2].key.name == "sym"
+
 class TestTSMethodSignatureInWalk:
     """Tests for TSMethodSignature appearing in walk() traversal"""
 
@@ -835,6 +851,29 @@ interface Logger {
         text = method_sig.get_text(source)
         assert "log" in text, f"get_text() should include 'log', got: {text}"
 
+    def test_ts_method_signature_type_parameters_and_optional(self):
+        """RED: TSMethodSignature should expose type_parameters and optional"""
+        source = "interface Parser { parse<T>(s: string): T; format?(v: T): string; }"
+        result = parse(source, source_type="ts")
+
+        assert result.is_valid
+
+        parse_sig, format_sig = find_all_nodes(result.program, "TSMethodSignature")
+
+        assert parse_sig.type_parameters.params[0].name == "T"
+        assert parse_sig.optional is False
+        assert format_sig.type_parameters is None
+        assert format_sig.optional is True
+
+    def test_ts_method_signature_name(self):
+        """RED: TSMethodSignature.name() extracts the key as a string"""
+        source = 'interface Api { get(): void; "delete"(): void; [Symbol.iterator](): void; }'
+        result = parse(source, source_type="ts")
+
+        sigs = find_all_nodes(result.program, "TSMethodSignature")
+
+        assert [sig.name() for sig in sigs] == ["get", "delete", None]
+
 
 class TestTSInterfaceDeclarationDetails:
     """Tests for detailed TSInterfaceDeclaration node structure"""
@@ -930,6 +969,45 @@ class TestTSTypeAliasDeclarationDetails:
             "TSTypeAliasDeclaration.type_parameters should be non-None for 'Result<T, E>'"
         )
 
+    def test_ts_type_alias_resolved_type_str(self):
+        """RED: resolved_type_str() returns the aliased type's source text"""
+        source = "type Callback = (x: string) => void"
+        result = parse(source, source_type="ts")
+
+        type_alias = find_node(result.program, "TSTypeAliasDeclaration")
+
+        assert type_alias.resolved_type_str(source) == "(x: string) => void"
+
+    def test_ts_type_alias_resolved_type_str_exported(self):
+        """RED: resolved_type_str() skips `=` inside type parameter defaults"""
+        source = "export type Id<T = string> = T | number;"
+        result = parse(source, source_type="ts")
+
+        type_alias = find_node(result.program, "TSTypeAliasDeclaration")
+
+        assert type_alias.resolved_type_str(source) == "T | number"
+
+    def test_ts_type_annotation_type_str(self):
+        """RED: type_str() returns the annotated type without the colon"""
+        source = "let cb: (x: string) => void;"
+        result = parse(source, source_type="ts")
+
+        annotation = find_node(result.program, "TSTypeAnnotation")
+
+        assert annotation.get_text(source) == ": (x: string) => void"
+        assert annotation.type_str(source) == "(x: string) => void"
+
+    def test_ts_type_annotation_primitive_kind(self):
+        """RED: primitive_kind() names primitive keyword annotations only"""
+        source = 'const x: string = ""; const y: User = {}; let z: bigint | null; let n: number;'
+        result = parse(source, source_type="ts")
+
+        declarators = [node for node, _ in walk(result.program) if node.type == "VariableDeclarator"]
+        kinds = [d.type_annotation.primitive_kind() for d in declarators]
+
+        assert kinds == ["string", None, None, "number"]
+        assert declarators[0].type_annotation.type_str(source) == "string"
+
 
 class TestTSInterfaceBodyInWalk:
     """Tests for TSInterfaceBody appearing in walk() traversal"""
@@ -1277,3 +1355,678 @@ class TestTypeScriptAdvancedTypes:
         assert "TSUnionType" in node_types or "TSIntersectionType" in node_types, (
             "Should find union or intersection types in complex type"
         )
+
+
+class TestTSConditionalAndImportTypes:
+    """Tests for conditional types, infer types, and import types"""
+
+    def test_infer_type_in_conditional_extends(self):
+        """RED: infer U inside a conditional type's extends clause should be a TSInferType"""
+        source = "type Unpack<T> = T extends Array<infer U> ? U : T"
+        result = parse(source, source_type="tsx")
+
+        assert result.is_valid
+
+        type_alias = find_node(result.program, "TSTypeAliasDeclaration")
+        conditional = type_alias.type_annotation
+        assert conditional.type == "TSConditionalType"
+
+        extends_type = conditional.extends_type
+        assert extends_type.type == "TSTypeReference"
+        infer = extends_type.type_parameters.params[0]
+        assert infer.type == "TSInferType"
+        assert infer.type_parameter.type == "TSTypeParameter"
+        assert infer.type_parameter.name == "U"
+
+    def test_infer_type_appears_in_walk(self):
+        """RED: TSInferType should be reachable via walk()"""
+        source = "type Unpack<T> = T extends Array<infer U> ? U : T"
+        result = parse(source, source_type="tsx")
+
+        infer = find_node(result.program, "TSInferType")
+        assert infer is not None
+        assert infer.get_text(source) == "infer U"
+
+    def test_import_type(self):
+        """RED: import("module").Type should be a TSImportType"""
+        source = 'type Foo = import("./module").Bar<string>;'
+        result = parse(source, source_type="tsx")
+
+        assert result.is_valid
+
+        import_type = find_node(result.program, "TSImportType")
+        assert import_type is not None
+        assert import_type.qualifier.name == "Bar"
+        assert import_type.type_arguments is not None
+        assert len(import_type.type_arguments.params) == 1
+        assert import_type.argument.get_text(source) == '"./module"'
+
+
+class TestTSKeywordTypes:
+    """Tests for TypeScript keyword types as concrete classes"""
+
+    KEYWORDS = {
+        "any": "TSAnyKeyword",
+        "bigint": "TSBigIntKeyword",
+        "boolean": "TSBooleanKeyword",
+        "never": "TSNeverKeyword",
+        "null": "TSNullKeyword",
+        "number": "TSNumberKeyword",
+        "object": "TSObjectKeyword",
+        "string": "TSStringKeyword",
+        "symbol": "TSSymbolKeyword",
+        "undefined": "TSUndefinedKeyword",
+        "unknown": "TSUnknownKeyword",
+        "void": "TSVoidKeyword",
+    }
+
+    def test_every_keyword_type_has_concrete_class(self):
+        """RED: Each keyword type should be an instance of its own pyclass"""
+        import oxc_python
+
+        for keyword, class_name in self.KEYWORDS.items():
+            source = f"type T = {keyword};"
+            result = parse(source, source_type="ts")
+            assert result.is_valid, keyword
+
+            node = find_node(result.program, "TSTypeAliasDeclaration").type_annotation
+            assert isinstance(node, getattr(oxc_python, class_name)), keyword
+            assert node.type == class_name
+            assert node.get_text(source) == keyword
+
+    def test_intrinsic_keyword(self):
+        """RED: intrinsic should produce TSIntrinsicKeyword"""
+        import oxc_python
+
+        source = "type Uppercase<S extends string> = intrinsic;"
+        result = parse(source, source_type="ts")
+
+        node = find_node(result.program, "TSTypeAliasDeclaration").type_annotation
+        assert isinstance(node, oxc_python.TSIntrinsicKeyword)
+
+    def test_this_type(self):
+        """RED: this in a return type position should produce TSThisType"""
+        import oxc_python
+
+        source = "interface Builder { set(): this; }"
+        result = parse(source, source_type="ts")
+
+        method = find_node(result.program, "TSMethodSignature")
+        node = method.return_type.type_annotation
+        assert isinstance(node, oxc_python.TSThisType)
+        assert node.type == "TSThisType"
+
+    def test_keyword_types_have_span_and_lines(self):
+        """RED: Keyword type nodes should carry span and line info"""
+        source = "let x: number;"
+        result = parse(source, source_type="ts")
+
+        node = find_node(result.program, "TSNumberKeyword")
+        assert node is not None
+        assert node.span.start == 7
+        assert node.span.end == 13
+        assert node.get_line_range(source) == (1, 1)
+
+
+class TestTSLiteralType:
+    """Tests for TypeScript literal types"""
+
+    def test_string_literal_union_members(self):
+        """RED: Both members of a string literal union should be TSLiteralType"""
+        source = 'type Status = "active" | "inactive"'
+        result = parse(source, source_type="ts")
+
+        assert result.is_valid
+
+        union = find_node(result.program, "TSTypeAliasDeclaration").type_annotation
+        assert union.type == "TSUnionType"
+        assert [member.type for member in union.types] == ["TSLiteralType", "TSLiteralType"]
+        assert [member.literal.type for member in union.types] == ["Literal", "Literal"]
+        assert [member.literal.raw for member in union.types] == ['"active"', '"inactive"']
+        assert union.types[0].literal.value == "active"
+
+    def test_numeric_literal_type(self):
+        """RED: Numeric literal types should wrap a Literal with a numeric value"""
+        source = "type N = 42;"
+        result = parse(source, source_type="ts")
+
+        literal_type = find_node(result.program, "TSTypeAliasDeclaration").type_annotation
+        assert literal_type.type == "TSLiteralType"
+        assert literal_type.literal.value == 42
+        assert literal_type.literal.raw == "42"
+
+    def test_boolean_literal_type(self):
+        """RED: Boolean literal types should wrap a Literal with a bool value"""
+        source = "type T = true;"
+        result = parse(source, source_type="ts")
+
+        literal_type = find_node(result.program, "TSTypeAliasDeclaration").type_annotation
+        assert literal_type.type == "TSLiteralType"
+        assert literal_type.literal.value is True
+        assert literal_type.get_text(source) == "true"
+
+
+class TestTSAbstractClassMembers:
+    """Tests for abstract class members"""
+
+    def test_abstract_members_in_class_body(self):
+        """RED: Abstract property and method should appear in ClassBody.body"""
+        source = "abstract class A { abstract name: string; abstract greet(): void; }"
+        result = parse(source, source_type="ts")
+
+        assert result.is_valid
+
+        class_body = find_node(result.program, "ClassBody")
+        types = [element.type for element in class_body.body]
+        assert types.count("TSAbstractPropertyDefinition") == 1
+        assert types.count("TSAbstractMethodDefinition") == 1
+
+        prop, method = class_body.body
+        assert prop.name == "name"
+        assert prop.abstract is True
+        assert prop.type_annotation.type == "TSTypeAnnotation"
+        assert method.name == "greet"
+        assert method.abstract is True
+        assert method.function_body is None
+
+    def test_abstract_members_alongside_concrete(self):
+        """RED: Concrete members keep their own node types next to abstract ones"""
+        source = """
+abstract class Shape {
+    sides = 0;
+    abstract area(): number;
+    describe() { return "shape"; }
+}
+"""
+        result = parse(source, source_type="ts")
+
+        class_body = find_node(result.program, "ClassBody")
+        assert [element.type for element in class_body.body] == [
+            "ClassProperty",
+            "TSAbstractMethodDefinition",
+            "MethodDefinition",
+        ]
+        with pytest.warns(DeprecationWarning):
+            assert [m.name for m in class_body.methods] == ["describe"]
+
+    def test_abstract_members_in_walk(self):
+        """RED: Abstract members should be reachable via walk()"""
+        source = "abstract class A { abstract name: string; abstract greet(): void; }"
+        result = parse(source, source_type="ts")
+
+        node_types = [node.type for node, _ in walk(result.program)]
+        assert "TSAbstractPropertyDefinition" in node_types
+        assert "TSAbstractMethodDefinition" in node_types
+
+
+class TestTSTupleTypes:
+    """Tests for tuple types with optional and rest elements"""
+
+    def test_tuple_optional_and_rest_elements(self):
+        """RED: Optional and rest tuple elements get dedicated node types"""
+        source = "type T = [string, number?, ...boolean[]]"
+        result = parse(source, source_type="ts")
+
+        assert result.is_valid
+
+        tuple_type = find_node(result.program, "TSTypeAliasDeclaration").type_annotation
+        assert tuple_type.type == "TSTupleType"
+        assert len(tuple_type.element_types) == 3
+
+        first, second, third = tuple_type.element_types
+        assert first.type == "TSStringKeyword"
+
+        assert second.type == "TSOptionalType"
+        assert second.type_annotation.type == "TSNumberKeyword"
+        assert second.get_text(source) == "number?"
+
+        assert third.type == "TSRestType"
+        assert third.type_annotation.type == "TSArrayType"
+        assert third.type_annotation.element_type.type == "TSBooleanKeyword"
+
+    def test_tuple_elements_in_walk(self):
+        """RED: Tuple element nodes should be reachable via walk()"""
+        source = "type T = [string, number?, ...boolean[]]"
+        result = parse(source, source_type="ts")
+
+        node_types = [node.type for node, _ in walk(result.program)]
+        assert "TSOptionalType" in node_types
+        assert "TSRestType" in node_types
+        assert "TSBooleanKeyword" in node_types
+
+
+class TestTSNameExtraction:
+    """Tests for extract_ts_type_names and declared-name helpers"""
+
+    SOURCE = """
+interface User { id: UserId; profile: Profile; }
+interface Admin extends User { roles: Role[]; }
+type UserId = string;
+type Lookup = Map<UserId, User>;
+function load(id: UserId): Promise<User> { return fetchUser(id); }
+"""
+
+    def test_type_reference_names_are_unique(self):
+        """RED: All referenced type names appear once"""
+        import oxc_python
+
+        result = parse(self.SOURCE, source_type="ts")
+        names = oxc_python.extract_ts_type_names(result.program)
+
+        assert len(names) == len(set(names))
+        assert {"UserId", "Profile", "Role", "Map", "User", "Promise"} <= set(names)
+
+    def test_declared_interface_and_alias_names(self):
+        """RED: Declared interface and type alias names are collected"""
+        import oxc_python
+
+        result = parse(self.SOURCE, source_type="ts")
+
+        assert oxc_python.extract_ts_interface_names(result.program) == ["User", "Admin"]
+        assert oxc_python.extract_ts_type_alias_names(result.program) == ["UserId", "Lookup"]
+
+
+class TestTSTypeParameterModifiers:
+    """Tests for in/out/const modifiers on type parameters"""
+
+    def test_variance_modifiers(self):
+        """RED: `out T` sets out_modifier only"""
+        result = parse("type Box<out T> = { value: T }", source_type="ts")
+        assert result.is_valid
+
+        param = find_node(result.program, "TSTypeParameter")
+        assert param.name == "T"
+        assert param.out_modifier is True
+        assert param.in_modifier is False
+        assert param.const_modifier is False
+
+    def test_in_out_modifiers(self):
+        """RED: `in out T` sets both variance modifiers"""
+        result = parse("interface Cell<in out T> { value: T }", source_type="ts")
+
+        param = find_node(result.program, "TSTypeParameter")
+        assert param.in_modifier is True
+        assert param.out_modifier is True
+
+    def test_const_modifier(self):
+        """RED: `const T` sets const_modifier"""
+        result = parse("function f<const T extends string>(x: T) {}", source_type="ts")
+
+        param = find_node(result.program, "TSTypeParameter")
+        assert param.const_modifier is True
+        assert param.in_modifier is False
+        assert param.out_modifier is False
+
+    def test_variance(self):
+        """RED: variance combines the in/out modifiers into one string"""
+        out = find_node(parse("type Box<out T> = T", source_type="ts").program, "TSTypeParameter")
+        assert out.variance == "out"
+        assert out.is_covariant() is True
+        assert out.is_contravariant() is False
+
+        action = parse("type Action<in T> = (x: T) => void", source_type="ts")
+        param = find_node(action.program, "TSTypeParameter")
+        assert param.variance == "in"
+        assert param.is_contravariant() is True
+        assert param.is_covariant() is False
+
+        cell = find_node(parse("interface Cell<in out T> {}", source_type="ts").program, "TSTypeParameter")
+        assert cell.variance == "in out"
+        assert cell.is_bivariant() is True
+
+        plain = find_node(parse("type Id<T> = T", source_type="ts").program, "TSTypeParameter")
+        assert plain.variance == "invariant"
+        assert not (plain.is_covariant() or plain.is_contravariant() or plain.is_bivariant())
+
+
+class TestTSEnumMemberValues:
+    """Tests for TSEnumMember.string_value and numeric_value"""
+
+    def test_literal_and_computed_values(self):
+        """RED: literal initializers expose their value, computed ones do not"""
+        source = 'enum Status { Active = "active", Count = 42, Computed = 1 + 1 }'
+        result = parse(source, source_type="ts")
+        assert result.is_valid
+
+        members = {m.id.name: m for m in find_all_nodes(result.program, "TSEnumMember")}
+
+        assert members["Active"].string_value == "active"
+        assert members["Active"].numeric_value is None
+        assert members["Count"].numeric_value == 42.0
+        assert members["Count"].string_value is None
+        assert members["Computed"].string_value is None
+        assert members["Computed"].numeric_value is None
+
+    def test_initializer_is_full_expression(self):
+        """RED: initializer is the converted expression node"""
+        result = parse("enum E { A = 1 + 1, B }", source_type="ts")
+
+        members = find_all_nodes(result.program, "TSEnumMember")
+        assert members[0].initializer.type == "BinaryExpression"
+        assert members[1].initializer is None
+
+    def test_value_map_auto_increments(self):
+        """RED: value_map() numbers uninitialized members from the previous value"""
+        result = parse("enum E { A, B = 10, C }", source_type="ts")
+        enum = find_node(result.program, "TSEnumDeclaration")
+
+        assert enum.value_map() == {"A": 0, "B": 10, "C": 11}
+        assert isinstance(enum.value_map()["C"], int)
+
+    def test_value_map_strings_and_const_enum(self):
+        """RED: value_map() returns string values, const enums included"""
+        result = parse('const enum S { X = "x", Y = "y" }', source_type="ts")
+        enum = find_node(result.program, "TSEnumDeclaration")
+
+        assert enum.is_const
+        assert enum.value_map() == {"X": "x", "Y": "y"}
+
+    def test_value_map_unknown_after_computed(self):
+        """RED: computed values, and members following them, map to None"""
+        result = parse("enum F { A = 1 << 2, B, C = 1.5, D }", source_type="ts")
+        enum = find_node(result.program, "TSEnumDeclaration")
+
+        assert enum.value_map() == {"A": None, "B": None, "C": 1.5, "D": 2.5}
+
+    def test_value_map_signed_numbers(self):
+        """RED: unary minus and plus on numeric literals are folded"""
+        result = parse("enum K { A = +5, B, C = -1, D }", source_type="ts")
+        enum = find_node(result.program, "TSEnumDeclaration")
+
+        assert enum.value_map() == {"A": 5, "B": 6, "C": -1, "D": 0}
+        assert find_all_nodes(result.program, "TSEnumMember")[2].numeric_value == -1.0
+
+
+class TestTSInterfaceHeritage:
+    """Tests for TSInterfaceHeritage nodes in interface extends clauses"""
+
+    def test_extends_with_and_without_type_arguments(self):
+        """RED: each extended interface is a TSInterfaceHeritage"""
+        result = parse("interface Foo extends Bar<string>, Baz {}", source_type="ts")
+        assert result.is_valid
+
+        interface = find_node(result.program, "TSInterfaceDeclaration")
+        first, second = interface.extends
+        assert first.type == "TSInterfaceHeritage"
+        assert second.type == "TSInterfaceHeritage"
+
+        assert first.expression.name == "Bar"
+        assert first.type_arguments is not None
+        assert first.type_arguments.params[0].type == "TSStringKeyword"
+
+        assert second.expression.name == "Baz"
+        assert second.type_arguments is None
+
+    def test_extends_member_expression(self):
+        """RED: qualified names are converted as member expressions"""
+        result = parse("interface Foo extends ns.Bar {}", source_type="ts")
+
+        heritage = find_node(result.program, "TSInterfaceHeritage")
+        assert heritage.expression.type == "MemberExpression"
+
+
+class TestFunctionOverloads:
+    """Tests for FunctionDeclaration.overloads"""
+
+    def test_overloads_grouped_into_implementation(self):
+        """RED: overload signatures move into the implementation's overloads"""
+        source = (
+            "function f(x: string): string;\n"
+            "function f(x: number): number;\n"
+            "function f(x: any) { return x; }\n"
+        )
+        result = parse(source, source_type="ts")
+        assert result.is_valid
+
+        assert len(result.program.body) == 1
+        impl = result.program.body[0]
+        assert impl.name == "f"
+        assert impl.body is not None
+        assert len(impl.overloads) == 2
+        assert all(o.body is None for o in impl.overloads)
+
+        names = [n.name for n, _ in walk(result.program) if n.type == "FunctionDeclaration"]
+        assert names == ["f", "f", "f"]
+
+    def test_signatures_without_implementation_kept(self):
+        """RED: declarations with no implementation stay in the body"""
+        source = "function f(x: string): void;\nfunction g() {}\n"
+        result = parse(source, source_type="ts")
+
+        assert len(result.program.body) == 2
+        assert result.program.body[1].overloads == []
+
+    def test_exported_overloads_grouped(self):
+        """RED: exported overload signatures group under the exported implementation"""
+        source = "export function f(a: string): void;\nexport function f(a: any) {}\n"
+        result = parse(source, source_type="ts")
+        assert result.is_valid
+
+        assert len(result.program.body) == 1
+        export = result.program.body[0]
+        assert export.type == "ExportNamedDeclaration"
+        assert export.declaration.body is not None
+        assert len(export.declaration.overloads) == 1
+
+    def test_overloads_not_grouped_across_export(self):
+        """RED: signatures only group with an implementation of the same export kind"""
+        source = "export function f(x: string): string;\nfunction f(x) {}\n"
+        result = parse(source, source_type="ts")
+        assert [s.type for s in result.program.body] == ["ExportNamedDeclaration", "FunctionDeclaration"]
+        assert result.program.body[1].overloads == []
+        assert result.export_names() == ["f"]
+
+        source = "function g(x: string): string;\nexport function g(x) {}\n"
+        result = parse(source, source_type="ts")
+        assert [s.type for s in result.program.body] == ["FunctionDeclaration", "ExportNamedDeclaration"]
+        assert result.program.body[1].declaration.overloads == []
+        assert result.export_names() == ["g"]
+
+    def test_namespace_overloads_grouped(self):
+        """RED: overloads inside a namespace body are grouped"""
+        source = "namespace N {\n  function f(a: string): void;\n  function f(a: any) {}\n}\n"
+        result = parse(source, source_type="ts")
+        assert result.is_valid
+
+        ns = result.program.body[0]
+        assert ns.type == "TSModuleDeclaration"
+        assert ns.name == "N"
+        assert ns.kind == "namespace"
+        assert ns.body.type == "TSModuleBlock"
+        assert len(ns.body.body) == 1
+        assert len(ns.body.body[0].overloads) == 1
+
+
+class TestTSInterfaceMemberNames:
+    """Tests for TSInterfaceDeclaration.property_names and method_names"""
+
+    def test_property_and_method_names(self):
+        """RED: members are split into property and method names"""
+        source = "interface User { id: number; name: string; greet(): void; }"
+        result = parse(source, source_type="ts")
+
+        interface = find_node(result.program, "TSInterfaceDeclaration")
+        assert interface.property_names() == ["id", "name"]
+        assert interface.method_names() == ["greet"]
+
+    def test_string_and_computed_keys(self):
+        """RED: string keys use their value and computed keys are skipped"""
+        source = 'interface U { "quoted": string; [key]: number; "run"(): void; [Symbol.iterator](): void; }'
+        result = parse(source, source_type="ts")
+
+        interface = find_node(result.program, "TSInterfaceDeclaration")
+        assert interface.property_names() == ["quoted"]
+        assert interface.method_names() == ["run"]
+
+    def test_empty_interface(self):
+        """RED: an empty interface has no member names"""
+        result = parse("interface Empty {}", source_type="ts")
+
+        interface = find_node(result.program, "TSInterfaceDeclaration")
+        assert interface.property_names() == []
+        assert interface.method_names() == []
+
+
+class TestTSInterfaceIteration:
+    """Tests for iterating interface signatures without the double .body"""
+
+    def test_iterate_interface_signatures(self):
+        """RED: iterating an interface yields its body's signatures"""
+        source = "interface User { id: number; name: string; greet(): void; }"
+        result = parse(source, source_type="ts")
+
+        iface = find_node(result.program, "TSInterfaceDeclaration")
+        assert [sig.type for sig in iface] == [
+            "TSPropertySignature",
+            "TSPropertySignature",
+            "TSMethodSignature",
+        ]
+        assert len(iface) == 3
+        assert iface.signature_count() == len(iface.body.body) == 3
+        assert [sig.name() for sig in iface.body] == ["id", "name", "greet"]
+        assert len(iface.body) == 3
+
+    def test_empty_interface_iteration(self):
+        """RED: an empty interface iterates over nothing"""
+        iface = find_node(parse("interface Empty {}", source_type="ts").program, "TSInterfaceDeclaration")
+        assert list(iface) == []
+        assert iface.signature_count() == 0
+
+
+class TestMethodDefinitionTypes:
+    """Tests for MethodDefinition.return_type and type_parameters"""
+
+    def test_generic_method_with_return_type(self):
+        """RED: method return type and type parameters are converted"""
+        source = "class Greeter { async greet<T>(x: T): Promise<string> { return ''; } }"
+        result = parse(source, source_type="ts")
+        assert result.is_valid
+
+        method = find_node(result.program, "MethodDefinition")
+        assert method.return_type.type == "TSTypeAnnotation"
+        assert method.return_type.type_annotation.type == "TSTypeReference"
+        assert method.return_type.type_str(source) == "Promise<string>"
+        assert method.type_parameters.params[0].name == "T"
+
+    def test_untyped_method(self):
+        """RED: plain JS methods have no return type or type parameters"""
+        result = parse("class A { m() {} }")
+
+        method = find_node(result.program, "MethodDefinition")
+        assert method.return_type is None
+        assert method.type_parameters is None
+
+
+class TestParseTypescriptType:
+    """Tests for parse_typescript_type() standalone type parsing"""
+
+    def test_union_type(self):
+        """RED: a union type string parses to TSUnionType"""
+        import oxc_python
+
+        node = oxc_python.parse_typescript_type("string | number")
+        assert node.type == "TSUnionType"
+        assert [t.type for t in node.types] == ["TSStringKeyword", "TSNumberKeyword"]
+
+    def test_object_type(self):
+        """RED: an object type literal parses to a generic type node"""
+        import oxc_python
+
+        node = oxc_python.parse_typescript_type("{ x: number }")
+        assert node.type == "TSType"
+
+    def test_generic_reference(self):
+        """RED: generic references keep their type arguments"""
+        import oxc_python
+
+        node = oxc_python.parse_typescript_type("Record<string, number[]>")
+        assert node.type == "TSTypeReference"
+
+    def test_invalid_type_raises(self):
+        """RED: invalid or trailing input raises ValueError"""
+        import oxc_python
+
+        with pytest.raises(ValueError):
+            oxc_python.parse_typescript_type("<<")
+        with pytest.raises(ValueError):
+            oxc_python.parse_typescript_type("string; let x = 1")
+
+
+class TestTSExpressions:
+    """Tests for TypeScript expression wrappers and class member modifiers."""
+
+    def test_as_satisfies_and_non_null(self):
+        """RED: `as`, `satisfies` and `!` keep the wrapped expression and type"""
+        import oxc_python
+
+        source = "a as string;\nb satisfies Config;\nc!;"
+        body = oxc_python.parse(source, source_type="ts").program.body
+
+        as_expr, satisfies, non_null = (stmt.expression for stmt in body)
+        assert isinstance(as_expr, oxc_python.TSAsExpression)
+        assert as_expr.expression.name == "a"
+        assert as_expr.type_annotation.type == "TSStringKeyword"
+        assert satisfies.type == "TSSatisfiesExpression"
+        assert satisfies.type_annotation.get_text(source) == "Config"
+        assert non_null.type == "TSNonNullExpression"
+        assert non_null.expression.name == "c"
+
+    def test_type_assertion_and_instantiation(self):
+        """RED: `<T>x` and `f<T>` become TSTypeAssertion and TSInstantiationExpression"""
+        import oxc_python
+
+        body = oxc_python.parse("<number>x;\nconst g = f<string>;", source_type="ts").program.body
+
+        assertion = body[0].expression
+        assert assertion.type == "TSTypeAssertion"
+        assert assertion.type_annotation.type == "TSNumberKeyword"
+        inst = body[1].declarations[0].init
+        assert inst.type == "TSInstantiationExpression"
+        assert inst.expression.name == "f"
+        assert inst.type_arguments is not None
+
+    def test_call_type_arguments(self):
+        """RED: f<string>() exposes its type arguments on the CallExpression"""
+        import oxc_python
+
+        call = oxc_python.parse("f<string>()", source_type="ts").program.body[0].expression
+        assert call.type == "CallExpression"
+        assert call.type_arguments is not None
+        assert oxc_python.parse("f()").program.body[0].expression.type_arguments is None
+
+    def test_class_member_accessibility(self):
+        """RED: accessibility modifiers are exposed on properties and methods"""
+        import oxc_python
+
+        source = "class A { private x = 1; protected m() {} y = 2; }"
+        members = oxc_python.parse(source, source_type="ts").program.body[0].body.body
+
+        assert [m.accessibility for m in members] == ["private", "protected", None]
+
+
+class TestTSModuleDeclaration:
+    """Tests for namespace and ambient module declarations."""
+
+    def test_namespace_body(self):
+        """RED: namespace bodies are converted statements in a TSModuleBlock"""
+        result = parse("namespace N { const x = 1; }", source_type="ts")
+
+        ns = result.program.body[0]
+        assert ns.type == "TSModuleDeclaration"
+        assert ns.name == "N"
+        assert ns.kind == "namespace"
+        assert ns.declare is False
+        assert ns.body.type == "TSModuleBlock"
+        assert [stmt.type for stmt in ns.body.body] == ["VariableDeclaration"]
+
+    def test_dotted_namespace_and_ambient_module(self):
+        """RED: `namespace A.B` nests declarations; `declare module "m"` keeps its string name"""
+        result = parse('namespace A.B {}\ndeclare module "m" {}', source_type="ts")
+
+        outer, ambient = result.program.body
+        assert outer.name == "A"
+        assert outer.body.type == "TSModuleDeclaration"
+        assert outer.body.name == "B"
+        assert ambient.name == "m"
+        assert ambient.kind == "module"
+        assert ambient.declare is True
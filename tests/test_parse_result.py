@@ -49,3 +49,213 @@ def test_parse_result_not_tuple():
     assert not isinstance(result, tuple)
     assert hasattr(result, "program")
     assert hasattr(result, "errors")
+
+
+def test_parse_result_repr_includes_statements_and_source_type():
+    """Verify __repr__ reports statement count and source type."""
+    import oxc_python
+
+    result = oxc_python.parse("let a = 1; let b = 2;", source_type="typescript")
+
+    assert result.source_type == "typescript"
+    assert repr(result) == (
+        "ParseResult(is_valid=True, source_type='typescript', statements=2, errors=0, comments=0)"
+    )
+
+
+def test_parse_result_summary_lists_errors():
+    """Verify summary() shows each error's message, line and span."""
+    import oxc_python
+
+    result = oxc_python.parse("const a = 1;\nconst x = ;")
+    error = result.errors[0]
+    summary = result.summary()
+
+    assert error.start_line == 2
+    assert error.message in summary
+    assert f"line 2: {error.message} (span {error.span.start}..{error.span.end})" in summary
+
+
+def test_parse_result_summary_top_node_types():
+    """Verify summary() shows the five most common node types by count."""
+    import oxc_python
+
+    result = oxc_python.parse("let a = b; let c = d; f(a, c);")
+    summary = result.summary()
+
+    assert summary.splitlines()[0] == "ParseResult (module)"
+    assert "  statements: 3" in summary
+    node_types = summary.split("  node types:\n")[1].splitlines()
+    assert len(node_types) == 5
+    assert node_types[0] == "    Identifier: 7"
+    assert node_types[1] == "    VariableDeclaration: 2"
+
+
+def test_parse_result_has_errors():
+    """Verify has_errors is the opposite of is_valid."""
+    import oxc_python
+
+    assert oxc_python.parse("const a = 1;").has_errors is False
+    assert oxc_python.parse("const x = ;").has_errors is True
+
+
+def test_parse_result_has_jsx_and_typescript():
+    """Verify has_jsx and has_typescript detect JSX and TS nodes."""
+    import oxc_python
+
+    tsx = oxc_python.parse("const App = (p: Props) => <div>{p.x}</div>;", source_type="tsx")
+    assert tsx.has_jsx is True
+    assert tsx.has_typescript is True
+    # Cached value is returned on repeated access
+    assert tsx.has_jsx is True
+
+    js = oxc_python.parse("const a = [1, 2].map(x => x * 2);")
+    assert js.has_jsx is False
+    assert js.has_typescript is False
+
+    jsx = oxc_python.parse("const el = <span>hi</span>;", source_type="jsx")
+    assert jsx.has_jsx is True
+    assert jsx.has_typescript is False
+
+
+def test_parse_result_has_typescript_for_expression_level_syntax():
+    """Verify has_typescript sees TS syntax that has no type annotation."""
+    import oxc_python
+
+    for source in ["z as any", "y!", "f<string>()", "class A { private x = 1 }"]:
+        result = oxc_python.parse(source, source_type="ts")
+        assert result.has_typescript is True, source
+
+    assert oxc_python.parse("class A { x = 1 }", source_type="ts").has_typescript is False
+
+
+def test_parse_result_import_paths_and_export_names():
+    """Verify import_paths() and export_names() list module imports and exports."""
+    import oxc_python
+
+    source = """
+    import a, { b } from 'a';
+    import * as c from "./c";
+    export { x as y } from './re';
+    export * from 'star';
+    export const p = 1, q = 2;
+    export function f() { return import('./lazy'); }
+    export default class {}
+    """
+    result = oxc_python.parse(source)
+
+    assert result.import_paths() == ["a", "./c", "./re", "star", "./lazy"]
+    assert result.export_names() == ["y", "p", "q", "f", "default"]
+    # Repeated calls return the cached result
+    assert result.import_paths() == result.import_paths()
+    assert result.export_names() == ["y", "p", "q", "f", "default"]
+
+
+def test_parse_result_required_modules():
+    """Verify required_modules() lists string arguments of require() calls."""
+    import oxc_python
+
+    result = oxc_python.parse(
+        "const fs = require('fs');\nfunction load(name) { require('path'); return require(name); }"
+    )
+    assert result.required_modules() == ["fs", "path"]
+    assert result.required_modules() == ["fs", "path"]
+    assert oxc_python.parse("import fs from 'fs';").required_modules() == []
+
+
+def test_parse_result_source_hash():
+    """Verify source_hash is deterministic and matches hash_source()."""
+    import oxc_python
+
+    first = oxc_python.parse("const a = 1;")
+    second = oxc_python.parse("const a = 1;")
+    other = oxc_python.parse("const a = 2;")
+
+    assert first.source_hash == second.source_hash
+    assert first.source_hash != other.source_hash
+    assert len(first.source_hash) == 16
+    int(first.source_hash, 16)
+    assert oxc_python.hash_source("const a = 1;") == first.source_hash
+    # FNV-1a 64-bit offset basis for the empty string
+    assert oxc_python.hash_source("") == "cbf29ce484222325"
+    assert oxc_python.hash_source("a") == "af63dc4c8601ec8c"
+
+
+def test_parse_result_to_json_matches_to_dict():
+    """Verify to_json() output loads back to the same tree as to_dict()."""
+    import json
+
+    import oxc_python
+
+    source = """// header
+import { a } from "./a";
+export interface Box<T> { value: T; get(): T }
+export class Store<T> extends Base implements Box<T> {
+  private items: T[] = [];
+  get(): T { return this.items[0]!; }
+}
+export const f = async (x: number = 1, ...rest: string[]) => `v${x}` satisfies string;
+"""
+    result = oxc_python.parse(source, source_type="ts")
+
+    def plain(value):
+        if isinstance(value, (list, tuple)):
+            return [plain(v) for v in value]
+        if isinstance(value, dict):
+            return {k: plain(v) for k, v in value.items()}
+        if hasattr(value, "to_dict"):
+            return plain(value.to_dict())
+        return value
+
+    data = json.loads(result.to_json())
+    assert data["program"] == plain(result.program)
+    assert data["source_type"] == "typescript"
+    assert data["source_hash"] == result.source_hash
+    assert data["errors"] == []
+    assert [c["text"] for c in data["comments"]] == [" header"]
+    # Pretty-printing changes only the layout
+    assert json.loads(result.to_json(indent=2)) == data
+    assert "\n  " in result.to_json(indent=2)
+
+
+def test_parse_result_from_json_round_trip():
+    """Verify from_json() restores a result whose tree and metadata match the original."""
+    import oxc_python
+
+    source = """// header
+import { a } from "./a";
+export interface Box<T> { value: T; get(): T }
+export class Store<T> extends Base implements Box<T> {
+  private items: T[] = [];
+  get(): T { return this.items[0]!; }
+}
+export const f = async (x: number = 1, ...rest: string[]) => `v${x}` satisfies string;
+"""
+    result = oxc_python.parse(source, source_type="ts", filename="store.ts")
+    cached = result.to_json()
+    restored = oxc_python.ParseResult.from_json(cached)
+
+    assert restored.to_json() == cached
+    assert restored.source_type == result.source_type
+    assert restored.source_file == "store.ts"
+    assert restored.source_hash == result.source_hash
+    assert [(c.text, c.span, c.start_line) for c in restored.comments] == [
+        (c.text, c.span, c.start_line) for c in result.comments
+    ]
+
+    original = [(n.type, n.span) for n, _ in oxc_python.walk(result.program)]
+    assert [(n.type, n.span) for n, _ in oxc_python.walk(restored.program)] == original
+    assert restored.has_typescript is True
+    assert restored.import_paths() == ["./a"]
+
+    store = restored.program.body[2].declaration
+    assert isinstance(store, oxc_python.JsonNode)
+    assert store.name == "Store"
+    assert store.get_text(source).startswith("class Store")
+
+    invalid = oxc_python.parse("const x = 1;\nconst bad = ;", filename="bad.js")
+    restored = oxc_python.ParseResult.from_json(invalid.to_json())
+    assert not restored.is_valid
+    assert [(e.message, e.span, e.start_line, e.source_file) for e in restored.errors] == [
+        (e.message, e.span, e.start_line, e.source_file) for e in invalid.errors
+    ]
@@ -87,6 +87,33 @@ def test_parse_accepts_source_parameter():
     assert result is not None
 
 
+def test_parse_many_with_progress_reports_each_file():
+    """Verify parse_many_with_progress() calls back once per source."""
+    import oxc_python
+
+    sources = [f"const x{i} = {i};" for i in range(5)] + ["const broken = ;"]
+    calls = []
+
+    results = oxc_python.parse_many_with_progress(
+        sources, lambda done, total: calls.append((done, total)), source_type="module"
+    )
+
+    assert len(results) == len(sources)
+    assert [r.is_valid for r in results] == [True] * 5 + [False]
+    assert calls == [(i, len(sources)) for i in range(1, len(sources) + 1)]
+
+
+def test_parse_many_with_progress_callback_error_stops_batch():
+    """Verify an exception from the callback propagates."""
+    import oxc_python
+
+    def on_progress(done, total):
+        raise RuntimeError("stop")
+
+    with pytest.raises(RuntimeError):
+        oxc_python.parse_many_with_progress(["a;", "b;"], on_progress)
+
+
 # ==============================================================================
 # Allocator Tests
 # ==============================================================================
@@ -166,6 +193,24 @@ def test_allocator_reuse_large_batch():
         allocator.reset()
 
 
+def test_allocator_parse_many():
+    """Test Allocator.parse_many() parses a batch and resets internally."""
+    import oxc_python
+
+    allocator = oxc_python.Allocator()
+    sources = [f"function f{i}() {{ return {i}; }}" for i in range(100)]
+
+    results = allocator.parse_many(sources)
+    assert len(results) == 100
+    assert all(result.is_valid for result in results)
+    assert results[42].program.body[0].name == "f42"
+
+    # A second batch on the same allocator needs no manual reset
+    again = allocator.parse_many(["let x: number = 1;", "const y = 2;"], source_type="ts")
+    assert [result.is_valid for result in again] == [True, True]
+    assert allocator.parse_many([]) == []
+
+
 # ==============================================================================
 # source_type Parameter Tests
 # ==============================================================================
@@ -181,11 +226,121 @@ def test_source_type_parameter_exists():
 
 
 def test_source_type_module_default():
-    """Verify default source_type is 'module'."""
+    """Verify plain JavaScript is detected as 'module' by default."""
     import oxc_python
 
     result = oxc_python.parse("const x = 1;")
     assert result.is_valid
+    assert result.source_type == "module"
+
+
+def test_source_type_auto_detects_tsx():
+    """Verify the default 'auto' source_type handles JSX mixed with TypeScript."""
+    import oxc_python
+
+    source = """
+    interface Props { name: string }
+    const App = (props: Props) => <div className="app">{props.name}</div>;
+    """
+    result = oxc_python.parse(source)
+
+    assert result.is_valid, f"TSX should parse without source_type: {result.errors}"
+    assert result.source_type == "tsx"
+    assert oxc_python.parse(source, source_type="auto").source_type == "tsx"
+
+
+def test_source_type_auto_detects_jsx_and_typescript():
+    """Verify 'auto' picks jsx, ts, and script from the source alone."""
+    import oxc_python
+
+    assert oxc_python.parse("render(<App />);").source_type == "jsx"
+    assert oxc_python.parse("type Id = string;").source_type == "typescript"
+    assert oxc_python.parse("let x: number = 1;").source_type == "typescript"
+    assert oxc_python.parse("#!/usr/bin/env node\nrequire('x');").source_type == "script"
+    # Angle brackets in comparisons and strings are not JSX
+    assert oxc_python.parse("if (a < b) { s = '<div>'; }").source_type == "module"
+
+
+def test_source_type_auto_skips_regex_literals():
+    """Verify quotes inside regex literals don't hide the rest of the source."""
+    import oxc_python
+
+    result = oxc_python.parse("const r = /'/; let x: number = 1")
+    assert result.source_type == "typescript"
+    assert result.is_valid, result.errors
+
+    assert oxc_python.parse("const r = /[/]`/g; render(<App />);").source_type == "jsx"
+    # Division is not a regex
+    assert oxc_python.parse("const half = total / 2; let y: string = '/';").source_type == "typescript"
+
+
+def test_source_type_auto_detects_annotations_and_generics():
+    """Verify 'auto' detects TypeScript annotations, generics, and type-only syntax."""
+    import oxc_python
+
+    for source in [
+        "function f(a: User) {}",
+        "const x: Foo = 1;",
+        "function f<T>(a: T): T { return a; }",
+        "import type {A} from 'b';",
+        "import { type A } from 'b';",
+        "const y = x as Foo;",
+        "const c = { a: 1 } satisfies Config;",
+        "function f(a?: number) {}",
+        "class A { x: number = 1; y!: string; }",
+        "const f = (a): Foo => a;",
+    ]:
+        result = oxc_python.parse(source)
+        assert result.source_type == "typescript", source
+        assert result.is_valid, (source, result.errors)
+
+
+def test_source_type_auto_detects_tsx_generics_and_casts():
+    """Verify `<T,>` arrows and `as` inside JSX are not mistaken for JSX-only source."""
+    import oxc_python
+
+    for source in ["const id = <T,>(a: T) => a;", "<T,>(a: T) => a"]:
+        result = oxc_python.parse(source)
+        assert result.source_type == "typescript", source
+        assert result.is_valid, (source, result.errors)
+
+    for source in ["const C = <T,>(p: T) => <div />;", "const el = <div>{x as any}</div>;"]:
+        result = oxc_python.parse(source)
+        assert result.source_type == "tsx", source
+        assert result.is_valid, (source, result.errors)
+
+
+def test_source_type_auto_keeps_javascript_colons():
+    """Verify object literals, conditionals, labels, and module aliases stay JavaScript."""
+    import oxc_python
+
+    for source in [
+        "const o = { name: string };",
+        "const v = a ? b : c;",
+        "f(a ? b : c, { k: v });",
+        "outer: for (;;) { break outer; }",
+        "switch (x) { case a: break; default: y(); }",
+        "import { a as b } from 'm'; export * as ns from 'n';",
+        "import React, { useState as useS } from 'react';",
+        "import type from 'type';",
+        "const r = a?.b ?? c;",
+    ]:
+        result = oxc_python.parse(source)
+        assert result.source_type == "module", source
+        assert result.is_valid, (source, result.errors)
+
+
+def test_source_type_auto_falls_back_to_typescript():
+    """Verify TypeScript the scan misses still parses when detected JavaScript fails."""
+    import oxc_python
+
+    result = oxc_python.parse("const m = new Map<string, number>();")
+    assert result.source_type == "typescript"
+    assert result.is_valid, result.errors
+
+    result = oxc_python.parse("const bad = ;")
+    assert result.source_type == "module"
+    assert not result.is_valid
 
 
 def test_source_type_tsx_parses_typescript():
@@ -0,0 +1,148 @@
+"""
+Scope Analysis Tests
+
+Tests for the collect_scopes() function and the Scope tree it builds.
+"""
+
+
+def test_collect_scopes_returns_module_scope():
+    """RED: Test that collect_scopes() returns the module scope."""
+    import oxc_python
+
+    source = "const a = 1; let b = 2;"
+    result = oxc_python.parse(source)
+    scope = oxc_python.collect_scopes(result.program)
+
+    assert isinstance(scope, oxc_python.Scope)
+    assert scope.kind == "module"
+    assert scope.bindings == ["a", "b"]
+    assert scope.span.start == 0
+    assert scope.span.end == len(source)
+
+
+def test_nested_block_scope_bindings():
+    """RED: Test that let/const inside a nested block bind in the block scope."""
+    import oxc_python
+
+    source = """
+function outer(a) {
+    const top = 1;
+    if (a) {
+        let inner = 2;
+        const other = 3;
+    }
+}
+"""
+    result = oxc_python.parse(source)
+    module = oxc_python.collect_scopes(result.program)
+
+    assert module.bindings == ["outer"]
+
+    function = module.children[0]
+    assert function.kind == "function"
+    assert function.bindings == ["a", "top"]
+
+    block = function.children[0]
+    assert block.kind == "block"
+    assert block.bindings == ["inner", "other"]
+
+
+def test_var_is_hoisted_to_function_scope():
+    """RED: Test that var declarations bind in the enclosing function scope."""
+    import oxc_python
+
+    source = "function f() { { var hoisted = 1; let local = 2; } }"
+    result = oxc_python.parse(source)
+    function = oxc_python.collect_scopes(result.program).children[0]
+
+    assert function.bindings == ["hoisted"]
+    assert function.children[0].bindings == ["local"]
+
+
+def test_arrow_and_class_scopes():
+    """RED: Test arrow functions, classes and methods create scopes."""
+    import oxc_python
+
+    source = """
+const f = (x) => x;
+class K {
+    m(p) { let q; }
+}
+"""
+    result = oxc_python.parse(source)
+    module = oxc_python.collect_scopes(result.program)
+
+    assert module.bindings == ["f", "K"]
+    assert [child.kind for child in module.children] == ["function", "class"]
+    assert module.children[0].bindings == ["x"]
+
+    method = module.children[1].children[0]
+    assert method.kind == "function"
+    assert method.bindings == ["p", "q"]
+
+
+def test_import_bindings_in_module_scope():
+    """RED: Test that import specifiers bind in the module scope."""
+    import oxc_python
+
+    source = "import React, { useState as useS } from 'react';"
+    result = oxc_python.parse(source)
+    module = oxc_python.collect_scopes(result.program)
+
+    assert module.bindings == ["React", "useS"]
+
+
+def test_destructuring_binds_every_name():
+    """RED: Test that destructured declarations, params and catch bindings bind each name."""
+    import oxc_python
+
+    source = """
+const {a, b: [c, ...d]} = x;
+function f({p}, [q = 1], ...{length}) {}
+try {} catch ({message}) { let handled; }
+"""
+    result = oxc_python.parse(source)
+    module = oxc_python.collect_scopes(result.program)
+
+    assert module.bindings == ["a", "c", "d", "f"]
+    function, _try_block, catch = module.children
+    assert function.bindings == ["p", "q", "length"]
+    assert catch.kind == "block"
+    assert catch.bindings == ["message", "handled"]
+
+
+def test_named_function_expression_binds_in_own_scope():
+    """RED: Test that a function expression's name does not leak into the enclosing scope."""
+    import oxc_python
+
+    source = "const g = function h(n) { return h(n); }; const K = class Inner {};"
+    result = oxc_python.parse(source)
+    module = oxc_python.collect_scopes(result.program)
+
+    assert module.bindings == ["g", "K"]
+    function, klass = module.children
+    assert function.kind == "function"
+    assert function.bindings == ["h", "n"]
+    assert klass.kind == "class"
+    assert klass.bindings == ["Inner"]
+
+
+def test_for_loop_let_binds_in_loop_scope():
+    """RED: Test that let/const in a for head bind in the loop's own block scope."""
+    import oxc_python
+
+    source = """
+function f(items) {
+    for (let i = 0; i < 3; i++) {}
+    for (const item of items) {}
+    for (var k in items) {}
+}
+"""
+    result = oxc_python.parse(source)
+    function = oxc_python.collect_scopes(result.program).children[0]
+
+    assert function.bindings == ["items", "k"]
+    for_let, for_of = function.children[:2]
+    assert for_let.kind == "block"
+    assert for_let.bindings == ["i"]
+    assert for_of.bindings == ["item"]
@@ -232,3 +232,508 @@ def test_chunkhound_hierarchical_filtering():
 
     # Should find 3 top-level declarations
     assert len(top_level) >= 2, "Should find top-level declarations"
+
+
+def test_node_at_offset_finds_innermost_node():
+    """RED: Test node_at_offset() returns the deepest node containing the offset."""
+    import oxc_python
+
+    source = "const foo = bar + 42;"
+    result = oxc_python.parse(source)
+
+    node = oxc_python.node_at_offset(result.program, source.index("bar") + 1)
+    assert node.type == "Identifier"
+    assert node.name == "bar"
+
+    node = oxc_python.node_at_offset(result.program, 0)
+    assert node.type == "VariableDeclaration"
+
+
+def test_node_at_offset_span_end_is_exclusive():
+    """RED: Test node_at_offset() treats spans as half-open ranges."""
+    import oxc_python
+
+    source = "const foo = bar + 42;"
+    result = oxc_python.parse(source)
+
+    # Offset just past "bar" falls in the enclosing BinaryExpression, not the Identifier
+    node = oxc_python.node_at_offset(result.program, source.index("bar") + 3)
+    assert node.type == "BinaryExpression"
+
+
+def test_node_at_offset_outside_program():
+    """RED: Test node_at_offset() returns None when no node contains the offset."""
+    import oxc_python
+
+    source = "const x = 1;"
+    result = oxc_python.parse(source)
+
+    assert oxc_python.node_at_offset(result.program, len(source) + 10) is None
+
+
+def test_nodes_in_range_returns_overlapping_nodes_in_preorder():
+    """RED: Test nodes_in_range() returns nodes overlapping a range in pre-order."""
+    import oxc_python
+
+    source = "const a = 1;\nfoo(a);"
+    result = oxc_python.parse(source)
+    first, second = result.program.body
+
+    nodes = oxc_python.nodes_in_range(result.program, second.span.start, second.span.end)
+    types = [n.type for n in nodes]
+
+    assert "ExpressionStatement" in types
+    assert "CallExpression" in types
+    assert types.index("ExpressionStatement") < types.index("CallExpression")
+    assert "VariableDeclaration" not in types
+    assert all(n.span.start < second.span.end and n.span.end > second.span.start for n in nodes)
+
+
+def test_extract_function_signatures():
+    """RED: Test extract_function_signatures() covers functions, arrows, and methods."""
+    import oxc_python
+
+    source = """async function load(id: string, retries: number): Promise<string> {
+    return id;
+}
+const double = (x) => x * 2;
+class Repo {
+    *items(limit: number) {}
+}
+"""
+    result = oxc_python.parse(source, source_type="ts")
+    signatures = oxc_python.extract_function_signatures(result.program, source)
+    by_name = {sig.name: sig for sig in signatures}
+
+    load = by_name["load"]
+    assert load.params == ["id", "retries"]
+    assert load.param_types == ["string", "number"]
+    assert load.return_type == "Promise<string>"
+    assert load.is_async and not load.is_generator and not load.is_arrow
+    assert (load.start_line, load.end_line) == (1, 3)
+
+    arrow = by_name[None]
+    assert arrow.is_arrow
+    assert arrow.params == ["x"]
+    assert arrow.param_types == [None]
+    assert arrow.start_line == 4
+
+    items = by_name["items"]
+    assert items.is_generator
+    assert items.params == ["limit"]
+    assert items.param_types == ["number"]
+    assert items.start_line == 6
+
+
+def test_extract_function_signatures_without_source():
+    """RED: Test extract_function_signatures() omits type text when source is not given."""
+    import oxc_python
+
+    result = oxc_python.parse("function f(a: string): void {}", source_type="ts")
+    (sig,) = oxc_python.extract_function_signatures(result.program)
+
+    assert sig.name == "f"
+    assert sig.params == ["a"]
+    assert sig.param_types == [None]
+    assert sig.return_type is None
+
+
+def test_extract_function_signatures_pattern_and_default_params():
+    """RED: Test defaulted params keep their name and destructured params have none."""
+    import oxc_python
+
+    source = "function f({a}, [b], c: number = 1, ...r) {}"
+    result = oxc_python.parse(source, source_type="ts")
+    (sig,) = oxc_python.extract_function_signatures(result.program, source)
+
+    assert sig.params == [None, None, "c", "...r"]
+    assert sig.param_types == [None, None, "number", None]
+
+
+
+def test_extract_class_hierarchy_chain():
+    """RED: Test extract_class_hierarchy() maps each class to its superclass."""
+    import oxc_python
+
+    source = """
+interface I {}
+class A { base() {} }
+class B extends A {}
+class C extends B implements I { run() {} stop() {} }
+"""
+    result = oxc_python.parse(source, source_type="ts")
+
+    hierarchy = oxc_python.extract_class_hierarchy(result.program)
+    assert hierarchy == {"A": None, "B": "A", "C": "B"}
+
+    infos = oxc_python.extract_class_info(result.program)
+    assert [info.name for info in infos] == ["A", "B", "C"]
+    assert infos[2].superclass == "B"
+    assert infos[2].implements == ["I"]
+    assert infos[2].methods == ["run", "stop"]
+    assert infos[0].methods == ["base"]
+
+
+def test_extract_class_info_includes_class_expressions():
+    """RED: Test extract_class_info() covers class expressions."""
+    import oxc_python
+
+    result = oxc_python.parse("const X = class extends Base { m() {} };")
+    (info,) = oxc_python.extract_class_info(result.program)
+
+    assert info.name is None
+    assert info.superclass == "Base"
+    assert info.methods == ["m"]
+    assert oxc_python.extract_class_hierarchy(result.program) == {}
+
+
+def test_walk_with_context_yields_parents():
+    """RED: Test walk_with_context() yields each node's enclosing node."""
+    import oxc_python
+
+    result = oxc_python.parse("a.b()")
+    entries = list(oxc_python.walk_with_context(result.program))
+
+    program, depth, parent = entries[0]
+    assert program.type == "Program"
+    assert depth == 0
+    assert parent is None
+
+    parents = {node.type: (depth, parent) for node, depth, parent in entries}
+    assert parents["CallExpression"][0] == 2
+    assert parents["CallExpression"][1].type == "ExpressionStatement"
+    assert parents["MemberExpression"][1].type == "CallExpression"
+    assert [node.type for node, _, _ in entries] == [node.type for node, _ in oxc_python.walk(result.program)]
+
+def test_walk_max_depth_limits_traversal():
+    """RED: Test walk(max_depth=1) yields only Program and its direct children."""
+    import oxc_python
+
+    source = """
+function outer() {
+    function inner() {
+        if (x) { return 1; }
+    }
+}
+const y = 2;
+"""
+    result = oxc_python.parse(source)
+
+    nodes = list(oxc_python.walk(result.program, max_depth=1))
+    assert nodes[0][0].type == "Program"
+    assert nodes[0][1] == 0
+    assert [(node.type, depth) for node, depth in nodes[1:]] == [
+        ("FunctionDeclaration", 1),
+        ("VariableDeclaration", 1),
+    ]
+
+    # max_depth=0 yields only the root
+    assert [depth for _, depth in oxc_python.walk(result.program, max_depth=0)] == [0]
+
+    # walk_top_level is walk(max_depth=1)
+    top_level = [(n.type, d) for n, d in oxc_python.walk_top_level(result.program)]
+    assert top_level == [(n.type, d) for n, d in nodes]
+
+    # No limit still reaches the deepest nodes
+    assert max(depth for _, depth in oxc_python.walk(result.program)) > 1
+
+
+def test_walk_breadth_first_yields_levels_in_order():
+    """RED: Test walk_breadth_first() yields every depth-N node before depth N+1."""
+    import oxc_python
+
+    source = """
+function a() { if (x) { call(); } }
+const b = () => { return 1; };
+class C { m() { other(); } }
+"""
+    result = oxc_python.parse(source)
+
+    visited = list(oxc_python.walk_breadth_first(result.program))
+    depths = [depth for _, depth in visited]
+
+    assert visited[0][0].type == "Program"
+    assert depths == sorted(depths)
+    assert [n.type for n, d in visited if d == 1] == [
+        "FunctionDeclaration",
+        "VariableDeclaration",
+        "ClassDeclaration",
+    ]
+    # Same node set as walk()
+    assert len(visited) == len(list(oxc_python.walk(result.program)))
+
+
+def test_walk_is_depth_first_pre_order():
+    """RED: Test walk() finishes each subtree before the next sibling."""
+    import oxc_python
+
+    result = oxc_python.parse("function a() { call(); }\nconst b = 1;")
+
+    types = [node.type for node, _ in oxc_python.walk(result.program)]
+    bfs_types = [node.type for node, _ in oxc_python.walk_breadth_first(result.program)]
+
+    assert types.index("CallExpression") < types.index("VariableDeclaration")
+    assert types != bfs_types
+    assert sorted(types) == sorted(bfs_types)
+
+
+def test_walk_children_in_source_order():
+    """RED: Test walk() visits siblings in source order, whatever field holds them."""
+    import oxc_python
+
+    result = oxc_python.parse("[a, ...rest] = xs;\nt = `x${b}y${c}z`;")
+
+    spans = [node.span.start for node, _ in oxc_python.walk(result.program)]
+    assert spans == sorted(spans)
+    names = [node.name for node, _ in oxc_python.walk(result.program) if node.type == "Identifier"]
+    assert names == ["a", "rest", "xs", "t", "b", "c"]
+
+
+def test_walk_types_yields_only_requested_types():
+    """RED: Test walk_types() filters to the given node types, including nested ones."""
+    import oxc_python
+
+    source = """
+import x from "x";
+function outer(a) {
+    const inner = (b) => b + 1;
+    if (a) { return inner(a); }
+}
+class C { m() { return [1, "s"]; } }
+"""
+    result = oxc_python.parse(source)
+
+    all_types = {node.type for node, _ in oxc_python.walk(result.program)}
+    assert len(all_types) >= 10
+
+    wanted = ["FunctionDeclaration", "ArrowFunctionExpression"]
+    matched = list(oxc_python.walk_types(result.program, wanted))
+
+    assert [node.type for node, _ in matched] == wanted
+    assert matched[0][0].name == "outer"
+    expected = [(n, d) for n, d in oxc_python.walk(result.program) if n.type in wanted]
+    assert [d for _, d in matched] == [d for _, d in expected]
+
+
+
+def test_node_count_and_type_histogram():
+    """RED: Test node_count() and node_type_histogram() agree with walk()."""
+    import oxc_python
+
+    source = """
+function greet(name) {
+    return "Hello, " + name;
+}
+greet(user);
+"""
+    result = oxc_python.parse(source)
+
+    count = oxc_python.node_count(result.program)
+    histogram = oxc_python.node_type_histogram(result.program)
+
+    assert count > 0
+    assert count == len(list(oxc_python.walk(result.program)))
+    assert histogram["FunctionDeclaration"] == 1
+    assert histogram["Identifier"] >= 3
+    assert histogram["Program"] == 1
+    assert sum(histogram.values()) == count
+
+
+def test_visit_pairs_enter_and_exit():
+    """RED: Test visit() calls enter/exit in walk() order with matching depths."""
+    import oxc_python
+
+    source = """
+function outer() {
+    function inner() { return 1; }
+}
+function other() {}
+"""
+    result = oxc_python.parse(source)
+
+    names = []
+    open_nodes = []
+    max_open = 0
+
+    def enter(node, depth):
+        nonlocal max_open
+        assert depth == len(open_nodes)
+        open_nodes.append(node)
+        max_open = max(max_open, len(open_nodes))
+        if node.type == "FunctionDeclaration":
+            names.append(node.name)
+
+    def exit(node, depth):
+        assert open_nodes.pop() is node
+        assert depth == len(open_nodes)
+
+    oxc_python.visit(result.program, enter=enter, exit=exit)
+
+    assert names == ["outer", "inner", "other"]
+    assert open_nodes == []
+    assert max_open > 3
+
+
+def test_visit_prunes_when_enter_returns_false():
+    """RED: Test visit() skips children when enter returns False but still calls exit."""
+    import oxc_python
+
+    source = "function outer() { function inner() {} }\nfunction other() {}"
+    result = oxc_python.parse(source)
+
+    entered = []
+    exited = []
+
+    def enter(node, depth):
+        if node.type == "FunctionDeclaration":
+            entered.append(node.name)
+            return False
+
+    oxc_python.visit(
+        result.program,
+        enter=enter,
+        exit=lambda node, depth: exited.append(node.type),
+    )
+
+    assert entered == ["outer", "other"]
+    assert exited == ["FunctionDeclaration", "FunctionDeclaration", "Program"]
+
+
+def test_visit_enter_order_matches_walk():
+    """RED: Test visit() calls enter in the same order walk() yields nodes."""
+    import oxc_python
+
+    source = "function a(x) { if (x) { call(x); } }\nconst b = [1, 2];\nclass C { m() {} }"
+    result = oxc_python.parse(source)
+
+    entered = []
+    oxc_python.visit(result.program, enter=lambda node, depth: entered.append((node, depth)))
+
+    walked = list(oxc_python.walk(result.program))
+    assert [(n.type, d) for n, d in entered] == [(n.type, d) for n, d in walked]
+    assert all(a is b for (a, _), (b, _) in zip(entered, walked))
+
+
+def test_node_path_to_returns_ancestor_chain():
+    """RED: Test node_path_to() returns root-to-target nodes for an offset."""
+    import oxc_python
+
+    source = "function f() {\n    return a + 1;\n}"
+    result = oxc_python.parse(source)
+
+    path = oxc_python.node_path_to(result.program, source.index("1"))
+    assert [node.type for node in path] == [
+        "Program",
+        "FunctionDeclaration",
+        "BlockStatement",
+        "ReturnStatement",
+        "BinaryExpression",
+        "Literal",
+    ]
+    assert path[-1].value == 1
+
+    # Innermost node wins when several start at the same offset
+    path = oxc_python.node_path_to(result.program, source.index("a"))
+    assert [node.type for node in path][-2:] == ["BinaryExpression", "Identifier"]
+
+    # No node starts mid-identifier
+    assert oxc_python.node_path_to(result.program, source.index("eturn")) == []
+
+def test_collect_dependencies_all_kinds():
+    """RED: Test collect_dependencies() finds static, dynamic, and require dependencies."""
+    import oxc_python
+
+    source = """import React from "react";
+const utils = require("./utils");
+function load() {
+    return import("./lazy");
+}
+"""
+    result = oxc_python.parse(source)
+    deps = oxc_python.collect_dependencies(result.program, source)
+
+    assert [(d.specifier, d.kind) for d in deps] == [
+        ("react", "static"),
+        ("./utils", "require"),
+        ("./lazy", "dynamic"),
+    ]
+    assert [d.start_line for d in deps] == [1, 2, 4]
+    assert not any(d.type_only for d in deps)
+
+
+def test_collect_dependencies_type_only_and_computed():
+    """RED: Test type-only imports are flagged and computed specifiers skipped."""
+    import oxc_python
+
+    source = 'import type { User } from "./types";\nimport(name);\nrequire(path);'
+    result = oxc_python.parse(source, source_type="ts")
+    deps = oxc_python.collect_dependencies(result.program)
+
+    assert len(deps) == 1
+    assert deps[0].specifier == "./types"
+    assert deps[0].type_only is True
+
+
+def test_collect_dependencies_reexports_match_import_paths():
+    """RED: Test re-exports are dependencies and import_paths()/required_modules() agree."""
+    import oxc_python
+
+    source = 'export { a } from "./a";\nconst b = require("./b");\nexport * from "./c";\nimport("./d");'
+    result = oxc_python.parse(source)
+    deps = oxc_python.collect_dependencies(result.program)
+
+    assert [(d.specifier, d.kind) for d in deps] == [
+        ("./a", "reexport"),
+        ("./b", "require"),
+        ("./c", "reexport"),
+        ("./d", "dynamic"),
+    ]
+    assert result.import_paths() == ["./a", "./c", "./d"]
+    assert result.required_modules() == ["./b"]
+
+
+def test_walk_visits_type_parameters_and_union_members():
+    """RED: Test walk() reaches type parameters and union/intersection members."""
+    import oxc_python
+
+    result = oxc_python.parse("function foo<T extends string>() {}\nlet u: A | (B & C);", source_type="ts")
+    entries = list(oxc_python.walk_with_context(result.program))
+
+    (param, _, parent), = [e for e in entries if e[0].type == "TSTypeParameter"]
+    assert param.name == "T"
+    assert parent.type == "TSTypeParameterDeclaration"
+
+    union_children = [node.type for node, _, parent in entries if parent is not None and parent.type == "TSUnionType"]
+    assert union_children[0] == "TSTypeReference"
+    node_types = [node.type for node, _, _ in entries]
+    assert node_types.count("TSTypeReference") == 3
+
+
+def test_walk_and_find_type_methods_on_nodes():
+    """RED: Test node.walk() matches walk(node) and find_type() filters the subtree."""
+    import oxc_python
+
+    source = """
+function outer(a) {
+    const f = (b) => { if (b) { return g(b); } };
+    return f(a);
+}
+class C { m() { return h(); } }
+"""
+    program = oxc_python.parse(source).program
+    func, cls = program.body
+    arrow = func.body.body[0].declarations[0].init
+    block = arrow.body
+
+    for node in (program, func, cls, arrow, block):
+        expected = [(n.type, n.span, d) for n, d in oxc_python.walk(node)]
+        assert [(n.type, n.span, d) for n, d in node.walk()] == expected
+
+    assert max(d for _, d in func.walk(max_depth=1)) == 1
+
+    calls = program.find_type("CallExpression")
+    assert sorted(call.callee.name for call in calls) == ["f", "g", "h"]
+    assert calls == [n for n, _ in oxc_python.walk(program) if n.type == "CallExpression"]
+    assert [call.callee.name for call in arrow.find_type("CallExpression")] == ["g"]
+    assert func.find_type("FunctionDeclaration") == [func]
+    assert cls.find_type("ArrowFunctionExpression") == []